@@ -3,7 +3,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use bumpalo::Bump;
-use roc_error_macros::{internal_error, user_error};
+use roc_error_macros::internal_error;
 use roc_fmt::def::fmt_defs;
 use roc_fmt::header::fmt_header;
 use roc_fmt::Buf;
@@ -17,6 +17,8 @@ pub enum FormatMode {
     WriteToFile,
     WriteToStdout,
     CheckOnly,
+    /// Only check that the formatter round-trips each file correctly, for finding formatter bugs.
+    Verify,
 }
 
 fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
@@ -66,18 +68,25 @@ fn is_roc_file(path: &Path) -> bool {
 pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
     let arena = Bump::new();
     let mut files_to_reformat = Vec::new(); // to track which files failed `roc format --check`
+    let mut unparsable_files = Vec::new(); // to track which files were skipped because they don't parse
+    let mut parse_reports = Vec::new();
+    let mut formatter_bugs = Vec::new(); // to track which files failed `roc format --verify`
 
     for file in flatten_directories(files) {
         let src = std::fs::read_to_string(&file).unwrap();
 
-        match format_src(&arena, &src) {
+        match format_src(&arena, &file, &src) {
             Ok(buf) => {
                 match mode {
                     FormatMode::CheckOnly => {
                         // If a file fails `format --check`, add it to the file
                         // list for reporting afterwards.
                         if buf.as_str() != src {
-                            files_to_reformat.push(file.display().to_string());
+                            files_to_reformat.push(format!(
+                                "{} ({})",
+                                file.display(),
+                                diff_summary(&src, buf.as_str())
+                            ));
                         }
                     }
                     FormatMode::WriteToFile => {
@@ -87,9 +96,22 @@ pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(
                     FormatMode::WriteToStdout => {
                         std::io::stdout().lock().write_all(buf.as_bytes()).unwrap()
                     }
+                    FormatMode::Verify => {
+                        // format_src already checked the round trip, and there's nothing to write.
+                    }
                 }
             }
             Err(err) => match err {
+                FormatProblem::InvalidSource { report } => {
+                    // Skip files we can't parse, so one broken file doesn't stop
+                    // the rest of the directory from being formatted.
+                    parse_reports.push(report);
+                    unparsable_files.push(file.display().to_string());
+                }
+                bug if matches!(mode, FormatMode::Verify) => {
+                    // Keep going, so one run of `--verify` finds every file the formatter mishandles.
+                    formatter_bugs.push(format!("{}: {}", file.display(), bug.summary()));
+                }
                 FormatProblem::ParsingFailed {
                     formatted_src,
                     parse_err,
@@ -155,19 +177,97 @@ pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(
             },
         }
     }
+    let mut messages = Vec::new();
+
     // After processing all files, check if any files failed `format --check`
     if !files_to_reformat.is_empty() {
-        let file_list = files_to_reformat.join(", ");
-        return Err(format!(
+        let file_list = files_to_reformat.join("\n\t");
+        messages.push(format!(
             "The following file(s) failed `roc format --check`:\n\t{}\nYou can fix this with `roc format filename.roc`.",
             file_list
         ));
     }
-    Ok(())
+
+    if !formatter_bugs.is_empty() {
+        let file_list = formatter_bugs.join("\n\t");
+        messages.push(format!(
+            "The formatter has a bug that affects the following file(s):\n\t{}",
+            file_list
+        ));
+    }
+
+    if !unparsable_files.is_empty() {
+        messages.extend(parse_reports);
+
+        let file_list = unparsable_files.join("\n\t");
+        messages.push(format!(
+            "The following file(s) could not be parsed, so they were not formatted:\n\t{}",
+            file_list
+        ));
+    }
+
+    if messages.is_empty() {
+        Ok(())
+    } else {
+        Err(messages.join("\n\n"))
+    }
+}
+
+/// How many lines formatting adds and removes, e.g. `+2 -3 lines`, so `roc format --check`
+/// can say how much each file would change without printing the whole diff.
+pub fn diff_summary(before: &str, after: &str) -> String {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+
+    // Lines at the start and end that formatting didn't touch can't be part of the diff.
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let before = &before[prefix..before.len() - suffix];
+    let after = &after[prefix..after.len() - suffix];
+
+    // Every line that isn't in the longest common subsequence was removed or added.
+    let mut row = vec![0; after.len() + 1];
+
+    for old in before {
+        let mut diagonal = 0;
+
+        for (index, new) in after.iter().enumerate() {
+            let above = row[index + 1];
+
+            row[index + 1] = if old == new {
+                diagonal + 1
+            } else {
+                above.max(row[index])
+            };
+
+            diagonal = above;
+        }
+    }
+
+    let unchanged = row[after.len()];
+
+    format!(
+        "+{} -{} lines",
+        after.len() - unchanged,
+        before.len() - unchanged
+    )
 }
 
 #[derive(Debug)]
 pub enum FormatProblem {
+    InvalidSource {
+        /// The rendered report for the parse error, like `roc check` would print.
+        report: String,
+    },
     ParsingFailed {
         formatted_src: String,
         parse_err: String,
@@ -183,10 +283,31 @@ pub enum FormatProblem {
     },
 }
 
-pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
-    let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
-        user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
-    }));
+impl FormatProblem {
+    fn summary(&self) -> &'static str {
+        match self {
+            FormatProblem::InvalidSource { .. } => "the source code doesn't parse",
+            FormatProblem::ParsingFailed { .. } => "the formatted code doesn't parse",
+            FormatProblem::ReformattingChangedAst { .. } => {
+                "the formatted code doesn't parse to the same tree as the original"
+            }
+            FormatProblem::ReformattingUnstable { .. } => {
+                "formatting the formatted code changes it again"
+            }
+        }
+    }
+}
+
+/// Formats the source code of a module. The filename is only used for error reports.
+pub fn format_src(arena: &Bump, filename: &Path, src: &str) -> Result<String, FormatProblem> {
+    let ast = match parse_all(arena, src) {
+        Ok(ast) => arena.alloc(ast),
+        Err(e) => {
+            return Err(FormatProblem::InvalidSource {
+                report: parse_error_report(filename, src, e),
+            });
+        }
+    };
     let mut buf = Buf::new_in(arena);
     fmt_all(&mut buf, ast);
 
@@ -231,6 +352,48 @@ pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
     Ok(buf.as_str().to_string())
 }
 
+fn parse_error_report<'a>(filename: &Path, src: &'a str, problem: SyntaxError<'a>) -> String {
+    use roc_module::symbol::{Interns, ModuleIds};
+    use roc_parse::parser::{FileError, SourceError};
+    use roc_region::all::LineInfo;
+    use roc_reporting::report::{
+        parse_problem, terminal_palette, terminal_render_target, RocDocAllocator,
+    };
+
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let lines = LineInfo::new(src);
+
+    let module_name = filename
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+    let mut module_ids = ModuleIds::default();
+    let module_id = module_ids.get_or_insert(&module_name.into());
+    let interns = Interns::default();
+
+    let alloc = RocDocAllocator::new(&src_lines, module_id, &interns);
+
+    let problem = FileError {
+        problem: SourceError {
+            problem,
+            bytes: src.as_bytes(),
+        },
+        filename: filename.to_path_buf(),
+    };
+
+    let report = parse_problem(&alloc, &lines, filename.to_path_buf(), 0, problem);
+
+    let mut buf = String::new();
+    report.render(
+        terminal_render_target(),
+        &mut buf,
+        &alloc,
+        &terminal_palette(),
+    );
+
+    buf
+}
+
 fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<FullAst<'a>, SyntaxError<'a>> {
     let (header, state) = header::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
@@ -281,6 +444,10 @@ main =
         Stdout.line! "Hi $(name)!"
 "#;
 
+    const BROKEN_ROC: &str = r#"app [main] { pf: platform "platform/main.roc" }
+
+main = [1, 2, , 3]"#;
+
     fn setup_test_file(dir: &Path, file_name: &str, contents: &str) -> PathBuf {
         let file_path = dir.join(file_name);
         let mut file = File::create(&file_path).unwrap();
@@ -301,17 +468,26 @@ main =
 
         let result = format_files(vec![file_path.clone()], FormatMode::CheckOnly);
         assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err(),
-            format!(
-                "The following file(s) failed `roc format --check`:\n\t{}\nYou can fix this with `roc format filename.roc`.",
-                &file_path.as_path().to_str().unwrap()
-            )
+        let error_message = result.unwrap_err();
+        assert!(error_message.starts_with(&format!(
+            "The following file(s) failed `roc format --check`:\n\t{} (+",
+            &file_path.as_path().to_str().unwrap()
+        )));
+        assert!(
+            error_message.ends_with(" lines)\nYou can fix this with `roc format filename.roc`.")
         );
 
         cleanup_temp_dir(dir);
     }
 
+    #[test]
+    fn test_diff_summary() {
+        assert_eq!(diff_summary("a\nb\nc\n", "a\nb\nc\n"), "+0 -0 lines");
+        assert_eq!(diff_summary("a\n  b\nc\n", "a\nb\nc\n"), "+1 -1 lines");
+        assert_eq!(diff_summary("a\nb\n\n\nc\n", "a\nb\n\nc\n"), "+0 -1 lines");
+        assert_eq!(diff_summary("a\nc\n", "x\na\nb\nc\n"), "+2 -0 lines");
+    }
+
     #[test]
     fn test_multiple_files_needs_reformatting() {
         let dir = tempdir().unwrap();
@@ -355,4 +531,56 @@ main =
 
         cleanup_temp_dir(dir);
     }
+
+    #[test]
+    fn test_unparsable_file_is_skipped() {
+        let dir = tempdir().unwrap();
+        let file_broken = setup_test_file(dir.path(), "broken.roc", BROKEN_ROC);
+        let file_unformatted = setup_test_file(dir.path(), "test1.roc", UNFORMATTED_ROC);
+
+        let result = format_files(
+            vec![file_broken.clone(), file_unformatted.clone()],
+            FormatMode::WriteToFile,
+        );
+        assert!(result.is_err());
+        let error_message = result.unwrap_err();
+        assert!(error_message.contains("could not be parsed"));
+        assert!(error_message.contains("broken.roc"));
+        // The parse error is reported like `roc check` would, pointing at the problem
+        assert!(error_message.contains("3│"));
+        assert!(!error_message.contains("test1.roc"));
+
+        // The broken file is left alone, and the other file still gets formatted
+        assert_eq!(
+            std::fs::read_to_string(&file_broken).unwrap(),
+            format!("{BROKEN_ROC}\n")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&file_unformatted).unwrap(),
+            format_src(
+                &Bump::new(),
+                &file_unformatted,
+                &format!("{UNFORMATTED_ROC}\n")
+            )
+            .unwrap()
+        );
+
+        cleanup_temp_dir(dir);
+    }
+
+    #[test]
+    fn test_verify_does_not_change_files() {
+        let dir = tempdir().unwrap();
+        let file_unformatted = setup_test_file(dir.path(), "test1.roc", UNFORMATTED_ROC);
+
+        // Unformatted code is fine for --verify, as long as the formatter handles it correctly.
+        let result = format_files(vec![file_unformatted.clone()], FormatMode::Verify);
+        assert!(result.is_ok());
+        assert_eq!(
+            std::fs::read_to_string(&file_unformatted).unwrap(),
+            format!("{UNFORMATTED_ROC}\n")
+        );
+
+        cleanup_temp_dir(dir);
+    }
 }
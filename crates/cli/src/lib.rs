@@ -14,18 +14,17 @@ use roc_build::program::{
     BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions, DEFAULT_ROC_FILENAME,
 };
 #[cfg(not(windows))]
-use roc_collections::MutMap;
+use roc_collections::{MutMap, MutSet};
 use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
-use roc_load::{ExpectMetadata, Threading};
+use roc_load::{ExecutionMode, ExpectMetadata, FunctionKind, LoadConfig, Threading};
 #[cfg(not(windows))]
 use roc_module::symbol::ModuleId;
 use roc_mono::ir::OptLevel;
-use roc_packaging::cache::RocCacheDir;
+use roc_packaging::cache::{roc_cache_packages_dir, RocCacheDir};
 use roc_packaging::tarball::Compression;
-#[cfg(not(windows))]
-use roc_reporting::report::ANSI_STYLE_CODES;
+use roc_reporting::report::{terminal_palette, Report};
 use roc_target::{Architecture, Target};
 use std::env;
 use std::ffi::{CString, OsStr, OsString};
@@ -42,21 +41,24 @@ use strum::IntoEnumIterator;
 use tempfile::TempDir;
 
 mod format;
-pub use format::{format_files, format_src, FormatMode};
+pub use format::{diff_summary, format_files, format_src, FormatMode, FormatProblem};
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
 pub const CMD_DEV: &str = "dev";
 pub const CMD_REPL: &str = "repl";
 pub const CMD_DOCS: &str = "docs";
+pub const CMD_DEPS: &str = "deps";
 pub const CMD_CHECK: &str = "check";
 pub const CMD_VERSION: &str = "version";
+pub const CMD_TARGETS: &str = "targets";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
+pub const FLAG_EMIT_ASM: &str = "emit-asm";
 pub const FLAG_PROFILING: &str = "profiling";
 pub const FLAG_BUNDLE: &str = "bundle";
 pub const FLAG_DEV: &str = "dev";
@@ -67,8 +69,11 @@ pub const FLAG_LIB: &str = "lib";
 pub const FLAG_NO_LINK: &str = "no-link";
 pub const FLAG_TARGET: &str = "target";
 pub const FLAG_TIME: &str = "time";
+pub const FLAG_WARNINGS_AS_ERRORS: &str = "warnings-as-errors";
 pub const FLAG_VERBOSE: &str = "verbose";
 pub const FLAG_NO_COLOR: &str = "no-color";
+pub const FLAG_COLOR: &str = "color";
+pub const FLAG_ERROR_FORMAT: &str = "error-format";
 pub const FLAG_NO_HEADER: &str = "no-header";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_BUILD_HOST: &str = "build-host";
@@ -76,10 +81,15 @@ pub const FLAG_SUPPRESS_BUILD_HOST_WARNING: &str = "suppress-build-host-warning"
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_STDIN: &str = "stdin";
 pub const FLAG_STDOUT: &str = "stdout";
+pub const FLAG_VERIFY: &str = "verify";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_DOT: &str = "dot";
+pub const FLAG_JSON: &str = "json";
+pub const FLAG_TRANSITIVE: &str = "transitive";
 pub const FLAG_FUZZ: &str = "fuzz";
 pub const FLAG_MAIN: &str = "main";
+pub const FLAG_WATCH: &str = "watch";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_DIR: &str = "GLUE_DIR";
@@ -120,7 +130,13 @@ pub fn build_app() -> Command {
 
     let flag_emit_llvm_ir = Arg::new(FLAG_EMIT_LLVM_IR)
         .long(FLAG_EMIT_LLVM_IR)
-        .help("Emit a `.ll` file containing the LLVM IR of the program")
+        .help("Emit a `.ll` file containing the optimized LLVM IR of the program, and an\n`.unoptimized.ll` file containing the LLVM IR before any optimizations")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_emit_asm = Arg::new(FLAG_EMIT_ASM)
+        .long(FLAG_EMIT_ASM)
+        .help("Emit a `.s` file containing the target assembly of the program")
         .action(ArgAction::SetTrue)
         .required(false);
 
@@ -136,6 +152,28 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_warnings_as_errors = Arg::new(FLAG_WARNINGS_AS_ERRORS)
+        .long(FLAG_WARNINGS_AS_ERRORS)
+        .help("Treat warnings as errors, so they fail the build")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_color = Arg::new(FLAG_COLOR)
+        .long(FLAG_COLOR)
+        .help("When to use colors in compiler output\n(`auto` only uses colors when stdout is a terminal, and respects the NO_COLOR and ROC_PALETTE environment variables.)")
+        .value_parser(["always", "never", "auto"])
+        .default_value("auto")
+        .global(true)
+        .required(false);
+
+    let flag_error_format = Arg::new(FLAG_ERROR_FORMAT)
+        .long(FLAG_ERROR_FORMAT)
+        .help("How to print errors and warnings\n(`json` prints one JSON object per problem to stderr, for editors and CI tools.)")
+        .value_parser(["human", "json"])
+        .default_value("human")
+        .global(true)
+        .required(false);
+
     let flag_linker = Arg::new(FLAG_LINKER)
         .long(FLAG_LINKER)
         .help("Set which linker to use\n(The surgical linker is enabled by default only when building for wasm32 or x86_64 Linux, because those are the only targets it currently supports. Otherwise the legacy linker is used by default.)")
@@ -204,8 +242,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(flag_linker.clone())
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
@@ -257,8 +297,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(flag_linker.clone())
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
@@ -285,7 +327,7 @@ pub fn build_app() -> Command {
             .arg(
                 Arg::new(FLAG_NO_COLOR)
                     .long(FLAG_NO_COLOR)
-                    .help("Do not use any ANSI color codes in the repl output")
+                    .help("Do not use any ANSI color codes in the repl output\n(An alias for `--color never`.)")
                     .action(ArgAction::SetTrue)
                     .required(false)
             )
@@ -304,6 +346,7 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -320,8 +363,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_asm.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(flag_linker.clone())
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
@@ -344,6 +389,13 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_VERIFY)
+                    .long(FLAG_VERIFY)
+                    .help("Checks that formatting the specified files keeps their meaning, and that formatting them\ntwice gives the same result, without changing any files\n(This is for finding bugs in the formatter. If it finds any, return a non-zero exit code.)")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
             .arg(
                 Arg::new(FLAG_STDIN)
                     .long(FLAG_STDIN)
@@ -362,11 +414,21 @@ pub fn build_app() -> Command {
         )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
+        .subcommand(Command::new(CMD_TARGETS)
+            .about("List the targets this compiler can build for, which backends and linker each one uses, and the bundled builtins version"))
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_main.clone())
             .arg(flag_time.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_WATCH)
+                    .long(FLAG_WATCH)
+                    .help("Keep running, and check again whenever one of the checked modules changes")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to check")
@@ -392,6 +454,35 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
                 )
         )
+        .subcommand(
+            Command::new(CMD_DEPS)
+                .about("Print which modules each module in a Roc app or package imports\n(If the imports form a cycle, explain the cycle instead.)")
+                .arg(Arg::new(FLAG_TRANSITIVE)
+                    .long(FLAG_TRANSITIVE)
+                    .help("List every module each module depends on, including the ones it only imports indirectly")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+                )
+                .arg(Arg::new(FLAG_DOT)
+                    .long(FLAG_DOT)
+                    .help("Print the import graph in Graphviz DOT format")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+                )
+                .arg(Arg::new(FLAG_JSON)
+                    .long(FLAG_JSON)
+                    .help("Print the direct and transitive imports of each module as JSON")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with(FLAG_DOT)
+                    .required(false),
+                )
+                .arg(Arg::new(ROC_FILE)
+                    .help("The main .roc file of the app or package")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+                )
+        )
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
             .arg(&flag_dev)
@@ -456,8 +547,12 @@ pub fn build_app() -> Command {
         .arg(flag_opt_size)
         .arg(flag_dev)
         .arg(flag_emit_llvm_ir)
+        .arg(flag_emit_asm)
         .arg(flag_profiling)
         .arg(flag_time)
+        .arg(flag_warnings_as_errors)
+        .arg(flag_color)
+        .arg(flag_error_format)
         .arg(flag_linker)
         .arg(flag_build_host)
         .arg(flag_suppress_build_host_warning)
@@ -487,6 +582,161 @@ fn opt_level_from_flags(matches: &ArgMatches) -> OptLevel {
     }
 }
 
+/// The imports of one module, by module name.
+struct ModuleDeps {
+    name: String,
+    imports: Vec<String>,
+    transitive_imports: Vec<String>,
+}
+
+impl ModuleDeps {
+    fn imports(&self, transitive: bool) -> &[String] {
+        if transitive {
+            &self.transitive_imports
+        } else {
+            &self.imports
+        }
+    }
+}
+
+/// Print the import graph of an app or package, leaving out builtin modules.
+/// Import cycles are reported by load itself, along with the chain of imports
+/// that forms the cycle, so they never reach this point.
+pub fn deps(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+    let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+
+    let load_config = LoadConfig {
+        // only used for generating errors, since we stop after type checking
+        target: Target::LinuxX64,
+        function_kind: FunctionKind::from_env(),
+        render: roc_reporting::report::terminal_render_target(),
+        palette: roc_reporting::report::terminal_palette(),
+        threading: Threading::AllAvailable,
+        exec_mode: ExecutionMode::Check,
+    };
+
+    let loaded = match roc_load::load_and_typecheck(
+        &arena,
+        roc_file_path.to_owned(),
+        None,
+        RocCacheDir::Persistent(roc_cache_packages_dir().as_path()),
+        load_config,
+    ) {
+        Ok(loaded) => loaded,
+        Err(problem) => return handle_loading_problem(problem),
+    };
+
+    let module_name = |module_id: ModuleId| {
+        let name = loaded.interns.module_name(module_id);
+
+        if name.is_empty() {
+            // the app module
+            roc_file_path.display().to_string()
+        } else {
+            name.as_str().to_string()
+        }
+    };
+
+    let direct_imports = |module_id: ModuleId| -> Vec<ModuleId> {
+        loaded
+            .imports
+            .get(&module_id)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|import| !import.is_builtin())
+            .collect()
+    };
+
+    // Load rejects import cycles, so this never finds the module itself.
+    let transitive_imports = |module_id: ModuleId| -> Vec<ModuleId> {
+        let mut seen = MutSet::default();
+        let mut stack = direct_imports(module_id);
+
+        while let Some(import) = stack.pop() {
+            if seen.insert(import) {
+                stack.extend(direct_imports(import));
+            }
+        }
+
+        seen.into_iter().collect()
+    };
+
+    let sorted_names = |module_ids: Vec<ModuleId>| {
+        let mut names: Vec<String> = module_ids.into_iter().map(module_name).collect();
+
+        names.sort();
+
+        names
+    };
+
+    let mut graph: Vec<ModuleDeps> = loaded
+        .imports
+        .keys()
+        .copied()
+        .filter(|module_id| !module_id.is_builtin())
+        .map(|module_id| ModuleDeps {
+            name: module_name(module_id),
+            imports: sorted_names(direct_imports(module_id)),
+            transitive_imports: sorted_names(transitive_imports(module_id)),
+        })
+        .collect();
+
+    graph.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let transitive = matches.get_flag(FLAG_TRANSITIVE);
+
+    if matches.get_flag(FLAG_JSON) {
+        let modules: Vec<serde_json::Value> = graph
+            .iter()
+            .map(|module| {
+                serde_json::json!({
+                    "name": module.name,
+                    "imports": module.imports,
+                    "transitive_imports": module.transitive_imports,
+                })
+            })
+            .collect();
+
+        println!("{}", serde_json::json!({ "modules": modules }));
+    } else if matches.get_flag(FLAG_DOT) {
+        println!("digraph imports {{");
+
+        for module in graph.iter() {
+            // List every module, so the ones without any imports show up too.
+            println!("    {};", dot_string(&module.name));
+
+            for import in module.imports(transitive) {
+                println!(
+                    "    {} -> {};",
+                    dot_string(&module.name),
+                    dot_string(import)
+                );
+            }
+        }
+
+        println!("}}");
+    } else {
+        for module in graph.iter() {
+            println!("{}", module.name);
+
+            for import in module.imports(transitive) {
+                println!("    {import}");
+            }
+        }
+    }
+
+    Ok(0)
+}
+
+/// Quote a module name or path as a Graphviz DOT string, e.g. `"Foo"`.
+fn dot_string(name: &str) -> String {
+    // Inside a quoted DOT string, only `"` needs escaping, but a backslash
+    // (e.g. in a Windows path) would start an escape sequence in a label.
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 #[cfg(windows)]
 pub fn test(_matches: &ArgMatches, _target: Target) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
@@ -509,6 +759,7 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     let start_time = Instant::now();
     let arena = Bump::new();
     let opt_level = opt_level_from_flags(matches);
+    let warnings_as_errors = matches.get_flag(FLAG_WARNINGS_AS_ERRORS);
 
     let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
         None => Threading::AllAvailable,
@@ -562,9 +813,8 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         let load_config = LoadConfig {
             target,
             function_kind,
-            // TODO: expose this from CLI?
-            render: roc_reporting::report::RenderTarget::ColorTerminal,
-            palette: roc_reporting::report::DEFAULT_PALETTE,
+            render: roc_reporting::report::terminal_render_target(),
+            palette: roc_reporting::report::terminal_palette(),
             threading,
             exec_mode: ExecutionMode::Test,
         };
@@ -585,7 +835,16 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
                 return handle_error_module(module, start_time.elapsed(), path.as_os_str(), false);
             }
         };
-        let problems = report_problems_monomorphized(&mut loaded);
+        let problems =
+            report_problems_monomorphized(&mut loaded).with_warnings_as_errors(warnings_as_errors);
+
+        // Only warnings promoted by --warnings-as-errors can be errors by now.
+        if problems.errors > 0 {
+            problems.print_error_warning_count(start_time.elapsed());
+            println!(".\n\nNot running tests, because --warnings-as-errors was set.");
+
+            return Ok(problems.exit_code());
+        }
 
         let mut expectations = std::mem::take(&mut loaded.expectations);
 
@@ -610,7 +869,7 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
             );
             if problems.warnings > 0 {
                 problems.print_error_warning_count(start_time.elapsed());
-                println!(".\n\nRunning tests…\n\n\x1B[36m{}\x1B[39m", "─".repeat(80));
+                println!(".\n\nRunning tests…\n\n{}", horizontal_rule());
             }
         }
 
@@ -735,13 +994,14 @@ fn print_test_results(
 
 #[cfg(not(windows))]
 fn test_summary(failed_count: usize, passed_count: usize, tests_duration: Duration) -> String {
+    let palette = terminal_palette();
     let failed_color = if failed_count == 0 {
-        ANSI_STYLE_CODES.green
+        palette.success
     } else {
-        ANSI_STYLE_CODES.red
+        palette.error
     };
-    let passed_color = ANSI_STYLE_CODES.green;
-    let reset = ANSI_STYLE_CODES.reset;
+    let passed_color = palette.success;
+    let reset = palette.reset;
 
     format!(
         "{failed_color}{failed_count}{reset} failed and {passed_color}{passed_count}{reset} passed in {} ms.",
@@ -749,6 +1009,13 @@ fn test_summary(failed_count: usize, passed_count: usize, tests_duration: Durati
     )
 }
 
+/// The rule printed between the compiler's output and the program's (or the tests') output.
+fn horizontal_rule() -> String {
+    let palette = terminal_palette();
+
+    format!("{}{}", Report::horizontal_rule(&palette), palette.reset)
+}
+
 /// Find the element of `options` with the smallest edit distance to
 /// `reference`. Returns a tuple containing the element and the distance, or
 /// `None` if the `options` `Vec` is empty.
@@ -850,8 +1117,11 @@ pub fn build(
             };
             let created_path = path.with_file_name(&filename);
 
+            let palette = terminal_palette();
+            let (warning, success, reset) = (palette.warning, palette.success, palette.reset);
+
             println!(
-                "\nBundled \x1B[33m{}\x1B[39m and its dependent files into the following archive in {total_time}:\n\n\t\x1B[33m{}\x1B[39m\n\nTo distribute this archive as a package, upload this to some URL and then add it as a dependency with:\n\n\t\x1B[32m\"https://your-url-goes-here/{filename}\"\x1B[39m\n",
+                "\nBundled {warning}{}{reset} and its dependent files into the following archive in {total_time}:\n\n\t{warning}{}{reset}\n\nTo distribute this archive as a package, upload this to some URL and then add it as a dependency with:\n\n\t{success}\"https://your-url-goes-here/{filename}\"{reset}\n",
                 path.to_string_lossy(),
                 created_path.to_string_lossy()
             );
@@ -892,10 +1162,26 @@ pub fn build(
         user_error!("Cannot emit llvm ir while using a dev backend.");
     }
 
+    let emit_asm = matches.get_flag(FLAG_EMIT_ASM);
+    if emit_asm && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot emit assembly while using a dev backend.");
+    }
+    if emit_asm && matches!(target.architecture(), Architecture::Wasm32) {
+        user_error!("Cannot emit assembly when building for wasm32. Use --emit-llvm-ir instead.");
+    }
+
     let emit_debug_info = matches.get_flag(FLAG_PROFILING)
         || matches!(opt_level, OptLevel::Development | OptLevel::Normal);
     let emit_timings = matches.get_flag(FLAG_TIME);
 
+    // `roc run` always runs the program, so it doesn't accept this flag.
+    let warnings_as_errors = matches
+        .try_get_one::<bool>(FLAG_WARNINGS_AS_ERRORS)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or(false);
+
     let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
         None => Threading::AllAvailable,
         Some(0) => user_error!("cannot build with at most 0 threads"),
@@ -941,6 +1227,7 @@ pub fn build(
         opt_level,
         emit_debug_info,
         emit_llvm_ir,
+        emit_asm,
         fuzz,
     };
 
@@ -956,6 +1243,7 @@ pub fn build(
         linking_strategy,
         build_host,
         suppress_build_host_warning,
+        warnings_as_errors,
         wasm_dev_stack_bytes,
         roc_cache_dir,
         load_config,
@@ -992,8 +1280,8 @@ pub fn build(
                     if problems.fatally_errored {
                         problems.print_error_warning_count(total_time);
                         println!(
-                            ".\n\nCannot run program due to fatal error…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
+                            ".\n\nCannot run program due to fatal error…\n\n{}",
+                            horizontal_rule()
                         );
 
                         // Return a nonzero exit code due to fatal problem
@@ -1001,10 +1289,7 @@ pub fn build(
                     }
                     if problems.errors > 0 || problems.warnings > 0 {
                         problems.print_error_warning_count(total_time);
-                        println!(
-                            ".\n\nRunning program anyway…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
-                        );
+                        println!(".\n\nRunning program anyway…\n\n{}", horizontal_rule());
                     }
 
                     let args = matches
@@ -1030,8 +1315,8 @@ pub fn build(
                     if problems.fatally_errored {
                         problems.print_error_warning_count(total_time);
                         println!(
-                            ".\n\nCannot run program due to fatal error…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
+                            ".\n\nCannot run program due to fatal error…\n\n{}",
+                            horizontal_rule()
                         );
 
                         // Return a nonzero exit code due to fatal problem
@@ -1044,10 +1329,7 @@ pub fn build(
 
                     if problems.warnings > 0 {
                         problems.print_error_warning_count(total_time);
-                        println!(
-                            ".\n\nRunning program…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
-                        );
+                        println!(".\n\nRunning program…\n\n{}", horizontal_rule());
                     }
 
                     let args = matches
@@ -1075,6 +1357,15 @@ pub fn build(
             handle_error_module(module, total_time, path.as_os_str(), true)
         }
         Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
+        Err(BuildFileError::WarningsAsErrors {
+            problems,
+            total_time,
+        }) => {
+            problems.print_error_warning_count(total_time);
+            println!(".\n\nNothing was built, because --warnings-as-errors was set.");
+
+            Ok(problems.exit_code())
+        }
     }
 }
 
@@ -1,13 +1,15 @@
 //! The `roc` binary that brings together all functionality in the Roc toolset.
 use bumpalo::Bump;
 use roc_build::link::LinkType;
-use roc_build::program::{check_file, CodeGenBackend};
+use roc_build::program::{check_file, CheckedFile, CodeGenBackend};
 use roc_cli::{
-    build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
-    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST,
-    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB, FLAG_MAIN, FLAG_NO_COLOR,
+    build_app, deps, diff_summary, format_files, format_src, test, BuildConfig, FormatMode,
+    FormatProblem, CMD_BUILD, CMD_CHECK, CMD_DEPS, CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GLUE,
+    CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TARGETS, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES,
+    FLAG_CHECK, FLAG_COLOR, FLAG_DEV, FLAG_ERROR_FORMAT, FLAG_LIB, FLAG_MAIN, FLAG_NO_COLOR,
     FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_PP_DYLIB, FLAG_PP_HOST, FLAG_PP_PLATFORM,
-    FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
+    FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, FLAG_VERIFY, FLAG_WARNINGS_AS_ERRORS,
+    FLAG_WATCH, GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
@@ -15,12 +17,16 @@ use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
 use roc_load::{LoadingProblem, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
-use roc_target::Target;
+use roc_reporting::cli::print_formatted_report;
+use roc_reporting::report::{set_color_choice, set_error_format, ColorChoice, ErrorFormat};
+use roc_target::{Architecture, Target};
 use std::fs::{self, FileType};
 use std::io::BufRead;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+use strum::IntoEnumIterator;
 use target_lexicon::Triple;
 use tempfile::Builder;
 
@@ -41,6 +47,27 @@ fn main() -> io::Result<()> {
         .collect();
     let matches = app.get_matches();
 
+    let repl_no_color = matches!(
+        matches.subcommand(),
+        Some((CMD_REPL, repl_matches)) if repl_matches.get_flag(FLAG_NO_COLOR)
+    );
+    let color_choice = match matches.get_one::<String>(FLAG_COLOR).map(String::as_str) {
+        _ if repl_no_color => ColorChoice::Never,
+        Some("always") => ColorChoice::Always,
+        Some("never") => ColorChoice::Never,
+        _ => ColorChoice::Auto,
+    };
+    set_color_choice(color_choice);
+
+    let error_format = match matches
+        .get_one::<String>(FLAG_ERROR_FORMAT)
+        .map(String::as_str)
+    {
+        Some("json") => ErrorFormat::Json,
+        _ => ErrorFormat::Human,
+    };
+    set_error_format(error_format);
+
     let exit_code = match matches.subcommand() {
         None => {
             if matches.contains_id(ROC_FILE) {
@@ -197,6 +224,7 @@ fn main() -> io::Result<()> {
             let arena = Bump::new();
 
             let emit_timings = matches.get_flag(FLAG_TIME);
+            let warnings_as_errors = matches.get_flag(FLAG_WARNINGS_AS_ERRORS);
             let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
                 None => Threading::AllAvailable,
@@ -245,13 +273,19 @@ fn main() -> io::Result<()> {
                             RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                             threading,
                         ) {
-                            Ok((problems, total_time)) => {
+                            Ok(CheckedFile {
+                                problems,
+                                total_time,
+                                ..
+                            }) => {
+                                let problems = problems.with_warnings_as_errors(warnings_as_errors);
+
                                 problems.print_error_warning_count(total_time);
                                 exit_code = problems.exit_code();
                             }
 
                             Err(LoadingProblem::FormattedReport(report)) => {
-                                print!("{report}");
+                                print_formatted_report(&report);
 
                                 exit_code = 1;
                             }
@@ -267,6 +301,52 @@ fn main() -> io::Result<()> {
 
                     Ok(exit_code)
                 }
+                _ if matches.get_flag(FLAG_WATCH) => {
+                    // Until a check succeeds, we only know about the file we were given.
+                    let mut module_paths = vec![roc_file_path.to_owned()];
+
+                    loop {
+                        let arena = Bump::new();
+
+                        match check_file(
+                            &arena,
+                            roc_file_path.to_owned(),
+                            opt_main_path.cloned(),
+                            emit_timings,
+                            RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
+                            threading,
+                        ) {
+                            Ok(checked) => {
+                                let problems =
+                                    checked.problems.with_warnings_as_errors(warnings_as_errors);
+
+                                problems.print_error_warning_count(checked.total_time);
+
+                                module_paths = checked.module_paths;
+                            }
+
+                            // If loading fails (e.g. on a syntax error), keep watching the modules
+                            // from the last successful check, so fixing any of them re-checks.
+                            Err(LoadingProblem::FormattedReport(report)) => {
+                                print_formatted_report(&report);
+                            }
+                            Err(other) => {
+                                eprintln!("roc check failed with error:\n{other:?}");
+                            }
+                        }
+
+                        if !module_paths.contains(roc_file_path) {
+                            module_paths.push(roc_file_path.to_owned());
+                        }
+
+                        let snapshot = source_snapshot(&module_paths);
+
+                        println!("\nWatching for changes... (press Ctrl+C to stop)\n");
+                        io::stdout().flush()?;
+
+                        wait_for_changes(&module_paths, snapshot);
+                    }
+                }
                 _ => {
                     match check_file(
                         &arena,
@@ -276,13 +356,19 @@ fn main() -> io::Result<()> {
                         RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                         threading,
                     ) {
-                        Ok((problems, total_time)) => {
+                        Ok(CheckedFile {
+                            problems,
+                            total_time,
+                            ..
+                        }) => {
+                            let problems = problems.with_warnings_as_errors(warnings_as_errors);
+
                             problems.print_error_warning_count(total_time);
                             Ok(problems.exit_code())
                         }
 
                         Err(LoadingProblem::FormattedReport(report)) => {
-                            print!("{report}");
+                            print_formatted_report(&report);
 
                             Ok(1)
                         }
@@ -294,11 +380,11 @@ fn main() -> io::Result<()> {
             }
         }
         Some((CMD_REPL, matches)) => {
-            let has_color = !matches.get_one::<bool>(FLAG_NO_COLOR).unwrap();
             let has_header = !matches.get_one::<bool>(FLAG_NO_HEADER).unwrap();
 
-            Ok(roc_repl_cli::main(has_color, has_header))
+            Ok(roc_repl_cli::main(has_header))
         }
+        Some((CMD_DEPS, matches)) => deps(matches),
         Some((CMD_DOCS, matches)) => {
             let root_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let out_dir = matches.get_one::<OsString>(FLAG_OUTPUT).unwrap();
@@ -312,6 +398,8 @@ fn main() -> io::Result<()> {
             let to_stdout = matches.get_flag(FLAG_STDOUT);
             let format_mode = if to_stdout {
                 FormatMode::WriteToStdout
+            } else if matches.get_flag(FLAG_VERIFY) {
+                FormatMode::Verify
             } else {
                 match matches.get_flag(FLAG_CHECK) {
                     true => FormatMode::CheckOnly,
@@ -320,7 +408,7 @@ fn main() -> io::Result<()> {
             };
 
             if from_stdin && matches!(format_mode, FormatMode::WriteToFile) {
-                eprintln!("When using the --stdin flag, either the --check or the --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
+                eprintln!("When using the --stdin flag, one of the --check, --verify or --stdout flags must also be specified. (Otherwise, it's unclear what filename to write to!)");
                 std::process::exit(1);
             }
 
@@ -370,17 +458,21 @@ fn main() -> io::Result<()> {
                     std::process::exit(1);
                 });
 
-                match format_src(&arena, src) {
+                match format_src(&arena, Path::new("<stdin>"), src) {
                     Ok(formatted_src) => {
                         match format_mode {
                             FormatMode::CheckOnly => {
-                                if src == formatted_src {
-                                    eprintln!("One or more files need to be reformatted.");
+                                if src != formatted_src {
+                                    eprintln!(
+                                        "The code from stdin failed `roc format --check` ({}).\nYou can see the formatted code with `roc format --stdin --stdout`.",
+                                        diff_summary(src, &formatted_src)
+                                    );
                                     1
                                 } else {
                                     0
                                 }
                             }
+                            FormatMode::Verify => 0,
                             FormatMode::WriteToStdout => {
                                 std::io::stdout()
                                     .lock()
@@ -396,6 +488,10 @@ fn main() -> io::Result<()> {
                             }
                         }
                     }
+                    Err(FormatProblem::InvalidSource { report }) => {
+                        print_formatted_report(&report);
+                        1
+                    }
                     Err(problem) => {
                         eprintln!("`roc format` failed: {problem:?}");
                         1
@@ -417,12 +513,113 @@ fn main() -> io::Result<()> {
             println!("roc {}", VERSION);
             Ok(0)
         }
+        Some((CMD_TARGETS, _)) => {
+            let default_target = Target::default();
+
+            for target in Target::iter() {
+                let name: &'static str = target.into();
+                let linker = if roc_linker::supported(LinkType::Executable, target) {
+                    "surgical"
+                } else {
+                    "legacy"
+                };
+                let default = if target == default_target {
+                    " (default)"
+                } else {
+                    ""
+                };
+
+                println!(
+                    "{name:<16} {:<16} {linker} linker{default}",
+                    available_backends(target)
+                );
+            }
+
+            println!(
+                "\nBuiltins: {VERSION} (source hash {:016x})",
+                roc_builtins::roc::sources_hash()
+            );
+
+            Ok(0)
+        }
         _ => unreachable!(),
     }?;
 
     std::process::exit(exit_code);
 }
 
+/// The code generation backends this build of `roc` can use for a target.
+fn available_backends(target: Target) -> String {
+    let llvm = match target.architecture() {
+        Architecture::X86_64 => cfg!(feature = "target-x86_64"),
+        Architecture::X86_32 => cfg!(any(feature = "target-x86", feature = "target-x86_64")),
+        Architecture::Aarch64 => cfg!(feature = "target-aarch64"),
+        Architecture::Aarch32 => cfg!(feature = "target-arm"),
+        Architecture::Wasm32 => cfg!(feature = "target-wasm32"),
+    };
+
+    // `--dev` uses the wasm backend for wasm32, and the assembly backend for the
+    // 64-bit targets; roc_build only includes the latter without wasm32 support.
+    let dev = match target.architecture() {
+        Architecture::Wasm32 if cfg!(feature = "target-wasm32") => Some("wasm"),
+        Architecture::X86_64 | Architecture::Aarch64 if !cfg!(feature = "target-wasm32") => {
+            Some("dev")
+        }
+        _ => None,
+    };
+
+    let backends: Vec<&str> = llvm.then_some("llvm").into_iter().chain(dev).collect();
+
+    if backends.is_empty() {
+        "none".to_string()
+    } else {
+        backends.join(", ")
+    }
+}
+
+/// The modification time and a hash of the contents of each file, to notice when they change.
+fn source_snapshot(paths: &[PathBuf]) -> Vec<(Option<SystemTime>, Option<u64>)> {
+    paths
+        .iter()
+        .map(|path| (modified_time(path), contents_hash(path)))
+        .collect()
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+fn contents_hash(path: &Path) -> Option<u64> {
+    use std::hash::{Hash, Hasher};
+
+    fs::read(path).ok().map(|bytes| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        bytes.hash(&mut hasher);
+        hasher.finish()
+    })
+}
+
+/// Block until the contents of any of these files differ from the snapshot.
+/// Saving a file without changing it (or just touching it) doesn't count.
+fn wait_for_changes(paths: &[PathBuf], mut snapshot: Vec<(Option<SystemTime>, Option<u64>)>) {
+    loop {
+        std::thread::sleep(Duration::from_millis(500));
+
+        for (path, (last_modified, last_hash)) in paths.iter().zip(snapshot.iter_mut()) {
+            let modified = modified_time(path);
+
+            // Only read a file again once its modification time says something happened.
+            if modified != *last_modified {
+                if contents_hash(path) != *last_hash {
+                    return;
+                }
+
+                *last_modified = modified;
+            }
+        }
+    }
+}
+
 fn read_all_roc_files(
     dir: &OsString,
     roc_file_paths: &mut Vec<OsString>,
@@ -8,10 +8,11 @@ extern crate roc_module;
 
 #[cfg(test)]
 mod cli_tests {
+    use cli_test_utils::command::{run_command, CmdOut};
     use cli_test_utils::exec_cli::ExecCli;
-    use cli_test_utils::helpers::{dir_from_root, file_from_root};
+    use cli_test_utils::helpers::{dir_from_root, file_from_root, path_to_roc_binary};
     use const_format::concatcp;
-    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_FORMAT, CMD_TEST};
+    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_DEPS, CMD_FORMAT, CMD_TARGETS, CMD_TEST};
 
     #[cfg(all(unix, not(target_os = "macos")))]
     const ALLOW_VALGRIND: bool = true;
@@ -38,6 +39,9 @@ mod cli_tests {
     const SUPPRESS_BUILD_HOST_WARNING_FLAG: &str =
         concatcp!("--", roc_cli::FLAG_SUPPRESS_BUILD_HOST_WARNING);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const STDIN_FLAG: &str = concatcp!("--", roc_cli::FLAG_STDIN);
+    const WARNINGS_AS_ERRORS_FLAG: &str = concatcp!("--", roc_cli::FLAG_WARNINGS_AS_ERRORS);
+    const ERROR_FORMAT_JSON_FLAG: &str = concatcp!("--", roc_cli::FLAG_ERROR_FORMAT, "=", "json");
     #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
 
@@ -246,6 +250,23 @@ mod cli_tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn no_expects_found() {
+        let cli_test = ExecCli::new(
+            CMD_TEST,
+            file_from_root(
+                "crates/cli/tests/test-projects/module_params",
+                "NoParams.roc",
+            ),
+        );
+
+        // Finding no expects at all is treated as a failure, so CI notices if tests stop running
+        let cli_test_out = cli_test.run();
+        cli_test_out.assert_nonzero_exit();
+        cli_test_out.assert_stdout_and_stderr_ends_with("No expectations were found.\n");
+    }
+
     #[test]
     #[cfg_attr(windows, ignore)]
     fn transitive_expects_verbose() {
@@ -339,6 +360,22 @@ mod cli_tests {
             cli_build.run().assert_clean_stdout(expected_out);
         }
 
+        #[test]
+        #[cfg_attr(windows, ignore)]
+        fn check_module_without_problems() {
+            let cli_check = ExecCli::new(
+                CMD_CHECK,
+                file_from_root(
+                    "crates/cli/tests/test-projects/module_params",
+                    "NoParams.roc",
+                ),
+            );
+
+            let expected_out = "0 error and 0 warning found in <ignored for test> ms\n";
+
+            cli_check.run().assert_clean_stdout(expected_out);
+        }
+
         #[test]
         #[cfg_attr(windows, ignore)]
         fn import_in_expect() {
@@ -484,6 +521,70 @@ mod cli_tests {
             });
         }
 
+        #[test]
+        #[cfg_attr(windows, ignore)]
+        fn build_warnings_as_errors() {
+            build_platform_host();
+
+            let binary_path = file_from_root(
+                "crates/cli/tests/test-projects/test-platform-simple-zig",
+                "app_with_warning",
+            );
+            let _ = std::fs::remove_file(&binary_path);
+
+            let cli_build = ExecCli::new(
+                CMD_BUILD,
+                file_from_root(
+                    "crates/cli/tests/test-projects/test-platform-simple-zig",
+                    "app_with_warning.roc",
+                ),
+            )
+            .arg(WARNINGS_AS_ERRORS_FLAG);
+
+            let cli_build_out = cli_build.run();
+
+            assert_eq!(cli_build_out.status.code(), Some(1), "{cli_build_out}");
+            assert!(cli_build_out
+                .stdout
+                .contains("Nothing was built, because --warnings-as-errors was set."));
+            assert!(!binary_path.exists());
+        }
+
+        #[test]
+        #[cfg_attr(windows, ignore)]
+        fn build_emits_llvm_ir_and_asm() {
+            build_platform_host();
+
+            let dir = "crates/cli/tests/test-projects/test-platform-simple-zig";
+            let emitted_files = [
+                file_from_root(dir, "app.ll"),
+                file_from_root(dir, "app.unoptimized.ll"),
+                file_from_root(dir, "app.s"),
+            ];
+
+            for file in &emitted_files {
+                let _ = std::fs::remove_file(file);
+            }
+
+            let cli_build = ExecCli::new(CMD_BUILD, file_from_root(dir, "app.roc"))
+                .arg(concatcp!("--", roc_cli::FLAG_EMIT_LLVM_IR))
+                .arg(concatcp!("--", roc_cli::FLAG_EMIT_ASM));
+
+            let cli_build_out = cli_build.run();
+
+            assert!(cli_build_out.status.success(), "{cli_build_out}");
+
+            for file in &emitted_files {
+                assert!(
+                    std::fs::metadata(file).map_or(false, |meta| meta.len() > 0),
+                    "{} was not emitted:\n{cli_build_out}",
+                    file.display()
+                );
+
+                std::fs::remove_file(file).unwrap();
+            }
+        }
+
         #[test]
         #[cfg_attr(windows, ignore)]
         fn run_multi_dep_str() {
@@ -1296,6 +1397,47 @@ mod cli_tests {
         insta::assert_snapshot!(cli_check_out.normalize_stdout_and_stderr());
     }
 
+    #[test]
+    fn known_type_error_as_json() {
+        let cli_check = ExecCli::new(
+            CMD_CHECK,
+            file_from_root("crates/cli/tests/test-projects/known_bad", "TypeError.roc"),
+        )
+        .arg(ERROR_FORMAT_JSON_FLAG);
+
+        let cli_check_out = cli_check.run();
+        cli_check_out.assert_nonzero_exit();
+
+        let reports: Vec<serde_json::Value> = cli_check_out
+            .stderr
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(reports.len(), 1, "{cli_check_out}");
+
+        let report = &reports[0];
+        let region = serde_json::json!({
+            "start": { "line": 5, "column": 5, "offset": 112 },
+            "end": { "line": 5, "column": 77, "offset": 184 },
+        });
+
+        assert_eq!(report["severity"], "error");
+        assert_eq!(report["code"], "TYPE_MISMATCH");
+        assert!(report["filename"]
+            .as_str()
+            .unwrap()
+            .ends_with("TypeError.roc"));
+        assert_eq!(report["region"], region);
+        assert_eq!(
+            report["labels"][0],
+            serde_json::json!({
+                "message": "Something is off with the body of the `main` definition:",
+                "region": region,
+            })
+        );
+    }
+
     #[test]
     #[cfg_attr(windows, ignore)]
     fn test_module_imports_unknown_pkg() {
@@ -1339,6 +1481,108 @@ mod cli_tests {
         );
     }
 
+    #[test]
+    fn deps_json_lists_transitive_imports() {
+        let cli_deps = ExecCli::new(
+            CMD_DEPS,
+            file_from_root(
+                "crates/cli/tests/test-projects/fixtures/transitive-deps",
+                "direct-zero.roc",
+            ),
+        )
+        .arg(concatcp!("--", roc_cli::FLAG_JSON));
+
+        let cli_deps_out = cli_deps.run();
+        cli_deps_out.assert_zero_exit();
+
+        let json: serde_json::Value = serde_json::from_str(cli_deps_out.stdout.trim())
+            .unwrap_or_else(|err| panic!("Invalid JSON ({err}):\n{cli_deps_out}"));
+
+        let app = json["modules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|module| {
+                module["name"]
+                    .as_str()
+                    .unwrap()
+                    .ends_with("direct-zero.roc")
+            })
+            .unwrap_or_else(|| panic!("The app module is missing:\n{cli_deps_out}"));
+
+        let names = |key: &str| -> Vec<String> {
+            app[key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|name| name.as_str().unwrap().to_string())
+                .collect()
+        };
+
+        // The app only imports Zero, which imports One, which imports Two.
+        let imports = names("imports");
+        let transitive_imports = names("transitive_imports");
+
+        assert!(
+            imports.iter().any(|name| name.contains("Zero")),
+            "{imports:?}"
+        );
+        assert!(
+            !imports.iter().any(|name| name.contains("Two")),
+            "{imports:?}"
+        );
+
+        for module in ["Zero", "One", "Two"] {
+            assert!(
+                transitive_imports.iter().any(|name| name.contains(module)),
+                "{module} is missing from {transitive_imports:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn deps_dot_quotes_module_names() {
+        let cli_deps = ExecCli::new(
+            CMD_DEPS,
+            file_from_root(
+                "crates/cli/tests/test-projects/fixtures/transitive-deps",
+                "direct-zero.roc",
+            ),
+        )
+        .arg(concatcp!("--", roc_cli::FLAG_DOT));
+
+        let cli_deps_out = cli_deps.run();
+        cli_deps_out.assert_zero_exit();
+
+        let stdout = cli_deps_out.stdout.trim();
+
+        assert!(stdout.starts_with("digraph imports {"), "{stdout}");
+        assert!(stdout.ends_with('}'), "{stdout}");
+        // Names are plain DOT strings, not Rust debug strings.
+        assert!(!stdout.contains("\\u{"), "{stdout}");
+        assert!(stdout.contains("Zero\" -> \""), "{stdout}");
+    }
+
+    #[test]
+    fn deps_explains_import_cycle() {
+        let cli_deps = ExecCli::new(
+            CMD_DEPS,
+            file_from_root(
+                "crates/cli/tests/test-projects/known_bad",
+                "ImportCycleA.roc",
+            ),
+        );
+
+        let cli_deps_out = cli_deps.run();
+
+        assert_eq!(cli_deps_out.status.code(), Some(1), "{cli_deps_out}");
+
+        let output = cli_deps_out.normalize_stdout_and_stderr();
+
+        assert!(output.contains("IMPORT CYCLE"), "{output}");
+        assert!(output.contains("ImportCycleB"), "{output}");
+    }
+
     #[test]
     fn known_type_error_with_long_path() {
         let cli_check = ExecCli::new(
@@ -1387,6 +1631,121 @@ mod cli_tests {
         insta::assert_snapshot!(cli_check_out.normalize_stdout_and_stderr());
     }
 
+    #[test]
+    fn check_warnings_as_errors() {
+        let cli_check = ExecCli::new(
+            CMD_CHECK,
+            file_from_root(
+                "crates/cli/tests/test-projects/known_bad",
+                "UnusedImport.roc",
+            ),
+        )
+        .arg(WARNINGS_AS_ERRORS_FLAG);
+
+        let cli_check_out = cli_check.run();
+
+        assert_eq!(cli_check_out.status.code(), Some(1), "{cli_check_out}");
+        assert!(cli_check_out
+            .normalize_stdout_and_stderr()
+            .contains("1 error and 0 warning found"));
+    }
+
+    #[test]
+    fn check_watch_rechecks_when_an_imported_module_changes() {
+        use std::io::{BufRead, BufReader};
+        use std::process::{Command, Stdio};
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        // The platform lives outside the app's directory, so it's only found
+        // through the module graph.
+        let dir = tempfile::tempdir().unwrap();
+        let app_dir = dir.path().join("app");
+        let platform_dir = dir.path().join("platform");
+        std::fs::create_dir(&app_dir).unwrap();
+        std::fs::create_dir(&platform_dir).unwrap();
+
+        let platform_file = platform_dir.join("main.roc");
+        std::fs::copy(
+            file_from_root(
+                "crates/cli/tests/test-projects/test-platform-simple-zig",
+                "main.roc",
+            ),
+            &platform_file,
+        )
+        .unwrap();
+
+        let app_file = app_dir.join("main.roc");
+        std::fs::write(
+            &app_file,
+            "app [main] { pf: platform \"../platform/main.roc\" }\n\nmain : Str\nmain = \"hi\"\n",
+        )
+        .unwrap();
+
+        let mut child = Command::new(path_to_roc_binary())
+            .arg(CMD_CHECK)
+            .arg(concatcp!("--", roc_cli::FLAG_WATCH))
+            .arg(&app_file)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let stdout = child.stdout.take().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let wait_until_watching = || loop {
+            let line = receiver
+                .recv_timeout(Duration::from_secs(120))
+                .expect("roc check --watch did not finish checking in time");
+
+            if line.starts_with("Watching for changes") {
+                break;
+            }
+        };
+
+        wait_until_watching();
+
+        let mut platform_src = std::fs::read_to_string(&platform_file).unwrap();
+        platform_src.push_str("\n# changed\n");
+        std::fs::write(&platform_file, platform_src).unwrap();
+
+        wait_until_watching();
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore = "running tests does not work on windows right now")]
+    fn test_warnings_as_errors() {
+        let cli_test = ExecCli::new(
+            CMD_TEST,
+            file_from_root(
+                "crates/cli/tests/test-projects/known_bad",
+                "UnusedImport.roc",
+            ),
+        )
+        .arg(WARNINGS_AS_ERRORS_FLAG);
+
+        let cli_test_out = cli_test.run();
+
+        assert_eq!(cli_test_out.status.code(), Some(1), "{cli_test_out}");
+        assert!(cli_test_out
+            .stdout
+            .contains("Not running tests, because --warnings-as-errors was set."));
+    }
+
     #[test]
     fn format_check_good() {
         ExecCli::new(
@@ -1435,6 +1794,68 @@ mod cli_tests {
         .assert_clean_success();
     }
 
+    fn format_check_stdin(file_name: &str) -> CmdOut {
+        let src = std::fs::read_to_string(file_from_root(
+            "crates/cli/tests/test-projects/fixtures/format",
+            file_name,
+        ))
+        .unwrap();
+
+        let mut roc_cli_command = std::process::Command::new(path_to_roc_binary());
+        roc_cli_command.args([CMD_FORMAT, CHECK_FLAG, STDIN_FLAG]);
+
+        run_command(roc_cli_command, Some(&src))
+    }
+
+    #[test]
+    fn format_check_stdin_good() {
+        format_check_stdin("formatted.roc").assert_clean_success();
+    }
+
+    #[test]
+    fn format_check_stdin_reformatting_needed() {
+        let cli_format_out = format_check_stdin("not-formatted.roc");
+
+        assert_eq!(cli_format_out.status.code(), Some(1), "\n{cli_format_out}");
+        assert!(
+            cli_format_out
+                .stderr
+                .contains("The code from stdin failed `roc format --check` (+"),
+            "\n{cli_format_out}"
+        );
+    }
+
+    #[test]
+    fn targets_lists_backends_and_builtins() {
+        let mut roc_cli_command = std::process::Command::new(path_to_roc_binary());
+        roc_cli_command.arg(CMD_TARGETS);
+
+        let cli_targets_out = run_command(roc_cli_command, None);
+        cli_targets_out.assert_clean_success();
+
+        let linux_x64 = cli_targets_out
+            .stdout
+            .lines()
+            .find(|line| line.starts_with("linux-x64 "))
+            .unwrap_or_else(|| panic!("no line for linux-x64:\n{cli_targets_out}"));
+        assert!(linux_x64.contains("llvm"), "{linux_x64}");
+
+        let wasm32 = cli_targets_out
+            .stdout
+            .lines()
+            .find(|line| line.starts_with("wasm32 "))
+            .unwrap_or_else(|| panic!("no line for wasm32:\n{cli_targets_out}"));
+        assert!(wasm32.contains("wasm"), "{wasm32}");
+
+        assert!(
+            cli_targets_out
+                .stdout
+                .lines()
+                .any(|line| line.starts_with("Builtins: ") && line.contains("source hash")),
+            "{cli_targets_out}"
+        );
+    }
+
     #[test]
     fn module_params_effectful_param() {
         let cli_check = ExecCli::new(
@@ -0,0 +1,5 @@
+module [a]
+
+import ImportCycleB
+
+a = ImportCycleB.b
@@ -0,0 +1,5 @@
+module [b]
+
+import ImportCycleA
+
+b = ImportCycleA.a
@@ -0,0 +1,7 @@
+app [main] { pf: platform "main.roc" }
+
+main : Str
+main =
+    unused = "not used anywhere"
+
+    "STUBBED APP"
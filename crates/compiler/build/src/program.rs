@@ -2,7 +2,7 @@ use crate::link::{link, preprocess_host_wasm32, rebuild_host, LinkType, LinkingS
 use bumpalo::collections::CollectIn;
 use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
@@ -13,8 +13,8 @@ use roc_load::{
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::{
-    cli::{report_problems, Problems},
-    report::{RenderTarget, DEFAULT_PALETTE},
+    cli::{print_formatted_report, report_problems, Problems},
+    report::{terminal_palette, terminal_render_target},
 };
 use roc_target::{Architecture, Target};
 use std::ffi::OsStr;
@@ -43,6 +43,7 @@ pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Proble
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        &mut loaded.type_hints,
     )
 }
 
@@ -52,6 +53,7 @@ pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        &mut loaded.type_hints,
     )
 }
 
@@ -84,6 +86,7 @@ pub struct CodeGenOptions {
     pub opt_level: OptLevel,
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
+    pub emit_asm: bool,
     pub fuzz: bool,
 }
 
@@ -102,6 +105,7 @@ pub fn gen_from_mono_module<'a>(
     let path = roc_file_path;
     let debug = code_gen_options.emit_debug_info;
     let emit_llvm_ir = code_gen_options.emit_llvm_ir;
+    let emit_asm = code_gen_options.emit_asm;
     let fuzz = code_gen_options.fuzz;
     let opt = code_gen_options.opt_level;
 
@@ -139,6 +143,7 @@ pub fn gen_from_mono_module<'a>(
             backend_mode,
             debug,
             emit_llvm_ir,
+            emit_asm,
             fuzz,
         ),
     }
@@ -157,6 +162,7 @@ fn gen_from_mono_module_llvm<'a>(
     backend_mode: LlvmBackendMode,
     emit_debug_info: bool,
     emit_llvm_ir: bool,
+    emit_asm: bool,
     fuzz: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
@@ -249,6 +255,15 @@ fn gen_from_mono_module_llvm<'a>(
         EntryPoint::Test => roc_mono::ir::EntryPoint::Expects { symbols: &[] },
     };
 
+    let unoptimized_ll_file = app_ll_file.with_extension("unoptimized.ll");
+
+    if emit_llvm_ir {
+        eprintln!(
+            "Emitting unoptimized LLVM IR to {}",
+            &unoptimized_ll_file.display()
+        );
+    }
+
     roc_gen_llvm::llvm::build::build_procedures(
         &env,
         &loaded.layout_interner,
@@ -257,6 +272,7 @@ fn gen_from_mono_module_llvm<'a>(
         loaded.host_exposed_lambda_sets,
         entry_point,
         Some(&app_ll_file),
+        emit_llvm_ir.then_some(unoptimized_ll_file.as_path()),
         &loaded.glue_layouts,
     );
 
@@ -389,6 +405,25 @@ fn gen_from_mono_module_llvm<'a>(
             "{bc_to_object_output:#?}"
         );
 
+        if emit_asm {
+            let app_asm_file = app_ll_file.with_extension("s");
+
+            eprintln!("Emitting assembly to {}", &app_asm_file.display());
+
+            let bc_to_asm_output = Command::new("llc")
+                .args([
+                    "-relocation-model=pic",
+                    "-filetype=asm",
+                    &temp_app_processed_file_str,
+                    "-o",
+                    app_asm_file.to_str().unwrap(),
+                ])
+                .output()
+                .unwrap();
+
+            assert!(bc_to_asm_output.status.success(), "{bc_to_asm_output:#?}");
+        }
+
         MemoryBuffer::create_from_file(&temp_app_o_file).expect("memory buffer creation works")
     } else {
         if emit_llvm_ir {
@@ -403,11 +438,25 @@ fn gen_from_mono_module_llvm<'a>(
                 let target_machine =
                     target::target_machine(target, convert_opt_level(opt_level), reloc).unwrap();
 
+                if emit_asm {
+                    let app_asm_file = app_ll_file.with_extension("s");
+
+                    eprintln!("Emitting assembly to {}", &app_asm_file.display());
+
+                    target_machine
+                        .write_to_file(env.module, FileType::Assembly, &app_asm_file)
+                        .expect("Writing .s file failed");
+                }
+
                 target_machine
                     .write_to_memory_buffer(env.module, FileType::Object)
                     .expect("Writing .o file failed")
             }
             Architecture::Wasm32 => {
+                if emit_asm {
+                    user_error!("Cannot emit assembly when building for wasm32, because the LLVM bitcode is passed on to zig rather than compiled to assembly. Use --emit-llvm-ir instead.");
+                }
+
                 // Useful for debugging
                 // module.print_to_file(app_ll_file);
                 module.write_bitcode_to_memory()
@@ -657,6 +706,12 @@ pub enum BuildFileError<'a> {
         module: LoadedModule,
         total_time: Duration,
     },
+    /// `--warnings-as-errors` turned the reported problems into errors,
+    /// so nothing was built.
+    WarningsAsErrors {
+        problems: Problems,
+        total_time: Duration,
+    },
 }
 
 impl<'a> BuildFileError<'a> {
@@ -688,13 +743,18 @@ pub fn handle_error_module(
     if print_run_anyway_hint {
         // If you're running "main.roc" then you can just do `roc run`
         // to re-run the program.
-        print!(".\n\nYou can run the program anyway with \x1B[32mroc run");
+        let palette = terminal_palette();
+
+        print!(
+            ".\n\nYou can run the program anyway with {}roc run",
+            palette.success
+        );
 
         if filename != DEFAULT_ROC_FILENAME {
             print!(" {}", &filename.to_string_lossy());
         }
 
-        println!("\x1B[39m");
+        println!("{}", palette.reset);
     }
 
     Ok(problems.exit_code())
@@ -703,7 +763,7 @@ pub fn handle_error_module(
 pub fn handle_loading_problem(problem: LoadingProblem) -> std::io::Result<i32> {
     match problem {
         LoadingProblem::FormattedReport(report) => {
-            print!("{report}");
+            print_formatted_report(&report);
             Ok(1)
         }
         _ => {
@@ -728,8 +788,8 @@ pub fn standard_load_config(
     LoadConfig {
         target,
         function_kind: FunctionKind::from_env(),
-        render: RenderTarget::ColorTerminal,
-        palette: DEFAULT_PALETTE,
+        render: terminal_render_target(),
+        palette: terminal_palette(),
         threading,
         exec_mode,
     }
@@ -746,6 +806,7 @@ pub fn build_file<'a>(
     linking_strategy: LinkingStrategy,
     build_host: bool,
     suppress_build_host_warning: bool,
+    warnings_as_errors: bool,
     wasm_dev_stack_bytes: Option<u32>,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
@@ -772,6 +833,7 @@ pub fn build_file<'a>(
         linking_strategy,
         build_host,
         suppress_build_host_warning,
+        warnings_as_errors,
         wasm_dev_stack_bytes,
         loaded,
         compilation_start,
@@ -848,11 +910,24 @@ fn build_loaded_file<'a>(
     linking_strategy: LinkingStrategy,
     build_host_requested: bool,
     suppress_build_host_warning: bool,
+    warnings_as_errors: bool,
     wasm_dev_stack_bytes: Option<u32>,
-    loaded: roc_load::MonomorphizedModule<'a>,
+    mut loaded: roc_load::MonomorphizedModule<'a>,
     compilation_start: Instant,
     out_path: Option<&Path>,
 ) -> Result<BuiltFile<'a>, BuildFileError<'a>> {
+    // Report problems before touching the output path (the surgical linker copies the
+    // host there), so that --warnings-as-errors can stop the build without leaving a binary.
+    let problems = report_problems_monomorphized(&mut loaded);
+    let loaded = loaded;
+
+    if warnings_as_errors && problems.errors + problems.warnings > 0 {
+        return Err(BuildFileError::WarningsAsErrors {
+            problems: problems.with_warnings_as_errors(true),
+            total_time: compilation_start.elapsed(),
+        });
+    }
+
     // get the platform path from the app header
     let platform_main_roc_path = match &loaded.entry_point {
         EntryPoint::Executable { platform_path, .. } => platform_path.to_path_buf(),
@@ -924,12 +999,6 @@ fn build_loaded_file<'a>(
         }
     }
 
-    // This only needs to be mutable for report_problems. This can't be done
-    // inside a nested scope without causing a borrow error!
-    let mut loaded = loaded;
-    let problems = report_problems_monomorphized(&mut loaded);
-    let loaded = loaded;
-
     let (roc_app_bytes, code_gen_timing, expect_metadata) = gen_from_mono_module(
         arena,
         loaded,
@@ -1363,6 +1432,14 @@ fn spawn_legacy_host_build_thread(
     })
 }
 
+/// The outcome of type checking a file with [check_file].
+pub struct CheckedFile {
+    pub problems: Problems,
+    pub total_time: Duration,
+    /// The source file of every module that was checked, e.g. to watch them for changes.
+    pub module_paths: Vec<PathBuf>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn check_file<'a>(
     arena: &'a Bump,
@@ -1371,7 +1448,7 @@ pub fn check_file<'a>(
     emit_timings: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
-) -> Result<(Problems, Duration), LoadingProblem<'a>> {
+) -> Result<CheckedFile, LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
     // only used for generating errors. We don't do code generation, so hardcoding should be fine
@@ -1383,9 +1460,8 @@ pub fn check_file<'a>(
     let load_config = LoadConfig {
         target,
         function_kind: FunctionKind::from_env(),
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
-        palette: DEFAULT_PALETTE,
+        render: terminal_render_target(),
+        palette: terminal_palette(),
         threading,
         exec_mode: ExecutionMode::Check,
     };
@@ -1439,7 +1515,17 @@ pub fn check_file<'a>(
         println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
     }
 
-    Ok((report_problems_typechecked(&mut loaded), compilation_end))
+    let module_paths = loaded
+        .sources
+        .values()
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    Ok(CheckedFile {
+        problems: report_problems_typechecked(&mut loaded),
+        total_time: compilation_end,
+        module_paths,
+    })
 }
 
 pub fn build_str_test<'a>(
@@ -1455,6 +1541,7 @@ pub fn build_str_test<'a>(
         opt_level: OptLevel::Normal,
         emit_debug_info: false,
         emit_llvm_ir: false,
+        emit_asm: false,
         fuzz: false,
     };
 
@@ -1496,6 +1583,7 @@ pub fn build_str_test<'a>(
         linking_strategy,
         build_host_requested,
         suppress_build_host_warning,
+        false,
         wasm_dev_stack_bytes,
         loaded,
         compilation_start,
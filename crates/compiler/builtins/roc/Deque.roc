@@ -0,0 +1,288 @@
+module [
+    Deque,
+    empty,
+    withCapacity,
+    single,
+    len,
+    isEmpty,
+    pushFront,
+    pushBack,
+    popFront,
+    popBack,
+    first,
+    last,
+    walk,
+    map,
+    toList,
+    fromList,
+]
+
+import List
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import Num exposing [U64]
+import Hash exposing [Hash, Hasher]
+import Inspect exposing [Inspect, Inspector, InspectFormatter]
+
+## A [double-ended queue](https://en.wikipedia.org/wiki/Double-ended_queue),
+## which can efficiently add and remove elements at either end.
+##
+## Prepending to a [List] with [List.prepend] has to move every element in the
+## list, so it takes longer the bigger the list gets. A `Deque` supports
+## [pushFront], [pushBack], [popFront] and [popBack] in amortized constant time,
+## which makes it a good fit for breadth-first searches and work lists.
+##
+## ```roc
+## queue =
+##     Deque.empty {}
+##     |> Deque.pushBack 2
+##     |> Deque.pushBack 3
+##     |> Deque.pushFront 1
+##
+## expect Deque.toList queue == [1, 2, 3]
+## ```
+##
+## Internally the elements are split across two lists, rather than stored in
+## a single ring buffer: the front half is stored in reverse order, so that
+## both ends of the deque are at the end of a list. When one of the lists runs
+## out while popping, half of the elements in the other list are moved over.
+##
+## This keeps `Deque` an ordinary Roc module that every backend supports, but
+## it has some costs compared to a ring buffer:
+##
+## - The pop that moves elements between the lists takes linear time. Pushing
+##   and popping are only constant time on average over many operations, so
+##   an individual pop can be slow.
+## - Those averages assume each `Deque` is only used once. Popping from the same
+##   `Deque` value again and again can move the same elements every time.
+## - Getting an element from the middle of the deque by its index would not be
+##   constant time, which is why there is no `get`.
+Deque a := { front : List a, back : List a }
+    implements [
+        Eq {
+            isEq,
+        },
+        Hash {
+            hash: hashDeque,
+        },
+        Inspect {
+            toInspector: toInspectorDeque,
+        },
+    ]
+
+isEq : Deque a, Deque a -> Bool where a implements Eq
+isEq = \xs, ys ->
+    if len xs != len ys then
+        Bool.false
+    else
+        toList xs == toList ys
+
+hashDeque : hasher, Deque a -> hasher where a implements Hash, hasher implements Hasher
+hashDeque = \hasher, deque -> walk deque hasher Hash.hash
+
+toInspectorDeque : Deque a -> Inspector f where a implements Inspect, f implements InspectFormatter
+toInspectorDeque = \deque ->
+    Inspect.custom \fmt ->
+        Inspect.apply (Inspect.list deque walk Inspect.toInspector) fmt
+
+## Creates a new empty `Deque`.
+## ```roc
+## expect Deque.len (Deque.empty {}) == 0
+## ```
+empty : {} -> Deque *
+empty = \{} -> @Deque { front: [], back: [] }
+
+## Returns a `Deque` with space allocated for a number of elements. This
+## may provide a performance optimization if you know how many elements will
+## be pushed.
+withCapacity : U64 -> Deque *
+withCapacity = \cap ->
+    @Deque { front: [], back: List.withCapacity cap }
+
+## Creates a `Deque` with a single element.
+## ```roc
+## expect Deque.toList (Deque.single "a") == ["a"]
+## ```
+single : a -> Deque a
+single = \elem ->
+    @Deque { front: [], back: [elem] }
+
+## Returns the number of elements in the `Deque`.
+## ```roc
+## expect Deque.len (Deque.fromList [1, 2, 3]) == 3
+## ```
+len : Deque * -> U64
+len = \@Deque { front, back } ->
+    List.len front + List.len back
+
+## Checks if the `Deque` is empty.
+## ```roc
+## expect Deque.isEmpty (Deque.empty {})
+## expect !(Deque.isEmpty (Deque.single 1))
+## ```
+isEmpty : Deque * -> Bool
+isEmpty = \@Deque { front, back } ->
+    List.isEmpty front && List.isEmpty back
+
+## Adds an element to the front of the `Deque`.
+## ```roc
+## expect
+##     Deque.fromList [2, 3]
+##     |> Deque.pushFront 1
+##     |> Deque.toList
+##     == [1, 2, 3]
+## ```
+pushFront : Deque a, a -> Deque a
+pushFront = \@Deque { front, back }, elem ->
+    @Deque { front: List.append front elem, back }
+
+## Adds an element to the back of the `Deque`.
+## ```roc
+## expect
+##     Deque.fromList [1, 2]
+##     |> Deque.pushBack 3
+##     |> Deque.toList
+##     == [1, 2, 3]
+## ```
+pushBack : Deque a, a -> Deque a
+pushBack = \@Deque { front, back }, elem ->
+    @Deque { front, back: List.append back elem }
+
+## Removes the element at the front of the `Deque`, returning it along with
+## the remaining elements.
+## ```roc
+## expect
+##     when Deque.popFront (Deque.fromList [1, 2, 3]) is
+##         Ok { deque, elem } -> elem == 1 && Deque.toList deque == [2, 3]
+##         Err DequeWasEmpty -> Bool.false
+## ```
+popFront : Deque a -> Result { deque : Deque a, elem : a } [DequeWasEmpty]
+popFront = \@Deque { front, back } ->
+    when List.last front is
+        Ok elem ->
+            Ok { deque: @Deque { front: List.dropLast front 1, back }, elem }
+
+        Err ListWasEmpty ->
+            if List.isEmpty back then
+                Err DequeWasEmpty
+            else
+                # Move the first half of `back` over, so that repeatedly popping
+                # from alternating ends doesn't keep moving every element.
+                { before, others } = List.splitAt back (half back)
+
+                popFront (@Deque { front: List.reverse before, back: others })
+
+## Removes the element at the back of the `Deque`, returning it along with
+## the remaining elements.
+## ```roc
+## expect
+##     when Deque.popBack (Deque.fromList [1, 2, 3]) is
+##         Ok { deque, elem } -> elem == 3 && Deque.toList deque == [1, 2]
+##         Err DequeWasEmpty -> Bool.false
+## ```
+popBack : Deque a -> Result { deque : Deque a, elem : a } [DequeWasEmpty]
+popBack = \@Deque { front, back } ->
+    when List.last back is
+        Ok elem ->
+            Ok { deque: @Deque { front, back: List.dropLast back 1 }, elem }
+
+        Err ListWasEmpty ->
+            if List.isEmpty front then
+                Err DequeWasEmpty
+            else
+                # `front` is stored reversed, so its first half holds the
+                # elements closest to the back of the deque.
+                { before, others } = List.splitAt front (half front)
+
+                popBack (@Deque { front: others, back: List.reverse before })
+
+half : List * -> U64
+half = \list ->
+    (List.len list + 1) // 2
+
+## Returns the element at the front of the `Deque`, without removing it.
+## ```roc
+## expect Deque.first (Deque.fromList [1, 2, 3]) == Ok 1
+## expect Deque.first (Deque.empty {}) == Err DequeWasEmpty
+## ```
+first : Deque a -> Result a [DequeWasEmpty]
+first = \@Deque { front, back } ->
+    when List.last front is
+        Ok elem -> Ok elem
+        Err ListWasEmpty -> List.first back |> Result.mapErr \_ -> DequeWasEmpty
+
+## Returns the element at the back of the `Deque`, without removing it.
+## ```roc
+## expect Deque.last (Deque.fromList [1, 2, 3]) == Ok 3
+## expect Deque.last (Deque.empty {}) == Err DequeWasEmpty
+## ```
+last : Deque a -> Result a [DequeWasEmpty]
+last = \@Deque { front, back } ->
+    when List.last back is
+        Ok elem -> Ok elem
+        Err ListWasEmpty -> List.first front |> Result.mapErr \_ -> DequeWasEmpty
+
+## Iterates through the elements of the `Deque` from front to back, building
+## up a state value as it goes.
+## ```roc
+## expect Deque.walk (Deque.fromList [1, 2, 3]) 0 Num.add == 6
+## ```
+walk : Deque a, state, (state, a -> state) -> state
+walk = \@Deque { front, back }, state, fn ->
+    # `front` is stored reversed, so walking it backwards visits it in order.
+    frontState = List.walkBackwards front state fn
+
+    List.walk back frontState fn
+
+## Converts each element of the `Deque` with the given function, keeping
+## their order.
+## ```roc
+## expect
+##     Deque.fromList [1, 2, 3]
+##     |> Deque.map Num.toStr
+##     |> Deque.toList
+##     == ["1", "2", "3"]
+## ```
+map : Deque a, (a -> b) -> Deque b
+map = \@Deque { front, back }, fn ->
+    @Deque { front: List.map front fn, back: List.map back fn }
+
+## Returns the elements of the `Deque` as a [List], from front to back.
+toList : Deque a -> List a
+toList = \@Deque { front, back } ->
+    List.concat (List.reverse front) back
+
+## Creates a `Deque` from a [List], where the first element of the list is
+## the front of the deque.
+fromList : List a -> Deque a
+fromList = \list ->
+    @Deque { front: [], back: list }
+
+expect
+    actual =
+        empty {}
+        |> pushBack 3
+        |> pushFront 2
+        |> pushBack 4
+        |> pushFront 1
+
+    toList actual == [1, 2, 3, 4]
+
+# Popping from alternating ends still sees every element in order.
+expect
+    deque = fromList [1, 2, 3, 4, 5]
+
+    result =
+        popFront deque
+        |> Result.try \{ deque: d1, elem: a } ->
+            popBack d1
+            |> Result.try \{ deque: d2, elem: b } ->
+                popFront d2
+                |> Result.map \{ deque: d3, elem: c } ->
+                    (a, b, c, toList d3)
+
+    result == Ok (1, 5, 2, [3, 4])
+
+# Deques holding the same elements are equal, however they were built.
+expect
+    fromList [1, 2, 3] == (empty {} |> pushFront 3 |> pushFront 2 |> pushFront 1)
@@ -36,6 +36,7 @@ module [
     walkFromUntil,
     range,
     sortWith,
+    sort,
     swap,
     dropAt,
     min,
@@ -79,6 +80,7 @@ module [
 import Bool exposing [Bool, Eq]
 import Result exposing [Result]
 import Num exposing [U64, Num, U8]
+import Sort exposing [Sort]
 
 ## ## Types
 ##
@@ -995,6 +997,16 @@ expect
 ## Sort with a custom comparison function
 sortWith : List a, (a, a -> [LT, EQ, GT]) -> List a
 
+## Sorts a list in ascending order, using the [Sort] ability to compare elements.
+##
+## To sort with a custom ordering, use [List.sortWith] instead.
+## ```roc
+## expect List.sort [3, 1, 2] == [1, 2, 3]
+## expect List.sort ["b", "c", "a"] == ["a", "b", "c"]
+## ```
+sort : List a -> List a where a implements Sort
+sort = \list -> List.sortWith list Sort.compare
+
 ## Sorts a list of numbers in ascending order (lowest to highest).
 ##
 ## To sort in descending order (highest to lowest), use [List.sortDesc] instead.
@@ -0,0 +1,44 @@
+module [Sort, compare, compareBool]
+
+import Bool exposing [Bool]
+
+## Defines a type whose values can be placed in a total order.
+##
+## [List.sort] uses this ability to sort a list in ascending order without
+## needing an explicit comparison function, and [List.sortWith] can be passed
+## `Sort.compare` directly.
+##
+## The Roc compiler can derive `compare` for most types, as long as all of
+## the types they contain also implement `Sort`:
+##
+## 1. Integers and [Dec] values are compared numerically, as with [Num.compare].
+## [F32] and [F64] do not implement `Sort`, because `NaN` has no place in an
+## ordering. Use [List.sortWith] with [Num.compare] to sort floats.
+## 2. [Str] values are compared by their UTF-8 bytes, as with [Str.compare].
+## 3. `Bool.false` is less than `Bool.true`.
+## 4. Records are compared field by field, in alphabetical order of their
+## field names. The first field that differs decides the ordering.
+## 5. Tuples are compared element by element, from first to last.
+## 6. Tag unions are first compared by tag name, in alphabetical order. If
+## the tags are the same, their payloads are compared from first to last.
+## 7. Functions have no ordering, so Roc cannot derive `compare` for types
+## that contain functions.
+Sort implements
+    ## Returns `LT` if the first value comes before the second, `GT` if it
+    ## comes after, and `EQ` if the two are equivalent.
+    ## ```roc
+    ## expect Sort.compare 1 2 == LT
+    ## expect Sort.compare "b" "a" == GT
+    ## expect Sort.compare { x: 1, y: 2 } { x: 1, y: 2 } == EQ
+    ## ```
+    compare : a, a -> [LT, EQ, GT] where a implements Sort
+
+## Compares two [Bool]s, where `Bool.false` comes before `Bool.true`.
+compareBool : Bool, Bool -> [LT, EQ, GT]
+compareBool = \a, b ->
+    if a == b then
+        EQ
+    else if a then
+        GT
+    else
+        LT
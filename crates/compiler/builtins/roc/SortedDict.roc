@@ -0,0 +1,499 @@
+module [
+    SortedDict,
+    empty,
+    single,
+    len,
+    isEmpty,
+    get,
+    contains,
+    insert,
+    remove,
+    update,
+    min,
+    max,
+    walk,
+    walkRange,
+    keys,
+    values,
+    toList,
+    fromList,
+]
+
+import List
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import Num exposing [U64]
+import Hash exposing [Hash, Hasher]
+import Inspect exposing [Inspect, Inspector, InspectFormatter]
+import Sort exposing [Sort]
+
+## A [dictionary](https://en.wikipedia.org/wiki/Associative_array) whose
+## entries are always kept in ascending order of their keys.
+##
+## Iterating over a [Dict] visits its entries in an unspecified order. A
+## `SortedDict` instead uses the [Sort] ability of its keys, so [walk],
+## [toList], [keys] and [values] always go from the smallest key to the
+## largest, and [walkRange] can visit just the keys within a range.
+##
+## ```roc
+## scores =
+##     SortedDict.empty {}
+##     |> SortedDict.insert 300 "Alice"
+##     |> SortedDict.insert 100 "Bob"
+##     |> SortedDict.insert 200 "Carol"
+##
+## expect SortedDict.values scores == ["Bob", "Carol", "Alice"]
+## expect SortedDict.max scores == Ok (300, "Alice")
+## ```
+##
+## The entries are stored in a balanced binary search tree (an
+## [AA tree](https://en.wikipedia.org/wiki/AA_tree)), so [get], [contains],
+## [insert], [remove] and [update] all take logarithmic time, and walking over
+## the entries in order takes linear time.
+SortedDict k v := { size : U64, root : Tree k v }
+    implements [
+        Eq {
+            isEq,
+        },
+        Hash {
+            hash: hashSortedDict,
+        },
+        Inspect {
+            toInspector: toInspectorSortedDict,
+        },
+    ]
+
+# Each node stores its level: leaves are at level 1, a left child is always
+# one level below its parent, a right child is at the same level as its parent
+# or one below, and a right grandchild is always below its grandparent.
+Tree k v : [Empty, Node U64 (Tree k v) k v (Tree k v)]
+
+isEq : SortedDict k v, SortedDict k v -> Bool where k implements Eq, v implements Eq
+isEq = \xs, ys ->
+    # The same entries can be arranged into differently shaped trees, so compare
+    # the entries in order instead of the trees themselves.
+    len xs == len ys && toList xs == toList ys
+
+hashSortedDict : hasher, SortedDict k v -> hasher where k implements Hash, v implements Hash, hasher implements Hasher
+hashSortedDict = \hasher, dict -> Hash.hashList hasher (toList dict)
+
+toInspectorSortedDict : SortedDict k v -> Inspector f where k implements Inspect, v implements Inspect, f implements InspectFormatter
+toInspectorSortedDict = \dict ->
+    Inspect.custom \fmt ->
+        Inspect.apply (Inspect.dict dict walk Inspect.toInspector Inspect.toInspector) fmt
+
+## Returns an empty `SortedDict`.
+## ```roc
+## expect SortedDict.len (SortedDict.empty {}) == 0
+## ```
+empty : {} -> SortedDict * *
+empty = \{} -> @SortedDict { size: 0, root: Empty }
+
+## Returns a `SortedDict` containing the key and value provided as input.
+## ```roc
+## expect SortedDict.toList (SortedDict.single "A" "B") == [("A", "B")]
+## ```
+single : k, v -> SortedDict k v
+single = \key, value ->
+    @SortedDict { size: 1, root: Node 1 Empty key value Empty }
+
+## Returns the number of entries in the `SortedDict`.
+## ```roc
+## expect SortedDict.len (SortedDict.fromList [(1, "a"), (2, "b")]) == 2
+## ```
+len : SortedDict * * -> U64
+len = \@SortedDict { size } ->
+    size
+
+## Checks if the `SortedDict` is empty.
+## ```roc
+## expect SortedDict.isEmpty (SortedDict.empty {})
+## ```
+isEmpty : SortedDict * * -> Bool
+isEmpty = \@SortedDict { size } ->
+    size == 0
+
+## Gets the value for a given key. If there is a value for the specified key it
+## will return [Ok value], otherwise return [Err KeyNotFound].
+## ```roc
+## dictionary = SortedDict.fromList [(1, "Apple"), (2, "Orange")]
+##
+## expect SortedDict.get dictionary 1 == Ok "Apple"
+## expect SortedDict.get dictionary 2000 == Err KeyNotFound
+## ```
+get : SortedDict k v, k -> Result v [KeyNotFound] where k implements Sort
+get = \@SortedDict { root }, key ->
+    getHelp root key
+
+getHelp : Tree k v, k -> Result v [KeyNotFound] where k implements Sort
+getHelp = \tree, key ->
+    when tree is
+        Empty -> Err KeyNotFound
+        Node _ left nodeKey value right ->
+            when Sort.compare key nodeKey is
+                LT -> getHelp left key
+                GT -> getHelp right key
+                EQ -> Ok value
+
+## Checks if the dictionary has a value for a specified key.
+## ```roc
+## expect SortedDict.contains (SortedDict.single 1234 "5678") 1234
+## ```
+contains : SortedDict k v, k -> Bool where k implements Sort
+contains = \dict, key ->
+    when get dict key is
+        Ok _ -> Bool.true
+        Err KeyNotFound -> Bool.false
+
+## Inserts a value into the dictionary at a specified key. If the key is
+## already present, its value is replaced.
+## ```roc
+## expect
+##     SortedDict.empty {}
+##     |> SortedDict.insert "b" 2
+##     |> SortedDict.insert "a" 1
+##     |> SortedDict.keys
+##     == ["a", "b"]
+## ```
+insert : SortedDict k v, k, v -> SortedDict k v where k implements Sort
+insert = \@SortedDict { size, root }, key, value ->
+    { tree, added } = insertHelp root key value
+
+    if added then
+        @SortedDict { size: size + 1, root: tree }
+    else
+        @SortedDict { size, root: tree }
+
+insertHelp : Tree k v, k, v -> { tree : Tree k v, added : Bool } where k implements Sort
+insertHelp = \tree, key, value ->
+    when tree is
+        Empty ->
+            { tree: Node 1 Empty key value Empty, added: Bool.true }
+
+        Node levelOf left nodeKey nodeValue right ->
+            when Sort.compare key nodeKey is
+                LT ->
+                    result = insertHelp left key value
+                    balanced = Node level result.tree nodeKey nodeValue right |> skew |> split
+
+                    { tree: balanced, added: result.added }
+
+                GT ->
+                    result = insertHelp right key value
+                    balanced = Node levelOf left nodeKey nodeValue result.tree |> skew |> split
+
+                    { tree: balanced, added: result.added }
+
+                EQ ->
+                    { tree: Node levelOf left key value right, added: Bool.false }
+
+## Removes a key-value pair from the dictionary. If the key is not present,
+## the dictionary is returned unchanged.
+## ```roc
+## expect
+##     SortedDict.fromList [(1, "a"), (2, "b")]
+##     |> SortedDict.remove 1
+##     |> SortedDict.toList
+##     == [(2, "b")]
+## ```
+remove : SortedDict k v, k -> SortedDict k v where k implements Sort
+remove = \@SortedDict { size, root }, key ->
+    { tree, removed } = removeHelp root key
+
+    if removed then
+        @SortedDict { size: size - 1, root: tree }
+    else
+        @SortedDict { size, root }
+
+removeHelp : Tree k v, k -> { tree : Tree k v, removed : Bool } where k implements Sort
+removeHelp = \tree, key ->
+    when tree is
+        Empty ->
+            { tree: Empty, removed: Bool.false }
+
+        Node levelOf left nodeKey nodeValue right ->
+            when Sort.compare key nodeKey is
+                LT ->
+                    result = removeHelp left key
+
+                    { tree: rebalance (Node level result.tree nodeKey nodeValue right), removed: result.removed }
+
+                GT ->
+                    result = removeHelp right key
+
+                    { tree: rebalance (Node levelOf left nodeKey nodeValue result.tree), removed: result.removed }
+
+                EQ ->
+                    # Replace the node's entry with its closest neighbour from
+                    # one of its subtrees, and remove that neighbour instead.
+                    when (left, right) is
+                        (Empty, Empty) ->
+                            { tree: Empty, removed: Bool.true }
+
+                        (Empty, _) ->
+                            when minHelp right is
+                                Ok (nextKey, nextValue) ->
+                                    newRight = (removeHelp right nextKey).tree
+
+                                    { tree: rebalance (Node levelOf left nextKey nextValue newRight), removed: Bool.true }
+
+                                Err SortedDictWasEmpty ->
+                                    { tree: Empty, removed: Bool.true }
+
+                        _ ->
+                            when maxHelp left is
+                                Ok (prevKey, prevValue) ->
+                                    newLeft = (removeHelp left prevKey).tree
+
+                                    { tree: rebalance (Node level newLeft prevKey prevValue right), removed: Bool.true }
+
+                                Err SortedDictWasEmpty ->
+                                    { tree: right, removed: Bool.true }
+
+## Inserts or removes a value for a specified key, in the same way as
+## [Dict.update].
+## ```roc
+## increment : Result U64 [Missing] -> Result U64 [Missing]
+## increment = \possibleValue ->
+##     when possibleValue is
+##         Err Missing -> Ok 1
+##         Ok count -> Ok (count + 1)
+##
+## expect SortedDict.update (SortedDict.single "a" 1) "a" increment == SortedDict.single "a" 2
+## ```
+update : SortedDict k v, k, (Result v [Missing] -> Result v [Missing]) -> SortedDict k v where k implements Sort
+update = \dict, key, alter ->
+    possibleValue =
+        get dict key
+        |> Result.mapErr \_ -> Missing
+
+    when alter possibleValue is
+        Ok newValue -> insert dict key newValue
+        Err Missing -> remove dict key
+
+## Returns the entry with the smallest key.
+## ```roc
+## expect SortedDict.min (SortedDict.fromList [(2, "b"), (1, "a")]) == Ok (1, "a")
+## ```
+min : SortedDict k v -> Result (k, v) [SortedDictWasEmpty]
+min = \@SortedDict { root } ->
+    minHelp root
+
+minHelp : Tree k v -> Result (k, v) [SortedDictWasEmpty]
+minHelp = \tree ->
+    when tree is
+        Empty -> Err SortedDictWasEmpty
+        Node _ Empty key value _ -> Ok (key, value)
+        Node _ left _ _ _ -> minHelp left
+
+## Returns the entry with the largest key.
+## ```roc
+## expect SortedDict.max (SortedDict.fromList [(2, "b"), (1, "a")]) == Ok (2, "b")
+## ```
+max : SortedDict k v -> Result (k, v) [SortedDictWasEmpty]
+max = \@SortedDict { root } ->
+    maxHelp root
+
+maxHelp : Tree k v -> Result (k, v) [SortedDictWasEmpty]
+maxHelp = \tree ->
+    when tree is
+        Empty -> Err SortedDictWasEmpty
+        Node _ _ key value Empty -> Ok (key, value)
+        Node _ _ _ _ right -> maxHelp right
+
+## Iterates through the entries in ascending order of their keys, building up
+## a state value as it goes.
+## ```roc
+## expect
+##     SortedDict.fromList [(2, "b"), (1, "a"), (3, "c")]
+##     |> SortedDict.walk "" \state, _, v -> Str.concat state v
+##     == "abc"
+## ```
+walk : SortedDict k v, state, (state, k, v -> state) -> state
+walk = \@SortedDict { root }, state, fn ->
+    walkHelp root state fn
+
+walkHelp : Tree k v, state, (state, k, v -> state) -> state
+walkHelp = \tree, state, fn ->
+    when tree is
+        Empty -> state
+        Node _ left key value right ->
+            walkHelp right (fn (walkHelp left state fn) key value) fn
+
+## Like [walk], but only visits the keys within the given range.
+##
+## As with [List.range], the `start` of the range is either `At key`, to include
+## the key itself, or `After key`, to exclude it. The `end` is either `At key`
+## or `Before key`. The keys used for the range do not need to be in the
+## dictionary.
+## ```roc
+## dict = SortedDict.fromList [(1, "a"), (2, "b"), (3, "c"), (4, "d")]
+##
+## expect SortedDict.walkRange dict { start: At 2, end: Before 4 } [] \s, k, _ -> List.append s k == [2, 3]
+## expect SortedDict.walkRange dict { start: After 2, end: At 4 } [] \s, k, _ -> List.append s k == [3, 4]
+## ```
+walkRange : SortedDict k v, { start : [At k, After k], end : [At k, Before k] }, state, (state, k, v -> state) -> state where k implements Sort
+walkRange = \@SortedDict { root }, range, state, fn ->
+    walkRangeHelp root range state fn
+
+walkRangeHelp : Tree k v, { start : [At k, After k], end : [At k, Before k] }, state, (state, k, v -> state) -> state where k implements Sort
+walkRangeHelp = \tree, range, state, fn ->
+    when tree is
+        Empty -> state
+        Node _ left key value right ->
+            afterStart =
+                when range.start is
+                    At start -> Sort.compare key start != LT
+                    After start -> Sort.compare key start == GT
+
+            beforeEnd =
+                when range.end is
+                    At end -> Sort.compare key end != GT
+                    Before end -> Sort.compare key end == LT
+
+            # Skip the subtrees that are entirely outside of the range.
+            leftState = if afterStart then walkRangeHelp left range state fn else state
+            nodeState = if afterStart && beforeEnd then fn leftState key value else leftState
+
+            if beforeEnd then walkRangeHelp right range nodeState fn else nodeState
+
+## Returns the keys of the dictionary, from smallest to largest.
+## ```roc
+## expect SortedDict.keys (SortedDict.fromList [(2, "b"), (1, "a")]) == [1, 2]
+## ```
+keys : SortedDict k v -> List k
+keys = \dict ->
+    walk dict (List.withCapacity (len dict)) \accum, k, _ -> List.append accum k
+
+## Returns the values of the dictionary, in ascending order of their keys.
+## ```roc
+## expect SortedDict.values (SortedDict.fromList [(2, "b"), (1, "a")]) == ["a", "b"]
+## ```
+values : SortedDict k v -> List v
+values = \dict ->
+    walk dict (List.withCapacity (len dict)) \accum, _, v -> List.append accum v
+
+## Returns the entries of the dictionary as a list of key-value tuples, in
+## ascending order of their keys.
+toList : SortedDict k v -> List (k, v)
+toList = \dict ->
+    walk dict (List.withCapacity (len dict)) \accum, k, v -> List.append accum (k, v)
+
+## Creates a `SortedDict` from a list of key-value tuples. If a key appears
+## more than once, the last value for it wins.
+fromList : List (k, v) -> SortedDict k v where k implements Sort
+fromList = \list ->
+    List.walk list (empty {}) \dict, (k, v) -> insert dict k v
+
+levelOf : Tree * * -> U64
+levelOf = \tree ->
+    when tree is
+        Empty -> 0
+        Node nodeLevel _ _ _ _ -> nodeLevel
+
+# Turns a left child at the same level as its parent into the parent, by
+# rotating right.
+skew : Tree k v -> Tree k v
+skew = \tree ->
+    when tree is
+        Node nodeLevel (Node leftLevel a leftKey leftValue b) key value c if leftLevel == nodeLevel ->
+            Node leftLevel a leftKey leftValue (Node nodeLevel b key value c)
+
+        _ -> tree
+
+# Pulls up the middle of three nodes at the same level along the right spine,
+# by rotating left.
+split : Tree k v -> Tree k v
+split = \tree ->
+    when tree is
+        Node nodeLevel a key value (Node rightLevel b rightKey rightValue c) if levelOf c == nodeLevel ->
+            Node (rightLevel + 1) (Node nodeLevel a key value b) rightKey rightValue c
+
+        _ -> tree
+
+# Restores the levels of a node after an entry was removed from one of its
+# subtrees.
+rebalance : Tree k v -> Tree k v
+rebalance = \tree ->
+    tree
+    |> decreaseLevel
+    |> skew
+    |> mapRight \right -> right |> skew |> mapRight skew
+    |> split
+    |> mapRight split
+
+mapRight : Tree k v, (Tree k v -> Tree k v) -> Tree k v
+mapRight = \tree, fn ->
+    when tree is
+        Node nodeLevel left key value right -> Node nodeLevel left key value (fn right)
+        Empty -> Empty
+
+decreaseLevel : Tree k v -> Tree k v
+decreaseLevel = \tree ->
+    when tree is
+        Node nodeLevel left key value right ->
+            shouldBe = Num.min (levelOf left) (levelOf right) + 1
+
+            if shouldBe < nodeLevel then
+                newRight =
+                    when right is
+                        Node rightLevel rightLeft rightKey rightValue rightRight if shouldBe < rightLevel ->
+                            Node shouldBe rightLeft rightKey rightValue rightRight
+
+                        _ -> right
+
+                Node shouldBe left key value newRight
+            else
+                tree
+
+        Empty -> Empty
+
+# Checks the AA tree invariants, for the tests below.
+isBalanced : Tree * * -> Bool
+isBalanced = \tree ->
+    when tree is
+        Empty -> Bool.true
+        Node nodeLevel left _ _ right ->
+            leftOk = levelOf left + 1 == nodeLevel
+            rightOk = levelOf right == nodeLevel || levelOf right + 1 == nodeLevel
+
+            rightRightOk =
+                when right is
+                    Node _ _ _ _ rightRight -> levelOf rightRight < nodeLevel
+                    Empty -> Bool.true
+
+            leftOk && rightOk && rightRightOk && isBalanced left && isBalanced right
+
+expect
+    actual =
+        empty {}
+        |> insert 3 "c"
+        |> insert 1 "a"
+        |> insert 2 "b"
+        |> insert 1 "A"
+
+    toList actual == [(1, "A"), (2, "b"), (3, "c")]
+
+expect
+    actual = fromList [(3, "c"), (1, "a"), (2, "b"), (1, "A"), (3, "C"), (1, "!")]
+
+    toList actual == [(1, "!"), (2, "b"), (3, "C")]
+
+expect
+    dict = fromList [(10, {}), (20, {}), (30, {})]
+    collect = \state, k, _ -> List.append state k
+
+    (walkRange dict { start: At 15, end: At 30 } [] collect == [20, 30])
+    && (walkRange dict { start: After 30, end: At 40 } [] collect == [])
+    && (walkRange dict { start: At 20, end: Before 20 } [] collect == [])
+
+expect
+    inserted = List.walk (List.range { start: At 1u64, end: At 100 }) (empty {}) \dict, k -> insert dict k {}
+    removed = List.walk (List.range { start: At 1u64, end: At 50 }) inserted \dict, k -> remove dict (k * 2)
+    @SortedDict insertedTree = inserted
+    @SortedDict removedTree = removed
+
+    isBalanced insertedTree.root
+    && isBalanced removedTree.root
+    && (len removed == 50)
+    && (keys removed == List.range { start: At 1u64, end: At 99, step: 2 })
@@ -369,6 +369,7 @@ module [
     contains,
     dropPrefix,
     dropSuffix,
+    compare,
 ]
 
 import Bool exposing [Bool]
@@ -1088,3 +1089,34 @@ dropSuffix = \haystack, suffix ->
         substringUnsafe haystack start len
     else
         haystack
+
+## Compares two strings by their UTF-8 bytes, in lexicographic order.
+## If one string is a prefix of the other, the shorter one comes first.
+##
+## This is the ordering [Sort.compare] uses for [Str].
+## ```roc
+## expect Str.compare "apple" "banana" == LT
+## expect Str.compare "abc" "ab" == GT
+## expect Str.compare "" "" == EQ
+## ```
+compare : Str, Str -> [LT, EQ, GT]
+compare = \a, b ->
+    compareHelp a b 0 (Str.countUtf8Bytes a) (Str.countUtf8Bytes b)
+
+compareHelp : Str, Str, U64, U64, U64 -> [LT, EQ, GT]
+compareHelp = \a, b, index, aLength, bLength ->
+    if index >= aLength then
+        if index >= bLength then
+            EQ
+        else
+            LT
+    else if index >= bLength then
+        GT
+    else
+        when Num.compare (Str.getUnsafe a index) (Str.getUnsafe b index) is
+            EQ -> compareHelp a b (index + 1) aLength bLength
+            ordering -> ordering
+
+expect Str.compare "apple" "banana" == LT
+expect Str.compare "abc" "ab" == GT
+expect Str.compare "abc" "abc" == EQ
@@ -17,6 +17,9 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
         ModuleId::HASH => HASH,
         ModuleId::INSPECT => INSPECT,
         ModuleId::TASK => TASK,
+        ModuleId::SORT => SORT,
+        ModuleId::DEQUE => DEQUE,
+        ModuleId::SORTED_DICT => SORTED_DICT,
         _ => internal_error!(
             "ModuleId {:?} is not part of the standard library",
             module_id
@@ -24,6 +27,42 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
     }
 }
 
+/// A hash of the source code of every builtin module. Two builds of the compiler
+/// that bundle different builtins get different hashes, even if they report
+/// the same version.
+pub fn sources_hash() -> u64 {
+    const ALL_SOURCES: &[&str] = &[
+        RESULT,
+        NUM,
+        STR,
+        LIST,
+        DICT,
+        SET,
+        BOX,
+        BOOL,
+        ENCODE,
+        DECODE,
+        HASH,
+        INSPECT,
+        TASK,
+        SORT,
+        DEQUE,
+        SORTED_DICT,
+    ];
+
+    // FNV-1a, because it gives the same hash in every build and on every platform.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for source in ALL_SOURCES {
+        for byte in source.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    hash
+}
+
 const RESULT: &str = include_str!("../roc/Result.roc");
 const NUM: &str = include_str!("../roc/Num.roc");
 const STR: &str = include_str!("../roc/Str.roc");
@@ -37,3 +76,6 @@ const DECODE: &str = include_str!("../roc/Decode.roc");
 const HASH: &str = include_str!("../roc/Hash.roc");
 const INSPECT: &str = include_str!("../roc/Inspect.roc");
 const TASK: &str = include_str!("../roc/Task.roc");
+const SORT: &str = include_str!("../roc/Sort.roc");
+const DEQUE: &str = include_str!("../roc/Deque.roc");
+const SORTED_DICT: &str = include_str!("../roc/SortedDict.roc");
@@ -851,6 +851,7 @@ fn deep_copy_pattern_help<C: CopyEnv>(
         },
         NumLiteral(var, s, n, bound) => NumLiteral(sub!(*var), s.clone(), *n, *bound),
         IntLiteral(v1, v2, s, n, bound) => IntLiteral(sub!(*v1), sub!(*v2), s.clone(), *n, *bound),
+        IntRange(v1, v2, start, end, bound) => IntRange(sub!(*v1), sub!(*v2), *start, *end, *bound),
         FloatLiteral(v1, v2, s, n, bound) => {
             FloatLiteral(sub!(*v1), sub!(*v2), s.clone(), *n, *bound)
         }
@@ -973,7 +974,7 @@ fn deep_copy_type_vars<C: CopyEnv>(
 
             // Everything else is a mechanical descent.
             Structure(flat_type) => match flat_type {
-                EmptyRecord | EmptyTagUnion | EffectfulFunc => Structure(flat_type),
+                EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => Structure(flat_type),
                 Apply(symbol, arguments) => {
                     descend_slice!(arguments);
 
@@ -584,6 +584,7 @@ fn pattern<'a>(
         NumLiteral(_, n, _, _) | IntLiteral(_, _, n, _, _) | FloatLiteral(_, _, n, _, _) => {
             f.text(&**n)
         }
+        IntRange(_, _, start, end, _) => text!(f, "{}..{}", start, end),
         StrLiteral(s) => text!(f, r#""{}""#, s),
         SingleQuote(_, _, c, _) => text!(f, "'{}'", c),
         Underscore => f.text("_"),
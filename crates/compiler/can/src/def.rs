@@ -439,7 +439,6 @@ fn canonicalize_alias<'a>(
     output.references.union_mut(&can_ann.references);
 
     let mut can_vars: Vec<Loc<AliasVar>> = Vec::with_capacity(vars.len());
-    let mut is_phantom = false;
 
     let IntroducedVariables {
         named,
@@ -474,10 +473,10 @@ fn canonicalize_alias<'a>(
                     region: loc_lowercase.region,
                 });
             }
-            None => match kind {
-                AliasKind::Structural => {
-                    is_phantom = true;
-
+            None => {
+                // Opaques can have phantom types. Structural aliases can too, but since an
+                // unused parameter has no effect on the aliased type, we warn about it.
+                if kind == AliasKind::Structural {
                     env.problems.push(Problem::PhantomTypeArgument {
                         typ: symbol,
                         variable_region: loc_lowercase.region,
@@ -485,24 +484,17 @@ fn canonicalize_alias<'a>(
                         alias_kind: AliasKind::Structural,
                     });
                 }
-                AliasKind::Opaque => {
-                    // Opaques can have phantom types.
-                    can_vars.push(Loc {
-                        value: AliasVar {
-                            name: loc_lowercase.value.clone(),
-                            var: var_store.fresh(),
-                            opt_bound_abilities: None,
-                        },
-                        region: loc_lowercase.region,
-                    });
-                }
-            },
-        }
-    }
 
-    if is_phantom {
-        // Bail out
-        return Err(());
+                can_vars.push(Loc {
+                    value: AliasVar {
+                        name: loc_lowercase.value.clone(),
+                        var: var_store.fresh(),
+                        opt_bound_abilities: None,
+                    },
+                    region: loc_lowercase.region,
+                });
+            }
+        }
     }
 
     // Report errors for wildcards (*), underscores (_), and named vars that weren't declared.
@@ -2198,6 +2190,7 @@ fn pattern_to_vars_by_symbol(
 
         NumLiteral(..)
         | IntLiteral(..)
+        | IntRange(..)
         | FloatLiteral(..)
         | StrLiteral(_)
         | SingleQuote(..)
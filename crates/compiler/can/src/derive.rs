@@ -217,6 +217,60 @@ fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
     )
 }
 
+fn compare<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+    let alloc_expr = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+
+    let payload1 = "#payload1";
+    let payload2 = "#payload2";
+
+    let opaque_ref = alloc_pat(ast::Pattern::OpaqueRef(at_opaque));
+    // \@Opaq payload1
+    let opaque1 = ast::Pattern::Apply(
+        opaque_ref,
+        &*env.arena.alloc([Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::Identifier { ident: payload1 },
+        )]),
+    );
+    // \@Opaq payload2
+    let opaque2 = ast::Pattern::Apply(
+        opaque_ref,
+        &*env.arena.alloc([Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::Identifier { ident: payload2 },
+        )]),
+    );
+
+    // Sort.compare payload1 payload2
+    let call_member = alloc_expr(ast::Expr::Apply(
+        alloc_expr(ast::Expr::Var {
+            module_name: "Sort",
+            ident: "compare",
+        }),
+        &*env.arena.alloc([
+            &*alloc_expr(ast::Expr::Var {
+                module_name: "",
+                ident: payload1,
+            }),
+            &*alloc_expr(ast::Expr::Var {
+                module_name: "",
+                ident: payload2,
+            }),
+        ]),
+        roc_module::called_via::CalledVia::Space,
+    ));
+
+    // \@Opaq payload1, @Opaq payload2 -> Sort.compare payload1 payload2
+    ast::Expr::Closure(
+        env.arena.alloc([
+            Loc::at(DERIVED_REGION, opaque1),
+            Loc::at(DERIVED_REGION, opaque2),
+        ]),
+        call_member,
+    )
+}
+
 fn to_inspector<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
     // Inspect for opaques as a tag so it prints `@Opaque payload`.
     let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
@@ -330,6 +384,7 @@ pub(crate) fn synthesize_member_impl<'a>(
             format!("#{opaque_name}_toInspector"),
             to_inspector(env, at_opaque),
         ),
+        Symbol::SORT_COMPARE => (format!("#{opaque_name}_compare"), compare(env, at_opaque)),
         other => internal_error!("{:?} is not a derivable ability member!", other),
     };
 
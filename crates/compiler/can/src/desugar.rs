@@ -1113,6 +1113,14 @@ pub fn desugar_expr<'a>(
                 region: loc_expr.region,
             })
         }
+        TypeAscription(sub_expr, ann) => {
+            let desugared_sub_expr = &*env.arena.alloc(desugar_expr(env, scope, sub_expr));
+
+            env.arena.alloc(Loc {
+                value: *desugar_type_ascription(env, scope, desugared_sub_expr, ann),
+                region: loc_expr.region,
+            })
+        }
         Return(return_value, after_return) => {
             let desugared_return_value = &*env.arena.alloc(desugar_expr(env, scope, return_value));
 
@@ -1336,6 +1344,7 @@ fn desugar_pattern<'a>(env: &mut Env<'a>, scope: &mut Scope, pattern: Pattern<'a
         | NumLiteral(_)
         | NonBase10Literal { .. }
         | FloatLiteral(_)
+        | IntRange(..)
         | StrLiteral(_)
         | Underscore(_)
         | SingleQuote(_)
@@ -1459,6 +1468,52 @@ fn desugar_dbg_expr<'a>(
     env.arena.alloc(Defs(defs, dbg_stmt))
 }
 
+/// Desugar an inline type annotation `(expr : Type)` into an annotated def
+/// of a temporary variable, which is then returned.
+fn desugar_type_ascription<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    expr: &'a Loc<Expr<'a>>,
+    ann: &'a Loc<TypeAnnotation<'a>>,
+) -> &'a Expr<'a> {
+    let region = expr.region;
+
+    // tmpVar : Type
+    // tmpVar = expr
+    let ident = env.arena.alloc(scope.gen_unique_symbol_name().to_string());
+    let pattern = env.arena.alloc(Loc {
+        value: Pattern::Identifier { ident },
+        region,
+    });
+
+    let value_def = ValueDef::AnnotatedBody {
+        ann_pattern: pattern,
+        ann_type: ann,
+        lines_between: &[],
+        body_pattern: pattern,
+        body_expr: expr,
+    };
+
+    let defs = env.arena.alloc(Defs::default());
+    defs.push_value_def(
+        value_def,
+        Region::span_across(&region, &ann.region),
+        &[],
+        &[],
+    );
+
+    // tmpVar
+    let tmp_var = env.arena.alloc(Loc {
+        value: Var {
+            module_name: "",
+            ident,
+        },
+        region,
+    });
+
+    env.arena.alloc(Defs(defs, tmp_var))
+}
+
 /// Build a desugared `dbg {}` expression to act as a placeholder when the AST
 /// is invalid.
 pub fn desugar_invalid_dbg_expr<'a>(
@@ -68,6 +68,9 @@ pub fn check(
 enum SketchedPattern {
     Anything,
     Literal(Literal),
+    /// An inclusive integer range. Whether it can cover its whole type is only known once we
+    /// reify it against the type being matched.
+    IntRange(i128, i128),
     /// A constructor whose expected union is not yet known.
     /// We'll know the whole union when reifying the sketched pattern against an expected case type.
     Ctor(TagName, Vec<SketchedPattern>),
@@ -240,6 +243,10 @@ fn index_var(
                     };
                     return Ok(std::iter::repeat(Variable::NULL).take(num_fields).collect());
                 }
+                FlatType::EmptyTuple => {
+                    debug_assert!(matches!(ctor, IndexCtor::Tuple));
+                    return Ok(vec![]);
+                }
                 FlatType::EmptyTagUnion => {
                     internal_error!("empty tag unions are not indexable")
                 }
@@ -260,6 +267,11 @@ impl SketchedPattern {
         match self {
             Self::Anything => Ok(Pattern::Anything),
             Self::Literal(lit) => Ok(Pattern::Literal(lit)),
+            Self::IntRange(start, end) => Ok(Pattern::Literal(Literal::IntRange {
+                start: start.to_ne_bytes(),
+                end: end.to_ne_bytes(),
+                type_range: int_type_range(subs, real_var),
+            })),
             Self::KnownCtor(union, tag_id, patterns) => {
                 let index_ctor = IndexCtor::of_union(&union, tag_id);
                 let arg_vars = index_var(subs, real_var, index_ctor, &union.render_as)?;
@@ -335,6 +347,7 @@ fn sketch_pattern(pattern: &crate::pattern::Pattern) -> SketchedPattern {
         &NumLiteral(_, _, IntValue::U128(n), _) | &IntLiteral(_, _, _, IntValue::U128(n), _) => {
             SP::Literal(Literal::U128(n))
         }
+        &IntRange(_, _, start, end, _) => SP::IntRange(start, end),
         &FloatLiteral(_, _, _, f, _) => SP::Literal(Literal::Float(f64::to_bits(f))),
         StrLiteral(v) => SP::Literal(Literal::Str(v.clone())),
         &SingleQuote(_, _, c, _) => SP::Literal(Literal::Byte(c as u8)),
@@ -651,6 +664,46 @@ fn is_inhabited_pattern(pat: &Pattern) -> bool {
     true
 }
 
+/// The smallest and largest values of an integer type, if they can be represented as I128s.
+fn int_type_range(subs: &Subs, mut var: Variable) -> Option<([u8; 16], [u8; 16])> {
+    use roc_types::num::IntLitWidth::*;
+
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, _, real_var, _) => {
+                let width = match *symbol {
+                    Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => U8,
+                    Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => U16,
+                    Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => U32,
+                    Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => U64,
+                    Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => I8,
+                    Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => I16,
+                    Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => I32,
+                    Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => I64,
+                    Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => I128,
+                    // The largest U128 doesn't fit in an I128
+                    Symbol::NUM_U128 | Symbol::NUM_UNSIGNED128 => return None,
+                    _ => {
+                        var = *real_var;
+                        continue;
+                    }
+                };
+
+                let max = i128::try_from(width.max_value()).ok()?;
+
+                return Some((width.min_value().to_ne_bytes(), max.to_ne_bytes()));
+            }
+            Content::Structure(FlatType::Apply(Symbol::NUM_NUM | Symbol::NUM_INTEGER, args)) => {
+                match subs.get_subs_slice(*args) {
+                    [arg] => var = *arg,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+}
+
 fn convert_tag(subs: &Subs, whole_var: Variable, this_tag: &TagName) -> (Union, TagId) {
     let content = subs.get_content_without_compacting(whole_var);
 
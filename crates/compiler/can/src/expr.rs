@@ -8,7 +8,10 @@ use crate::num::{
     int_expr_from_result, num_expr_from_result, FloatBound, IntBound, NumBound,
 };
 use crate::params_in_abilities_unimplemented;
-use crate::pattern::{canonicalize_pattern, BindingsFromPattern, Pattern, PermitShadows};
+use crate::pattern::{
+    canonicalize_pattern, report_defaults_using_pattern_bindings, BindingsFromPattern, Pattern,
+    PermitShadows,
+};
 use crate::procedure::{QualifiedReference, References};
 use crate::scope::{Scope, SymbolLookup};
 use crate::traverse::{walk_expr, Visitor};
@@ -871,7 +874,7 @@ pub fn canonicalize_expr<'a>(
                     env.problem(Problem::RuntimeError(problem.clone()));
                     (RuntimeError(problem), output)
                 } else {
-                    match scope.lookup_opaque_ref(name, loc_fn.region) {
+                    match scope.lookup_opaque_ref(name, loc_fn.region, env.dep_idents) {
                         Err(runtime_error) => {
                             env.problem(Problem::RuntimeError(runtime_error.clone()));
                             (RuntimeError(runtime_error), output)
@@ -1071,6 +1074,9 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Backpassing(_, _, _) => {
             internal_error!("Backpassing should have been desugared by now")
         }
+        ast::Expr::TypeAscription(_, _) => {
+            internal_error!("Type ascription should have been desugared by now")
+        }
         ast::Expr::RecordUpdater(_) => {
             internal_error!("Record updater should have been desugared by now")
         }
@@ -1199,7 +1205,7 @@ pub fn canonicalize_expr<'a>(
             // If we're here, the opaque reference is definitely not wrapping an argument - wrapped
             // arguments are handled in the Apply branch.
             // Treat this as a function \payload -> @Opaque payload
-            match scope.lookup_opaque_ref(name, region) {
+            match scope.lookup_opaque_ref(name, region, env.dep_idents) {
                 Err(runtime_error) => {
                     env.problem(Problem::RuntimeError(runtime_error.clone()));
                     (RuntimeError(runtime_error), Output::default())
@@ -1599,6 +1605,8 @@ fn canonicalize_closure_body<'a>(
         ));
     }
 
+    report_defaults_using_pattern_bindings(env, can_args.iter_mut().map(|x| &mut x.2));
+
     let bound_by_argument_patterns: Vec<_> =
         BindingsFromPattern::new_many(can_args.iter().map(|x| &x.2)).collect();
 
@@ -1810,6 +1818,8 @@ fn canonicalize_when_branch<'a>(
         some_symbols_not_bound_in_all_patterns = true;
     }
 
+    report_defaults_using_pattern_bindings(env, patterns.iter_mut().map(|p| &mut p.pattern));
+
     let (value, mut branch_output) = canonicalize_expr(
         env,
         var_store,
@@ -2590,6 +2600,7 @@ pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
         | ast::Expr::OptionalFieldInRecordBuilder(_, loc_expr)
         | ast::Expr::PrecedenceConflict(PrecedenceConflict { expr: loc_expr, .. })
         | ast::Expr::UnaryOp(loc_expr, _)
+        | ast::Expr::TypeAscription(loc_expr, _)
         | ast::Expr::Closure(_, loc_expr) => is_valid_interpolation(&loc_expr.value),
         ast::Expr::TupleAccess(sub_expr, _)
         | ast::Expr::ParensAround(sub_expr)
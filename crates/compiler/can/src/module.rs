@@ -139,6 +139,8 @@ pub struct Module {
     pub loc_expects: VecMap<Region, Vec<ExpectLookup>>,
     pub loc_dbgs: VecMap<Symbol, DbgLookup>,
     pub module_params: Option<ModuleParams>,
+    /// Whether canonicalization reported any errors, in which case solved types are unreliable.
+    pub has_can_errors: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -172,6 +174,8 @@ pub struct RigidVariables {
     pub named: MutMap<Variable, Lowercase>,
     pub able: MutMap<Variable, (Lowercase, AbilitySet)>,
     pub wildcards: VecSet<Variable>,
+    /// The `_` holes in annotations, whose solved types are reported back to the user.
+    pub inferred: Vec<Loc<Variable>>,
 }
 
 #[derive(Debug)]
@@ -408,6 +412,14 @@ pub fn canonicalize_module_defs<'a>(
         rigid_variables.wildcards.insert(var.value);
     }
 
+    // Variables we inferred in place of invalid types have no region, and no `_` to report.
+    rigid_variables.inferred = output
+        .introduced_variables
+        .inferred
+        .into_iter()
+        .filter(|var| var.region != Region::zero())
+        .collect();
+
     let mut referenced_values = VecSet::default();
 
     // Gather up all the symbols that were referenced across all the defs' lookups.
@@ -903,6 +915,7 @@ fn fix_values_captured_in_closure_pattern(
         Identifier(_)
         | NumLiteral(..)
         | IntLiteral(..)
+        | IntRange(..)
         | FloatLiteral(..)
         | StrLiteral(_)
         | SingleQuote(..)
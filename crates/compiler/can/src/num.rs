@@ -109,6 +109,10 @@ pub enum ParsedNumResult {
 
 #[inline(always)]
 pub fn finish_parsing_num(raw: &str) -> Result<(&str, ParsedNumResult), (&str, IntErrorKind)> {
+    if has_misplaced_underscore(raw) {
+        return Err((raw, IntErrorKind::InvalidDigit));
+    }
+
     // Ignore underscores.
     let radix = 10;
     let (_, raw_without_suffix) = parse_literal_suffix(raw);
@@ -131,6 +135,10 @@ pub fn finish_parsing_base(
         Base::Binary => 2,
     };
 
+    if has_misplaced_underscore(raw) {
+        return Err((raw, IntErrorKind::InvalidDigit));
+    }
+
     // Ignore underscores, insert - when negative to get correct underflow/overflow behavior
     (if is_negative {
         from_str_radix(format!("-{}", raw.replace('_', "")).as_str(), radix)
@@ -150,6 +158,10 @@ pub fn finish_parsing_base(
 
 #[inline(always)]
 pub fn finish_parsing_float(raw: &str) -> Result<(&str, f64, FloatBound), (&str, FloatErrorKind)> {
+    if has_misplaced_underscore(raw) {
+        return Err((raw, FloatErrorKind::Error));
+    }
+
     let (opt_bound, raw_without_suffix) = parse_literal_suffix(raw);
 
     let bound = match opt_bound {
@@ -172,6 +184,21 @@ pub fn finish_parsing_float(raw: &str) -> Result<(&str, f64, FloatBound), (&str,
     }
 }
 
+/// Underscores may only separate digits, so `1_000` is fine but `1__000`, `1_000_`,
+/// `1_.5` and `0x_FF` (whose raw digits are `_FF`) are not.
+fn has_misplaced_underscore(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+
+    bytes.iter().enumerate().any(|(index, byte)| {
+        *byte == b'_'
+            && !(index > 0
+                && bytes[index - 1].is_ascii_alphanumeric()
+                && bytes
+                    .get(index + 1)
+                    .is_some_and(|next| next.is_ascii_alphanumeric()))
+    })
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum ParsedWidth {
     Int(IntLitWidth),
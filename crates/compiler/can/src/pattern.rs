@@ -6,6 +6,7 @@ use crate::num::{
     ParsedNumResult,
 };
 use crate::scope::{PendingAbilitiesInScope, Scope};
+use crate::traverse::{walk_expr, Visitor};
 use roc_exhaustive::ListArity;
 use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
@@ -70,6 +71,8 @@ pub enum Pattern {
     },
     NumLiteral(Variable, Box<str>, IntValue, NumBound),
     IntLiteral(Variable, Variable, Box<str>, IntValue, IntBound),
+    /// An inclusive range of integers, e.g. `1..9`
+    IntRange(Variable, Variable, i128, i128, IntBound),
     FloatLiteral(Variable, Variable, Box<str>, f64, FloatBound),
     StrLiteral(Box<str>),
     SingleQuote(Variable, Variable, char, SingleQuoteBound),
@@ -112,6 +115,7 @@ impl Pattern {
             } => Some(*whole_var),
             NumLiteral(var, ..) => Some(*var),
             IntLiteral(var, ..) => Some(*var),
+            IntRange(var, ..) => Some(*var),
             FloatLiteral(var, ..) => Some(*var),
             StrLiteral(_) => None,
             SingleQuote(..) => None,
@@ -156,6 +160,7 @@ impl Pattern {
             AppliedTag { .. }
             | NumLiteral(..)
             | IntLiteral(..)
+            | IntRange(..)
             | FloatLiteral(..)
             | StrLiteral(..)
             | SingleQuote(..) => false,
@@ -183,7 +188,7 @@ impl Pattern {
             TupleDestructure { .. } => C::Tuple,
             List { .. } => C::List,
             NumLiteral(..) => C::Num,
-            IntLiteral(..) => C::Int,
+            IntLiteral(..) | IntRange(..) => C::Int,
             FloatLiteral(..) => C::Float,
             StrLiteral(_) => C::Str,
             SingleQuote(..) => C::Character,
@@ -303,16 +308,22 @@ pub fn canonicalize_def_header_pattern<'a>(
                 }
             }
         }
-        _ => canonicalize_pattern(
-            env,
-            var_store,
-            scope,
-            output,
-            pattern_type,
-            pattern,
-            region,
-            PermitShadows(false),
-        ),
+        _ => {
+            let mut loc_can_pattern = canonicalize_pattern(
+                env,
+                var_store,
+                scope,
+                output,
+                pattern_type,
+                pattern,
+                region,
+                PermitShadows(false),
+            );
+
+            report_defaults_using_pattern_bindings(env, [&mut loc_can_pattern]);
+
+            loc_can_pattern
+        }
     }
 }
 
@@ -431,43 +442,45 @@ pub fn canonicalize_pattern<'a>(
                     }
                 }
 
-                OpaqueRef(name) => match scope.lookup_opaque_ref(name, tag.region) {
-                    Ok((opaque, opaque_def)) => {
-                        debug_assert!(!can_patterns.is_empty());
-
-                        if can_patterns.len() > 1 {
-                            env.problem(Problem::RuntimeError(
-                                RuntimeError::OpaqueAppliedToMultipleArgs(region),
-                            ));
-
-                            Pattern::UnsupportedPattern(region)
-                        } else {
-                            let argument = Box::new(can_patterns.pop().unwrap());
-
-                            let (type_arguments, lambda_set_variables, specialized_def_type) =
-                                freshen_opaque_def(var_store, opaque_def);
-
-                            output.references.insert_type_lookup(
-                                opaque,
-                                crate::procedure::QualifiedReference::Unqualified,
-                            );
-
-                            Pattern::UnwrappedOpaque {
-                                whole_var: var_store.fresh(),
-                                opaque,
-                                argument,
-                                specialized_def_type: Box::new(specialized_def_type),
-                                type_arguments,
-                                lambda_set_variables,
+                OpaqueRef(name) => {
+                    match scope.lookup_opaque_ref(name, tag.region, env.dep_idents) {
+                        Ok((opaque, opaque_def)) => {
+                            debug_assert!(!can_patterns.is_empty());
+
+                            if can_patterns.len() > 1 {
+                                env.problem(Problem::RuntimeError(
+                                    RuntimeError::OpaqueAppliedToMultipleArgs(region),
+                                ));
+
+                                Pattern::UnsupportedPattern(region)
+                            } else {
+                                let argument = Box::new(can_patterns.pop().unwrap());
+
+                                let (type_arguments, lambda_set_variables, specialized_def_type) =
+                                    freshen_opaque_def(var_store, opaque_def);
+
+                                output.references.insert_type_lookup(
+                                    opaque,
+                                    crate::procedure::QualifiedReference::Unqualified,
+                                );
+
+                                Pattern::UnwrappedOpaque {
+                                    whole_var: var_store.fresh(),
+                                    opaque,
+                                    argument,
+                                    specialized_def_type: Box::new(specialized_def_type),
+                                    type_arguments,
+                                    lambda_set_variables,
+                                }
                             }
                         }
-                    }
-                    Err(runtime_error) => {
-                        env.problem(Problem::RuntimeError(runtime_error));
+                        Err(runtime_error) => {
+                            env.problem(Problem::RuntimeError(runtime_error));
 
-                        Pattern::OpaqueNotInScope(Loc::at(tag.region, name.into()))
+                            Pattern::OpaqueNotInScope(Loc::at(tag.region, name.into()))
+                        }
                     }
-                },
+                }
                 _ => unreachable!("Other patterns cannot be applied"),
             }
         }
@@ -550,6 +563,35 @@ pub fn canonicalize_pattern<'a>(
             ptype => unsupported_pattern(env, ptype, region),
         },
 
+        &IntRange(start, end) => match pattern_type {
+            WhenBranch => match (parse_int_range_bound(start), parse_int_range_bound(end)) {
+                (Some((start, _)), Some((end, _))) if start > end => {
+                    let problem = MalformedPatternProblem::EmptyIntRange;
+                    malformed_pattern(env, problem, region)
+                }
+                (Some((start, start_bound)), Some((end, end_bound))) => {
+                    match merge_int_range_bounds(start_bound, end_bound) {
+                        Some(bound) => Pattern::IntRange(
+                            var_store.fresh(),
+                            var_store.fresh(),
+                            start,
+                            end,
+                            bound,
+                        ),
+                        None => {
+                            let problem = MalformedPatternProblem::MalformedInt;
+                            malformed_pattern(env, problem, region)
+                        }
+                    }
+                }
+                _ => {
+                    let problem = MalformedPatternProblem::MalformedInt;
+                    malformed_pattern(env, problem, region)
+                }
+            },
+            ptype => unsupported_pattern(env, ptype, region),
+        },
+
         StrLiteral(literal) => match pattern_type {
             WhenBranch => flatten_str_literal(literal),
             ptype => unsupported_pattern(env, ptype, region),
@@ -937,6 +979,98 @@ fn unsupported_pattern(env: &mut Env, pattern_type: PatternType, region: Region)
     Pattern::UnsupportedPattern(region)
 }
 
+/// Parse one end of an integer range pattern. Only integers that fit in an I128 are supported.
+fn parse_int_range_bound(raw: &str) -> Option<(i128, IntBound)> {
+    let (is_negative, digits) = match raw.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, raw),
+    };
+
+    let base = match digits.get(0..2) {
+        Some("0x") => Some(ast::Base::Hex),
+        Some("0o") => Some(ast::Base::Octal),
+        Some("0b") => Some(ast::Base::Binary),
+        _ => None,
+    };
+
+    if let Some(base) = base {
+        return match finish_parsing_base(&digits[2..], base, is_negative) {
+            // finish_parsing_base already applied the sign
+            Ok((IntValue::I128(n), bound)) => Some((i128::from_ne_bytes(n), bound)),
+            _ => None,
+        };
+    }
+
+    match finish_parsing_num(raw) {
+        Ok((_, ParsedNumResult::Int(IntValue::I128(n), bound))) => {
+            Some((i128::from_ne_bytes(n), bound))
+        }
+        Ok((_, ParsedNumResult::UnknownNum(IntValue::I128(n), bound))) => {
+            let bound = match bound {
+                NumBound::None => IntBound::None,
+                NumBound::AtLeastIntOrFloat { sign, width } => IntBound::AtLeast { sign, width },
+            };
+
+            Some((i128::from_ne_bytes(n), bound))
+        }
+        _ => None,
+    }
+}
+
+/// The bound of a range pattern must admit both of its ends, e.g. `-1..200` needs at least an I16.
+/// Returns `None` if the ends have suffixes that contradict each other.
+fn merge_int_range_bounds(start: IntBound, end: IntBound) -> Option<IntBound> {
+    use roc_types::num::{IntLitWidth, SignDemand};
+    use IntBound::*;
+
+    match (start, end) {
+        (None, bound) | (bound, None) => Some(bound),
+        (Exact(a), Exact(b)) => (a == b).then_some(Exact(a)),
+        (Exact(exact), AtLeast { sign, width }) | (AtLeast { sign, width }, Exact(exact)) => {
+            let fits = exact.is_superset(&width, sign == SignDemand::Signed);
+
+            fits.then_some(Exact(exact))
+        }
+        (
+            AtLeast {
+                sign: start_sign,
+                width: start_width,
+            },
+            AtLeast {
+                sign: end_sign,
+                width: end_width,
+            },
+        ) => {
+            let width = if start_width.is_superset(&end_width, false) {
+                start_width
+            } else {
+                end_width
+            };
+
+            if start_sign == SignDemand::Signed || end_sign == SignDemand::Signed {
+                // A signed type needs one more bit to hold the largest value of an unsigned one.
+                let width = match width {
+                    IntLitWidth::U8 => IntLitWidth::I16,
+                    IntLitWidth::U16 => IntLitWidth::I32,
+                    IntLitWidth::U32 => IntLitWidth::I64,
+                    IntLitWidth::U64 => IntLitWidth::I128,
+                    other => other,
+                };
+
+                Some(AtLeast {
+                    sign: SignDemand::Signed,
+                    width,
+                })
+            } else {
+                Some(AtLeast {
+                    sign: SignDemand::NoDemand,
+                    width,
+                })
+            }
+        }
+    }
+}
+
 /// When we detect a malformed pattern like `3.X` or `0b5`,
 /// report it to Env and return an UnsupportedPattern runtime error pattern.
 fn malformed_pattern(env: &mut Env, problem: MalformedPatternProblem, region: Region) -> Pattern {
@@ -947,6 +1081,118 @@ fn malformed_pattern(env: &mut Env, problem: MalformedPatternProblem, region: Re
     Pattern::MalformedPattern(problem, region)
 }
 
+/// The default of an optional record field is evaluated before any of the names bound by
+/// the surrounding patterns are available, e.g. `\{ x, y ? x } -> ...` can't work.
+/// Report such defaults, and replace them with a runtime error.
+pub fn report_defaults_using_pattern_bindings<'p, I>(env: &mut Env, patterns: I)
+where
+    I: IntoIterator<Item = &'p mut Loc<Pattern>>,
+{
+    let patterns: Vec<_> = patterns.into_iter().collect();
+
+    let bound: Vec<Symbol> = BindingsFromPattern::new_many(patterns.iter().map(|p| &**p))
+        .map(|(symbol, _)| symbol)
+        .collect();
+
+    if bound.is_empty() {
+        return;
+    }
+
+    for loc_pattern in patterns {
+        replace_defaults_using_bindings(env, &bound, &mut loc_pattern.value);
+    }
+}
+
+fn replace_defaults_using_bindings(env: &mut Env, bound: &[Symbol], pattern: &mut Pattern) {
+    use Pattern::*;
+
+    match pattern {
+        As(subpattern, _) => replace_defaults_using_bindings(env, bound, &mut subpattern.value),
+        AppliedTag { arguments, .. } => {
+            for (_, loc_arg) in arguments {
+                replace_defaults_using_bindings(env, bound, &mut loc_arg.value);
+            }
+        }
+        UnwrappedOpaque { argument, .. } => {
+            replace_defaults_using_bindings(env, bound, &mut argument.1.value)
+        }
+        RecordDestructure { destructs, .. } => {
+            for loc_destruct in destructs {
+                match &mut loc_destruct.value.typ {
+                    DestructType::Required => {}
+                    DestructType::Optional(var, loc_default) => {
+                        let mut finder = BoundLookupFinder { bound, found: None };
+                        finder.visit_expr(&loc_default.value, loc_default.region, *var);
+
+                        if let Some(loc_symbol) = finder.found {
+                            let error = RuntimeError::DefaultUsesPatternBinding {
+                                symbol: loc_symbol.value,
+                                region: loc_symbol.region,
+                            };
+
+                            env.problem(Problem::RuntimeError(error.clone()));
+                            loc_default.value = Expr::RuntimeError(error);
+                        }
+                    }
+                    DestructType::Guard(_, loc_guard) => {
+                        replace_defaults_using_bindings(env, bound, &mut loc_guard.value)
+                    }
+                }
+            }
+        }
+        TupleDestructure { destructs, .. } => {
+            for loc_destruct in destructs {
+                replace_defaults_using_bindings(env, bound, &mut loc_destruct.value.typ.1.value);
+            }
+        }
+        List { patterns, .. } => {
+            for loc_pattern in patterns.patterns.iter_mut() {
+                replace_defaults_using_bindings(env, bound, &mut loc_pattern.value);
+            }
+        }
+        Identifier(_)
+        | NumLiteral(..)
+        | IntLiteral(..)
+        | IntRange(..)
+        | FloatLiteral(..)
+        | StrLiteral(_)
+        | SingleQuote(..)
+        | Underscore
+        | AbilityMemberSpecialization { .. }
+        | Shadowed(..)
+        | OpaqueNotInScope(..)
+        | UnsupportedPattern(..)
+        | MalformedPattern(..) => {}
+    }
+}
+
+/// Finds the first lookup of one of the `bound` symbols in an expression.
+struct BoundLookupFinder<'b> {
+    bound: &'b [Symbol],
+    found: Option<Loc<Symbol>>,
+}
+
+impl Visitor for BoundLookupFinder<'_> {
+    fn should_visit(&mut self, _region: Region) -> bool {
+        self.found.is_none()
+    }
+
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        match expr {
+            Expr::Var(symbol, _) if self.bound.contains(symbol) => {
+                if self.found.is_none() {
+                    self.found = Some(Loc::at(region, *symbol));
+                }
+            }
+            _ => {
+                if self.should_visit(region) {
+                    walk_expr(self, expr, var);
+                }
+            }
+        }
+    }
+}
+
 /// An iterator over the bindings made by a pattern.
 ///
 /// We attempt to make no allocations when we can.
@@ -1019,6 +1265,7 @@ impl<'a> BindingsFromPattern<'a> {
                         }
                         NumLiteral(..)
                         | IntLiteral(..)
+                        | IntRange(..)
                         | FloatLiteral(..)
                         | StrLiteral(_)
                         | SingleQuote(..)
@@ -1,7 +1,7 @@
 use roc_collections::{VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::ident::{Ident, ModuleName};
-use roc_module::symbol::{IdentId, IdentIds, ModuleId, ModuleIds, Symbol};
+use roc_module::symbol::{IdentId, IdentIds, IdentIdsByModule, ModuleId, ModuleIds, Symbol};
 use roc_problem::can::{RuntimeError, ScopeModuleSource};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::Variable;
@@ -131,6 +131,7 @@ impl Scope {
         &self,
         opaque_ref: &str,
         lookup_region: Region,
+        dep_idents: &IdentIdsByModule,
     ) -> Result<(Symbol, &Alias), RuntimeError> {
         debug_assert!(opaque_ref.starts_with('@'));
         let opaque_str = &opaque_ref[1..];
@@ -152,6 +153,16 @@ impl Scope {
                         referenced_region: lookup_region,
                         imported_region: decl_region,
                     }
+                } else if let Some(import_region) =
+                    self.modules
+                        .find_import_defining_opaque(opaque_str, dep_idents, &self.aliases)
+                {
+                    // the opaque is not exposed by name, but an imported module defines it
+                    RuntimeError::OpaqueOutsideScope {
+                        opaque,
+                        referenced_region: lookup_region,
+                        imported_region: import_region,
+                    }
                 } else {
                     self.opaque_not_defined_error(opaque, lookup_region, None)
                 };
@@ -751,6 +762,36 @@ impl ScopeModules {
         self.names.iter()
     }
 
+    /// Finds a module imported into this scope that defines an opaque type with the given name,
+    /// and returns the region of its import.
+    pub fn find_import_defining_opaque(
+        &self,
+        ident: &str,
+        dep_idents: &IdentIdsByModule,
+        aliases: &VecMap<Symbol, Alias>,
+    ) -> Option<Region> {
+        self.ids
+            .iter()
+            .zip(self.sources.iter())
+            .find_map(|(module_id, source)| match source {
+                ScopeModuleSource::Import(region) => dep_idents
+                    .get(module_id)
+                    .and_then(|ident_ids| ident_ids.get_id(ident))
+                    .map(|ident_id| Symbol::new(*module_id, ident_id))
+                    .filter(|symbol| {
+                        matches!(
+                            aliases.get(symbol),
+                            Some(Alias {
+                                kind: AliasKind::Opaque,
+                                ..
+                            })
+                        )
+                    })
+                    .map(|_| *region),
+                ScopeModuleSource::Builtin | ScopeModuleSource::Current => None,
+            })
+    }
+
     pub fn insert(
         &mut self,
         module_name: ModuleName,
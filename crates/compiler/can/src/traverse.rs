@@ -612,6 +612,7 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
             .for_each(|p| visitor.visit_pattern(&p.value, p.region, Some(*elem_var))),
         NumLiteral(..) => { /* terminal */ }
         IntLiteral(..) => { /* terminal */ }
+        IntRange(..) => { /* terminal */ }
         FloatLiteral(..) => { /* terminal */ }
         StrLiteral(..) => { /* terminal */ }
         SingleQuote(..) => { /* terminal */ }
@@ -163,6 +163,40 @@ mod test_can {
         );
     }
 
+    #[test]
+    fn int_misplaced_underscores() {
+        use roc_parse::ast::Base;
+
+        for string in ["1__000", "1_000_"] {
+            assert_can_runtime_error(
+                string,
+                RuntimeError::InvalidInt(
+                    IntErrorKind::InvalidDigit,
+                    Base::Decimal,
+                    Region::zero(),
+                    string.into(),
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn float_misplaced_underscore() {
+        let string = "1_.5";
+        let region = Region::zero();
+
+        assert_can_runtime_error(
+            string,
+            RuntimeError::InvalidFloat(FloatErrorKind::Error, region, string.into()),
+        );
+    }
+
+    #[test]
+    fn int_with_underscores() {
+        assert_can_int("1_000_000", 1_000_000);
+        assert_can_int("0xFF_FF", 0xFF_FF);
+    }
+
     #[test]
     fn zero() {
         assert_can_num("0", 0);
@@ -1076,6 +1110,52 @@ mod test_can {
         assert!(&branches[0].guard.is_none());
     }
 
+    #[test]
+    fn backpassing_desugars_to_trailing_closure() {
+        let src = indoc!(
+            r#"
+                foo = \a, f -> f a
+
+                x <- foo 1
+
+                x
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert!(matches!(
+            out.problems.as_slice(),
+            [Problem::DeprecatedBackpassing(_)]
+        ));
+
+        // Assert that we desugar to:
+        //
+        // foo 1 (\x -> x)
+
+        let body = match &out.loc_expr.value {
+            LetNonRec(_, body) | LetRec(_, body, _) => &body.value,
+            other => panic!("Expr was not a definition of foo: {:?}", other),
+        };
+        let args = assert_func_call(body, "foo", CalledVia::Space, &out.interns);
+
+        assert_eq!(args.len(), 2);
+        assert_num_value(&args[0].1.value, 1);
+
+        match &args[1].1.value {
+            Closure(ClosureData {
+                arguments,
+                loc_body,
+                ..
+            }) => {
+                assert_eq!(arguments.len(), 1);
+                assert_pattern_name(&arguments[0].2.value, "x", &out.interns);
+                assert_var_usage(&loc_body.value, "x", &out.interns);
+            }
+            other => panic!("Last argument was not a closure: {:?}", other),
+        }
+    }
+
     fn assert_num_value(expr: &Expr, num: usize) {
         match expr {
             Expr::Num(_, num_str, _, _) => {
@@ -2051,6 +2131,17 @@ mod test_can {
             ),
             "foo\nbar",
         );
+
+        // indentation up to the opening """ is stripped, the rest is kept
+        assert_can_string(
+            r#"
+            """
+            foo
+              bar
+            """
+            "#,
+            "foo\n  bar",
+        );
     }
 
     //     #[test]
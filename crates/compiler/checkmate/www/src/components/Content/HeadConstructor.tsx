@@ -221,6 +221,9 @@ export default function DrawHeadConstructor({
     case "EmptyRecord": {
       return <>{"{}"}</>;
     }
+    case "EmptyTuple": {
+      return <>()</>;
+    }
     case "EmptyTagUnion": {
       return <>[]</>;
     }
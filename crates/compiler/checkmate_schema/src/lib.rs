@@ -98,6 +98,7 @@ impl_content! {
         extension: TagUnionExtension,
     },
     EmptyRecord {},
+    EmptyTuple {},
     EmptyTagUnion {},
     EffectfulFunc {},
     RangedNumber {
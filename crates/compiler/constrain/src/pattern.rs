@@ -87,6 +87,7 @@ fn headers_from_annotation_help(
         | OpaqueNotInScope(..)
         | NumLiteral(..)
         | IntLiteral(..)
+        | IntRange(..)
         | FloatLiteral(..)
         | SingleQuote(..)
         | StrLiteral(_) => true,
@@ -400,7 +401,8 @@ pub fn constrain_pattern_help(
             ));
         }
 
-        &IntLiteral(num_precision_var, precision_var, _, _, bound) => {
+        &IntLiteral(num_precision_var, precision_var, _, _, bound)
+        | &IntRange(num_precision_var, precision_var, _, _, bound) => {
             // First constraint on the free num var; this improves the resolved type quality in
             // case the bound is an alias.
             let num_type = builtins::add_numeric_bound_constr(
@@ -21,6 +21,7 @@ mod decoding;
 mod encoding;
 mod hash;
 mod inspect;
+mod sort;
 mod util;
 
 pub(crate) const DERIVED_SYNTH: ModuleId = ModuleId::DERIVED_SYNTH;
@@ -83,6 +84,7 @@ fn build_derived_body(
         DeriveKey::ToInspector(to_inspector_key) => {
             inspect::derive_to_inspector(&mut env, to_inspector_key, derived_symbol)
         }
+        DeriveKey::Compare(sort_key) => sort::derive_compare(&mut env, sort_key, derived_symbol),
     };
 
     let def = Def {
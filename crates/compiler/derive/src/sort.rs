@@ -0,0 +1,567 @@
+//! Derivers for the `Sort` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern},
+    pattern::Pattern,
+};
+use roc_derive_key::sort::FlatSortKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+        RedundantMark, SubsSlice, TagExt, TupleElems, UnionLambdas, UnionTags, Variable,
+    },
+    types::RecordField,
+};
+
+use crate::{
+    synth_var,
+    util::{Env, ExtensionKind},
+    DerivedBody,
+};
+
+pub(crate) fn derive_compare(
+    env: &mut Env<'_>,
+    key: FlatSortKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatSortKey::Record(fields) => compare_record(env, def_symbol, fields),
+        FlatSortKey::Tuple(arity) => compare_tuple(env, def_symbol, arity),
+        FlatSortKey::TagUnion(tags) => compare_tag_union(env, def_symbol, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::SORT_COMPARE);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn compare_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, a comparator for this record is
+    //
+    // compare_rcd : { f1: t1, ..., fn: tn }, { f1: t1, ..., fn: tn } -> [LT, EQ, GT]
+    // compare_rcd = \rcd1, rcd2 ->
+    //   when Sort.compare rcd1.f1 rcd2.f1 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare rcd1.fn rcd2.fn
+    //     ordering -> ordering
+    //
+    // where the fields are visited in alphabetical order.
+    let rcd1_sym = env.new_symbol("rcd1");
+    let rcd2_sym = env.new_symbol("rcd2");
+
+    let comparisons: Vec<_> = record_fields
+        .iter_all()
+        .map(|(field_name, field_var, _)| {
+            let field_name = env.subs[field_name].clone();
+            let field_var = env.subs[field_var];
+
+            let mut field_access = |rcd_sym| Expr::RecordAccess {
+                record_var,
+                field_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                    rcd_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                field: field_name.clone(),
+            };
+
+            (field_var, field_access(rcd1_sym), field_access(rcd2_sym))
+        })
+        .collect();
+
+    let (body_var, body) = compare_in_order(env, comparisons);
+
+    // Finally, build the closure
+    // \rcd1, rcd2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        record_var,
+        (Pattern::Identifier(rcd1_sym), Pattern::Identifier(rcd2_sym)),
+        (body_var, body),
+    )
+}
+
+fn compare_tuple(env: &mut Env<'_>, fn_name: Symbol, arity: u32) -> (Variable, Expr) {
+    // Suppose tup = (v1, ..., vn).
+    // Build a generalized type t_tup = (t1, ..., tn), with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many tuples of the same arity.
+    let (tuple_var, tuple_elems) = {
+        let flex_elems: Vec<_> = (0..arity)
+            .map(|i| (i as usize, env.subs.fresh_unnamed_flex_var()))
+            .collect();
+        let elems = TupleElems::insert_into_subs(env.subs, flex_elems);
+        let tuple_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Tuple(elems, Variable::EMPTY_TUPLE)),
+        );
+
+        (tuple_var, elems)
+    };
+
+    // Now, a comparator for this tuple is
+    //
+    // compare_tup : (t1, ..., tn), (t1, ..., tn) -> [LT, EQ, GT]
+    // compare_tup = \tup1, tup2 ->
+    //   when Sort.compare tup1.0 tup2.0 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare tup1.n tup2.n
+    //     ordering -> ordering
+    let tup1_sym = env.new_symbol("tup1");
+    let tup2_sym = env.new_symbol("tup2");
+
+    let comparisons: Vec<_> = tuple_elems
+        .iter_all()
+        .map(|(elem_idx, elem_var)| {
+            let index = env.subs[elem_idx];
+            let elem_var = env.subs[elem_var];
+
+            let mut elem_access = |tup_sym| Expr::TupleAccess {
+                tuple_var,
+                elem_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                    tup_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                index,
+            };
+
+            (elem_var, elem_access(tup1_sym), elem_access(tup2_sym))
+        })
+        .collect();
+
+    let (body_var, body) = compare_in_order(env, comparisons);
+
+    // Finally, build the closure
+    // \tup1, tup2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        tuple_var,
+        (Pattern::Identifier(tup1_sym), Pattern::Identifier(tup2_sym)),
+        (body_var, body),
+    )
+}
+
+fn compare_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ], sorted by tag name.
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = env.subs.reserve_into_vars(arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, a comparator for this tag union is
+    //
+    // compare_union : [ A t11 .. t1n, ..., Q tq1 .. tqm ], [ A t11 .. t1n, ..., Q tq1 .. tqm ] -> [LT, EQ, GT]
+    // compare_union = \union1, union2 ->
+    //   when union1 is
+    //     A x11 .. x1n ->
+    //       when union2 is
+    //         A y11 .. y1n -> <compare x11 with y11, ..., x1n with y1n, in order>
+    //         B _ .. _ -> LT
+    //         ...
+    //         Q _ .. _ -> LT
+    //     ...
+    //
+    // Tags that come earlier in alphabetical order are less than tags that come later.
+    let union1_sym = env.new_symbol("union1");
+    let union2_sym = env.new_symbol("union2");
+
+    let tags: Vec<_> = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| {
+            let tag_name = env.subs[tag].clone();
+            let payload_vars = env.subs.get_subs_slice(env.subs[payloads]).to_vec();
+            (tag_name, payload_vars)
+        })
+        .collect();
+
+    let ordering_var = ordering_var(env);
+
+    let outer_branches = tags
+        .iter()
+        .enumerate()
+        .map(|(outer_n, (outer_tag, outer_payload_vars))| {
+            // x11 .. x1n
+            let outer_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(outer_payload_vars.len())
+                .collect();
+
+            let inner_branches = tags
+                .iter()
+                .enumerate()
+                .map(|(inner_n, (inner_tag, inner_payload_vars))| {
+                    if inner_n == outer_n {
+                        // y11 .. y1n
+                        let inner_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                            .take(inner_payload_vars.len())
+                            .collect();
+
+                        let comparisons = (inner_payload_vars.iter())
+                            .zip(outer_syms.iter().zip(inner_syms.iter()))
+                            .map(|(var, (x, y))| (*var, Expr::Var(*x, *var), Expr::Var(*y, *var)))
+                            .collect();
+
+                        let (body_var, body) = compare_in_order(env, comparisons);
+                        env.unify(ordering_var, body_var);
+
+                        branch(
+                            tag_pattern(union_var, inner_tag, inner_payload_vars, &inner_syms),
+                            body,
+                        )
+                    } else {
+                        let ordering = if outer_tag.0.as_str() < inner_tag.0.as_str() {
+                            "LT"
+                        } else {
+                            "GT"
+                        };
+
+                        branch(
+                            tag_pattern(union_var, inner_tag, inner_payload_vars, &[]),
+                            ordering_tag(env, ordering_var, ordering),
+                        )
+                    }
+                })
+                .collect();
+
+            let inner_when = Expr::When {
+                loc_cond: Box::new(Loc::at_zero(Expr::Var(union2_sym, union_var))),
+                cond_var: union_var,
+                expr_var: ordering_var,
+                region: Region::zero(),
+                branches: inner_branches,
+                branches_cond_var: union_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            };
+
+            branch(
+                tag_pattern(union_var, outer_tag, outer_payload_vars, &outer_syms),
+                inner_when,
+            )
+        })
+        .collect();
+
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(union1_sym, union_var))),
+        cond_var: union_var,
+        expr_var: ordering_var,
+        region: Region::zero(),
+        branches: outer_branches,
+        branches_cond_var: union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    // Finally, build the closure
+    // \union1, union2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        union_var,
+        (
+            Pattern::Identifier(union1_sym),
+            Pattern::Identifier(union2_sym),
+        ),
+        (ordering_var, when_expr),
+    )
+}
+
+/// Builds a lexicographic comparison of the given pairs of values, returning the ordering of
+/// the first pair that is not `EQ`, or `EQ` if all pairs are equal.
+fn compare_in_order(
+    env: &mut Env<'_>,
+    comparisons: Vec<(Variable, Expr, Expr)>,
+) -> (Variable, Expr) {
+    let ordering_var = ordering_var(env);
+
+    // Build up from the last comparison, so that the first comparison ends up outermost.
+    let mut comparisons = comparisons.into_iter().rev();
+
+    let last = match comparisons.next() {
+        Some((var, left, right)) => {
+            let (compare_var, compare_expr) = call_sort_compare(env, var, left, right);
+            env.unify(ordering_var, compare_var);
+            compare_expr
+        }
+        None => ordering_tag(env, ordering_var, "EQ"),
+    };
+
+    let body = comparisons.fold(last, |rest, (var, left, right)| {
+        let (compare_var, compare_expr) = call_sort_compare(env, var, left, right);
+        env.unify(ordering_var, compare_var);
+
+        // when Sort.compare left right is
+        //   EQ -> rest
+        //   ordering -> ordering
+        let ordering_sym = env.new_symbol("ordering");
+        let eq_branch = branch(
+            Pattern::AppliedTag {
+                whole_var: ordering_var,
+                tag_name: "EQ".into(),
+                ext_var: Variable::EMPTY_TAG_UNION,
+                arguments: vec![],
+            },
+            rest,
+        );
+        let other_branch = branch(
+            Pattern::Identifier(ordering_sym),
+            Expr::Var(ordering_sym, ordering_var),
+        );
+
+        Expr::When {
+            loc_cond: Box::new(Loc::at_zero(compare_expr)),
+            cond_var: ordering_var,
+            expr_var: ordering_var,
+            region: Region::zero(),
+            branches: vec![eq_branch, other_branch],
+            branches_cond_var: ordering_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    });
+
+    (ordering_var, body)
+}
+
+/// `[LT, EQ, GT]`
+fn ordering_var(env: &mut Env<'_>) -> Variable {
+    let labels = ["EQ", "GT", "LT"]
+        .into_iter()
+        .map(|name| (TagName::from(name), SubsSlice::default()))
+        .collect::<Vec<_>>();
+    let union_tags = UnionTags::insert_slices_into_subs(env.subs, labels);
+
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::TagUnion(
+            union_tags,
+            TagExt::Any(Variable::EMPTY_TAG_UNION),
+        )),
+    )
+}
+
+fn ordering_tag(env: &mut Env<'_>, ordering_var: Variable, name: &str) -> Expr {
+    Expr::Tag {
+        tag_union_var: ordering_var,
+        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+        name: name.into(),
+        arguments: vec![],
+    }
+}
+
+/// `A x1 .. xn`, or `A _ .. _` if no symbols are given.
+fn tag_pattern(
+    union_var: Variable,
+    tag_name: &TagName,
+    payload_vars: &[Variable],
+    payload_syms: &[Symbol],
+) -> Pattern {
+    let arguments = if payload_syms.is_empty() {
+        (payload_vars.iter())
+            .map(|var| (*var, Loc::at_zero(Pattern::Underscore)))
+            .collect()
+    } else {
+        (payload_vars.iter())
+            .zip(payload_syms.iter())
+            .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+            .collect()
+    };
+
+    Pattern::AppliedTag {
+        whole_var: union_var,
+        tag_name: tag_name.clone(),
+        ext_var: Variable::EMPTY_TAG_UNION,
+        arguments,
+    }
+}
+
+fn branch(pattern: Pattern, value: Expr) -> WhenBranch {
+    WhenBranch {
+        patterns: vec![WhenBranchPattern {
+            pattern: Loc::at_zero(pattern),
+            degenerate: false,
+        }],
+        value: Loc::at_zero(value),
+        guard: None,
+        redundant: RedundantMark::known_non_redundant(),
+    }
+}
+
+fn call_sort_compare(
+    env: &mut Env<'_>,
+    val_var: Variable,
+    left: Expr,
+    right: Expr,
+) -> (Variable, Expr) {
+    // build `Sort.compare ...` function type.
+    //
+    // a, a -[uls]-> [LT, EQ, GT] where a implements Sort
+    let exposed_compare_fn_var = env.import_builtin_symbol_var(Symbol::SORT_COMPARE);
+
+    // (typeof val), (typeof val) -[clos]-> ordering
+    let this_arguments_slice = env.subs.insert_into_vars([val_var, val_var]);
+    let this_compare_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_ordering_var = env.subs.fresh_unnamed_flex_var();
+    let this_compare_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_compare_clos_var,
+            this_ordering_var,
+            Variable::PURE,
+        )),
+    );
+
+    //   a,             a             -[uls]->  [LT, EQ, GT] where a implements Sort
+    // ~ (typeof val),  (typeof val)  -[clos]-> ordering
+    env.unify(exposed_compare_fn_var, this_compare_fn_var);
+
+    // Sort.compare : (typeof val), (typeof val) -[clos]-> [LT, EQ, GT] where (typeof val) implements Sort
+    let compare_fn_head = Expr::AbilityMember(Symbol::SORT_COMPARE, None, this_compare_fn_var);
+    let compare_fn_data = Box::new((
+        this_compare_fn_var,
+        Loc::at_zero(compare_fn_head),
+        this_compare_clos_var,
+        this_ordering_var,
+        Variable::PURE,
+    ));
+
+    let compare_arguments = vec![
+        (val_var, Loc::at_zero(left)),
+        (val_var, Loc::at_zero(right)),
+    ];
+    let call_compare = Expr::Call(compare_fn_data, compare_arguments, CalledVia::Space);
+
+    (this_ordering_var, call_compare)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    val_var: Variable,
+    patterns: (Pattern, Pattern),
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (left_pattern, right_pattern) = patterns;
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // val_var, val_var -[fn_name]-> body_var
+        let args_slice = env.subs.insert_into_vars([val_var, val_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(
+                args_slice,
+                fn_clos_var,
+                body_var,
+                Variable::PURE,
+            )),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        fx_type: Variable::PURE,
+        early_returns: vec![],
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(left_pattern),
+            ),
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(right_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
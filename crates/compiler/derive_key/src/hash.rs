@@ -70,7 +70,9 @@ impl FlatHash {
                 FlatType::Tuple(elems, ext) => {
                     let (elems_iter, ext) = elems.sorted_iterator_and_ext(subs, ext);
 
-                    check_derivable_ext_var(subs, ext, |_| false)?;
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTuple))
+                    })?;
 
                     Ok(Key(FlatHashKey::Tuple(elems_iter.count() as _)))
                 }
@@ -110,6 +112,7 @@ impl FlatHash {
                         .collect(),
                 ))),
                 FlatType::EmptyRecord => Ok(Key(FlatHashKey::Record(vec![]))),
+                FlatType::EmptyTuple => Ok(Key(FlatHashKey::Tuple(0))),
                 FlatType::EmptyTagUnion => Ok(Key(FlatHashKey::TagUnion(vec![]))),
                 //
                 FlatType::Func(..) | FlatType::EffectfulFunc => Err(Underivable),
@@ -17,11 +17,13 @@ pub mod decoding;
 pub mod encoding;
 pub mod hash;
 pub mod inspect;
+pub mod sort;
 mod util;
 
 use decoding::{FlatDecodable, FlatDecodableKey};
 use encoding::{FlatEncodable, FlatEncodableKey};
 use hash::{FlatHash, FlatHashKey};
+use sort::{FlatSort, FlatSortKey};
 
 use inspect::{FlatInspectable, FlatInspectableKey};
 use roc_module::symbol::Symbol;
@@ -43,6 +45,7 @@ pub enum DeriveKey {
     Decoder(FlatDecodableKey),
     Hash(FlatHashKey),
     ToInspector(FlatInspectableKey),
+    Compare(FlatSortKey),
 }
 
 impl DeriveKey {
@@ -52,6 +55,7 @@ impl DeriveKey {
             DeriveKey::Decoder(key) => format!("decoder_{}", key.debug_name()),
             DeriveKey::Hash(key) => format!("hash_{}", key.debug_name()),
             DeriveKey::ToInspector(key) => format!("toInspector_{}", key.debug_name()),
+            DeriveKey::Compare(key) => format!("compare_{}", key.debug_name()),
         }
     }
 }
@@ -82,6 +86,7 @@ pub enum DeriveBuiltin {
     Hash,
     IsEq,
     ToInspector,
+    Compare,
 }
 
 impl TryFrom<Symbol> for DeriveBuiltin {
@@ -94,6 +99,7 @@ impl TryFrom<Symbol> for DeriveBuiltin {
             Symbol::HASH_HASH => Ok(DeriveBuiltin::Hash),
             Symbol::BOOL_IS_EQ => Ok(DeriveBuiltin::IsEq),
             Symbol::INSPECT_TO_INSPECTOR => Ok(DeriveBuiltin::ToInspector),
+            Symbol::SORT_COMPARE => Ok(DeriveBuiltin::Compare),
             _ => Err(value),
         }
     }
@@ -131,6 +137,12 @@ impl Derived {
                 FlatInspectable::Immediate(imm) => Ok(Derived::Immediate(imm)),
                 FlatInspectable::Key(repr) => Ok(Derived::Key(DeriveKey::ToInspector(repr))),
             },
+            DeriveBuiltin::Compare => match sort::FlatSort::from_var(subs, var)? {
+                FlatSort::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatSort::Key(repr) => Ok(Derived::Key(DeriveKey::Compare(repr))),
+            },
         }
     }
 
@@ -167,6 +179,12 @@ impl Derived {
                     FlatInspectable::Key(repr) => Ok(Derived::Key(DeriveKey::ToInspector(repr))),
                 }
             }
+            DeriveBuiltin::Compare => match sort::FlatSort::from_builtin_symbol(symbol)? {
+                FlatSort::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatSort::Key(repr) => Ok(Derived::Key(DeriveKey::Compare(repr))),
+            },
         }
     }
 }
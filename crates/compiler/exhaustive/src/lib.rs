@@ -125,6 +125,13 @@ impl ListArity {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Literal {
     Int([u8; 16]),
+    /// An inclusive range of integers. When the integer type being matched is known,
+    /// `type_range` holds its smallest and largest values, so that ranges can cover all of it.
+    IntRange {
+        start: [u8; 16],
+        end: [u8; 16],
+        type_range: Option<([u8; 16], [u8; 16])>,
+    },
     U128([u8; 16]),
     Bit(bool),
     Byte(u8),
@@ -134,6 +141,34 @@ pub enum Literal {
     Str(Box<str>),
 }
 
+impl Literal {
+    /// The inclusive range of integers this literal matches, if it is an integer literal.
+    fn int_range(&self) -> Option<(i128, i128)> {
+        match self {
+            Literal::Int(n) => {
+                let n = i128::from_ne_bytes(*n);
+                Some((n, n))
+            }
+            Literal::IntRange { start, end, .. } => {
+                Some((i128::from_ne_bytes(*start), i128::from_ne_bytes(*end)))
+            }
+            _ => None,
+        }
+    }
+
+    fn from_int_range((start, end): (i128, i128)) -> Self {
+        if start == end {
+            Literal::Int(start.to_ne_bytes())
+        } else {
+            Literal::IntRange {
+                start: start.to_ne_bytes(),
+                end: end.to_ne_bytes(),
+                type_range: None,
+            }
+        }
+    }
+}
+
 /// Error
 
 #[derive(Clone, Debug, PartialEq)]
@@ -287,6 +322,20 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
                     .collect()
             }
         }
+        CollectedCtors::IntRanges(pieces) => {
+            let is_piece_exhaustive = |piece: (i128, i128)| {
+                let new_matrix = specialize_matrix_by_int_range(piece, matrix);
+                let mut rest = is_exhaustive(&new_matrix, n - 1);
+
+                for row in rest.iter_mut() {
+                    row.push(Pattern::Literal(Literal::from_int_range(piece)));
+                }
+
+                rest
+            };
+
+            pieces.into_iter().flat_map(is_piece_exhaustive).collect()
+        }
         CollectedCtors::NonExhaustiveList(alt_lists) => {
             let is_alt_exhaustive = |arity: ListArity| {
                 let new_matrix: Vec<_> = matrix
@@ -417,6 +466,17 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                     }
 
                     Anything => {
+                        if let Some(type_range) = int_type_range(&old_matrix) {
+                            // Integer ranges can cover every value of their type, so check each
+                            // piece of the type that the ranges split it into.
+                            break int_range_pieces(&old_matrix, type_range).into_iter().any(
+                                |piece| {
+                                    let matrix = specialize_matrix_by_int_range(piece, &old_matrix);
+                                    is_useful(matrix, vector.clone())
+                                },
+                            );
+                        }
+
                         // check if all alternatives appear in matrix
                         match is_complete(&old_matrix) {
                             Complete::No => {
@@ -461,6 +521,16 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                     }
 
                     Literal(literal) => {
+                        if let Some(range) = literal.int_range() {
+                            // The rows may only cover this integer range piece by piece.
+                            break int_range_pieces(&old_matrix, range)
+                                .into_iter()
+                                .any(|piece| {
+                                    let matrix = specialize_matrix_by_int_range(piece, &old_matrix);
+                                    is_useful(matrix, vector.clone())
+                                });
+                        }
+
                         // keep checking rows that start with this Literal or Anything
 
                         for mut row in old_matrix.drain(..) {
@@ -641,6 +711,27 @@ fn specialize_row_by_ctor(tag_id: TagId, arity: usize, mut row: Row) -> Option<R
     }
 }
 
+/// INVARIANT: (length row == N) ==> (length result == N-1)
+fn specialize_matrix_by_int_range(piece: (i128, i128), matrix: &RefPatternMatrix) -> PatternMatrix {
+    let (piece_start, piece_end) = piece;
+
+    matrix
+        .iter()
+        .filter_map(|row| {
+            let mut row = row.to_vec();
+
+            match row.pop() {
+                Some(Anything) => Some(row),
+                Some(Literal(literal)) => match literal.int_range() {
+                    Some((start, end)) if start <= piece_start && piece_end <= end => Some(row),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 /// INVARIANT: (length row == N) ==> (length result == N-1)
 fn specialize_row_by_anything(row: &RefRow) -> Option<Row> {
     let mut row = row.to_vec();
@@ -661,7 +752,9 @@ pub enum Complete {
 fn is_complete(matrix: &RefPatternMatrix) -> Complete {
     let ctors = collect_ctors(matrix);
     match ctors {
-        CollectedCtors::NonExhaustiveAny | CollectedCtors::NonExhaustiveList(_) => Complete::No,
+        CollectedCtors::NonExhaustiveAny
+        | CollectedCtors::NonExhaustiveList(_)
+        | CollectedCtors::IntRanges(_) => Complete::No,
         CollectedCtors::Ctors(ctors) => {
             let length = ctors.len();
             let mut it = ctors.into_iter();
@@ -690,6 +783,7 @@ type Row = Vec<Pattern>;
 enum CollectedCtors {
     NonExhaustiveAny,
     NonExhaustiveList(Vec<ListArity>),
+    IntRanges(Vec<(i128, i128)>),
     Ctors(MutMap<TagId, Union>),
 }
 
@@ -703,7 +797,10 @@ fn collect_ctors(matrix: &RefPatternMatrix) -> CollectedCtors {
     if let Some(ctor) = first_row.last() {
         match ctor {
             Anything => CollectedCtors::NonExhaustiveAny,
-            Pattern::Literal(_) => CollectedCtors::NonExhaustiveAny,
+            Pattern::Literal(_) => match int_type_range(matrix) {
+                Some(type_range) => CollectedCtors::IntRanges(int_range_pieces(matrix, type_range)),
+                None => CollectedCtors::NonExhaustiveAny,
+            },
             List(_, _) => {
                 let list_ctors = build_list_ctors_covering_patterns(
                     ListArity::ANY,
@@ -903,3 +1000,42 @@ fn filter_matrix_list_ctors(matrix: &RefPatternMatrix) -> impl Iterator<Item = L
         _ => None,
     })
 }
+
+/// The smallest and largest values of the integer type matched by the first column, if any row
+/// matches it with a range that knows its type.
+fn int_type_range(matrix: &RefPatternMatrix) -> Option<(i128, i128)> {
+    matrix.iter().find_map(|row| match row.last() {
+        Some(Literal(Literal::IntRange {
+            type_range: Some((min, max)),
+            ..
+        })) => Some((i128::from_ne_bytes(*min), i128::from_ne_bytes(*max))),
+        _ => None,
+    })
+}
+
+/// Split the integers from `start` to `end` into pieces, such that every integer literal in the
+/// first column either covers a piece entirely or not at all.
+fn int_range_pieces(matrix: &RefPatternMatrix, (start, end): (i128, i128)) -> Vec<(i128, i128)> {
+    let mut piece_starts = vec![start];
+
+    for row in matrix {
+        if let Some(Literal(literal)) = row.last() {
+            if let Some((lit_start, lit_end)) = literal.int_range() {
+                piece_starts.push(lit_start);
+                piece_starts.extend(lit_end.checked_add(1));
+            }
+        }
+    }
+
+    piece_starts.retain(|piece_start| (start..=end).contains(piece_start));
+    piece_starts.sort_unstable();
+    piece_starts.dedup();
+
+    let piece_ends = piece_starts
+        .iter()
+        .skip(1)
+        .map(|next| next - 1)
+        .chain([end]);
+
+    piece_starts.iter().copied().zip(piece_ends).collect()
+}
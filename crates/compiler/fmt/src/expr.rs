@@ -96,6 +96,10 @@ impl<'a> Formattable for Expr<'a> {
 
             ParensAround(subexpr) => subexpr.is_multiline(),
 
+            TypeAscription(loc_subexpr, loc_ann) => {
+                loc_subexpr.is_multiline() || loc_ann.is_multiline()
+            }
+
             Closure(loc_patterns, loc_body) => {
                 // check the body first because it's more likely to be multiline
                 loc_body.is_multiline()
@@ -455,6 +459,13 @@ impl<'a> Formattable for Expr<'a> {
             Return(return_value, after_return) => {
                 fmt_return(buf, return_value, after_return, parens, newlines, indent);
             }
+            TypeAscription(sub_expr, ann) => {
+                sub_expr.format_with_options(buf, Parens::NotNeeded, newlines, indent);
+                buf.indent(indent);
+                buf.push_str(" :");
+                buf.spaces(1);
+                ann.format(buf, indent);
+            }
             If {
                 if_thens: branches,
                 final_else,
@@ -1649,6 +1660,7 @@ fn sub_expr_requests_parens(expr: &Expr<'_>) -> bool {
         }
         Expr::If { .. } => true,
         Expr::Defs(_, _) => true,
+        Expr::TypeAscription(_, _) => true,
         Expr::SpaceBefore(e, _) => sub_expr_requests_parens(e),
         Expr::SpaceAfter(e, _) => sub_expr_requests_parens(e),
         _ => false,
@@ -74,6 +74,7 @@ impl<'a> Formattable for Pattern<'a> {
             | Pattern::NumLiteral(..)
             | Pattern::NonBase10Literal { .. }
             | Pattern::FloatLiteral(..)
+            | Pattern::IntRange(..)
             | Pattern::SingleQuote(_)
             | Pattern::Underscore(_)
             | Pattern::Malformed(_)
@@ -189,6 +190,12 @@ impl<'a> Formattable for Pattern<'a> {
                 buf.indent(indent);
                 buf.push_str(string);
             }
+            &IntRange(start, end) => {
+                buf.indent(indent);
+                buf.push_str(start);
+                buf.push_str("..");
+                buf.push_str(end);
+            }
             StrLiteral(literal) => fmt_str_literal(buf, *literal, indent),
             SingleQuote(string) => {
                 buf.indent(indent);
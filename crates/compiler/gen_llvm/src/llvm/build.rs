@@ -5461,6 +5461,7 @@ pub fn build_procedures<'a>(
     host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
     entry_point: EntryPoint<'a>,
     debug_output_file: Option<&Path>,
+    unoptimized_ir_file: Option<&Path>,
     glue_layouts: &GlueLayouts<'a>,
 ) {
     let mod_solutions = build_procedures_help(
@@ -5471,6 +5472,7 @@ pub fn build_procedures<'a>(
         host_exposed_lambda_sets,
         entry_point,
         debug_output_file,
+        unoptimized_ir_file,
     );
 
     let niche = Niche::NONE;
@@ -5525,6 +5527,7 @@ pub fn build_wasm_test_wrapper<'a, 'ctx>(
         vec![],
         EntryPoint::Program(env.arena.alloc([entry_point])),
         Some(&std::env::temp_dir().join("test.ll")),
+        None,
     );
 
     promote_to_wasm_test_wrapper(
@@ -5552,6 +5555,7 @@ pub fn build_procedures_return_main<'a, 'ctx>(
         host_exposed_lambda_sets,
         EntryPoint::Program(env.arena.alloc([entry_point])),
         Some(&std::env::temp_dir().join("test.ll")),
+        None,
     );
 
     promote_to_main_function(
@@ -5586,6 +5590,7 @@ pub fn build_procedures_expose_expects<'a>(
         vec![],
         entry_point,
         Some(&std::env::temp_dir().join("test.ll")),
+        None,
     );
 
     let captures_niche = Niche::NONE;
@@ -5661,6 +5666,7 @@ fn build_procedures_help<'a>(
     host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
     entry_point: EntryPoint<'a>,
     debug_output_file: Option<&Path>,
+    unoptimized_ir_file: Option<&Path>,
 ) -> &'a ModSolutions {
     let mut layout_ids = roc_mono::layout::LayoutIds::default();
     let mut scope = Scope::default();
@@ -5700,6 +5706,10 @@ fn build_procedures_help<'a>(
 
     let (_, function_pass) = construct_optimization_passes(env.module, opt_level);
 
+    // To write out the IR before any optimizations, hold off on running the
+    // function passes until every function has been built.
+    let mut unoptimized_fn_vals = std::vec::Vec::new();
+
     for (proc, fn_vals) in headers {
         for (func_spec_solutions, fn_val) in fn_vals {
             let mut current_scope = scope.clone();
@@ -5723,7 +5733,11 @@ fn build_procedures_help<'a>(
             env.dibuilder.finalize();
 
             if fn_val.verify(true) {
-                function_pass.run_on(&fn_val);
+                if unoptimized_ir_file.is_some() {
+                    unoptimized_fn_vals.push(fn_val);
+                } else {
+                    function_pass.run_on(&fn_val);
+                }
             } else {
                 let mode = "NON-OPTIMIZED";
 
@@ -5756,6 +5770,14 @@ fn build_procedures_help<'a>(
         }
     }
 
+    if let Some(unoptimized_ir_file) = unoptimized_ir_file {
+        env.module.print_to_file(unoptimized_ir_file).unwrap();
+
+        for fn_val in unoptimized_fn_vals {
+            function_pass.run_on(&fn_val);
+        }
+    }
+
     use LlvmBackendMode::*;
     match env.mode {
         GenTest | WasmGenTest | CliTest => { /* no host, or exposing types is not supported */ }
@@ -26,6 +26,9 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::HASH, "Hash.roc"),
     (ModuleId::INSPECT, "Inspect.roc"),
     (ModuleId::TASK, "Task.roc"),
+    (ModuleId::SORT, "Sort.roc"),
+    (ModuleId::DEQUE, "Deque.roc"),
+    (ModuleId::SORTED_DICT, "SortedDict.roc"),
 ];
 
 fn main() {
@@ -101,6 +104,7 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
         &module.interns,
         &mut module.can_problems,
         &mut module.type_problems,
+        &mut module.type_hints,
     );
 
     if problems.errors + problems.warnings > 0 {
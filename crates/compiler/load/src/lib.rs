@@ -257,6 +257,10 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
     let mod_hash = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Hash.dat"));
     let mod_inspect = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Inspect.dat"));
     let mod_task = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Task.dat"));
+    let mod_sort = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Sort.dat"));
+    let mod_deque = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Deque.dat"));
+    let mod_sorted_dict =
+        include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/SortedDict.dat"));
 
     let mut output = MutMap::default();
 
@@ -282,6 +286,9 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
         output.insert(ModuleId::INSPECT, deserialize_help(mod_inspect));
 
         output.insert(ModuleId::TASK, deserialize_help(mod_task));
+        output.insert(ModuleId::SORT, deserialize_help(mod_sort));
+        output.insert(ModuleId::DEQUE, deserialize_help(mod_deque));
+        output.insert(ModuleId::SORTED_DICT, deserialize_help(mod_sorted_dict));
     }
 
     output
@@ -45,9 +45,88 @@ mod test_reporting {
             doc,
             filename: filename_from_string(r"/code/proj/Main.roc"),
             severity: Severity::RuntimeError,
+            region: None,
         }
     }
 
+    #[test]
+    fn render_report_as_json() {
+        use ven_pretty::DocAllocator;
+
+        let interns = Interns::default();
+        let alloc = RocDocAllocator::new(&[], test_home(), &interns);
+
+        let report = Report {
+            title: "UNUSED DEFINITION".to_string(),
+            doc: alloc.reflow("`x` is not used anywhere in your code."),
+            filename: filename_from_string(r"/code/proj/Main.roc"),
+            severity: Severity::Warning,
+            region: None,
+        };
+
+        let mut buf = String::new();
+        report.render_json(&mut buf, &alloc);
+
+        assert!(!buf.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&buf).unwrap(),
+            serde_json::json!({
+                "severity": "warning",
+                "code": "UNUSED_DEFINITION",
+                "title": "UNUSED DEFINITION",
+                "filename": "/code/proj/Main.roc",
+                "region": null,
+                "labels": [],
+                "message": "`x` is not used anywhere in your code.",
+            })
+        );
+    }
+
+    #[test]
+    fn render_report_region_as_json() {
+        use roc_region::all::{LineColumn, LineColumnRegion};
+        use ven_pretty::DocAllocator;
+
+        let src_lines = ["main =", "    x = 1", "    2"];
+        let interns = Interns::default();
+        let alloc = RocDocAllocator::new(&src_lines, test_home(), &interns);
+
+        let region = LineColumnRegion {
+            start: LineColumn { line: 1, column: 4 },
+            end: LineColumn { line: 1, column: 5 },
+        };
+
+        let report = Report {
+            title: "UNUSED DEFINITION".to_string(),
+            doc: alloc.stack([
+                alloc.reflow("`x` is not used anywhere in your code."),
+                alloc.region(region, Severity::Warning),
+                alloc.reflow("If you didn't intend on using `x` then remove it."),
+            ]),
+            filename: filename_from_string(r"/code/proj/Main.roc"),
+            severity: Severity::Warning,
+            region: None,
+        };
+
+        let mut buf = String::new();
+        report.render_json(&mut buf, &alloc);
+
+        let region_json = serde_json::json!({
+            "start": { "line": 2, "column": 5, "offset": 11 },
+            "end": { "line": 2, "column": 6, "offset": 12 },
+        });
+        let json = serde_json::from_str::<serde_json::Value>(&buf).unwrap();
+
+        assert_eq!(json["region"], region_json);
+        assert_eq!(
+            json["labels"],
+            serde_json::json!([{
+                "message": "`x` is not used anywhere in your code.",
+                "region": region_json,
+            }])
+        );
+    }
+
     fn promote_expr_to_module(src: &str) -> String {
         let mut buffer = String::from("app \"test\" provides [main] to \"./platform\"\n\nmain =\n");
 
@@ -151,6 +230,7 @@ mod test_reporting {
         subdir: &str,
         arena: &'a Bump,
         expr_src: &'a str,
+        include_hints: bool,
     ) -> Result<
         (
             String,
@@ -166,17 +246,28 @@ mod test_reporting {
             module_id: home,
             mut can_problems,
             mut type_problems,
+            mut type_hints,
             interns,
             ..
         } = result?;
 
         let can_problems = can_problems.remove(&home).unwrap_or_default();
-        let type_problems = type_problems.remove(&home).unwrap_or_default();
+        let mut type_problems = type_problems.remove(&home).unwrap_or_default();
+
+        if include_hints {
+            type_problems.extend(type_hints.remove(&home).unwrap_or_default());
+        }
 
         Ok((module_src, type_problems, can_problems, home, interns))
     }
 
-    fn list_reports_new<F>(subdir: &str, arena: &Bump, src: &str, finalize_render: F) -> String
+    fn list_reports_new<F>(
+        subdir: &str,
+        arena: &Bump,
+        src: &str,
+        include_hints: bool,
+        finalize_render: F,
+    ) -> String
     where
         F: FnOnce(RocDocBuilder<'_>, &mut String),
     {
@@ -186,7 +277,7 @@ mod test_reporting {
 
         let mut buf = String::new();
 
-        match infer_expr_help_new(subdir, arena, src) {
+        match infer_expr_help_new(subdir, arena, src, include_hints) {
             Err(LoadingProblem::FormattedReport(fail)) => fail,
             Ok((module_src, type_problems, can_problems, home, interns)) => {
                 let lines = LineInfo::new(&module_src);
@@ -424,7 +515,13 @@ mod test_reporting {
     }
 
     /// Do not call this directly! Use the test_report macro below!
-    fn __new_report_problem_as(test_name: &str, src: &str, check_render: impl FnOnce(&str)) {
+    /// Hints like inferred type holes aren't problems, so `test_no_problem!` leaves them out.
+    fn __new_report_problem_as(
+        test_name: &str,
+        src: &str,
+        include_hints: bool,
+        check_render: impl FnOnce(&str),
+    ) {
         let arena = Bump::new();
 
         let finalize_render = |doc: RocDocBuilder<'_>, buf: &mut String| {
@@ -433,7 +530,7 @@ mod test_reporting {
                 .expect("list_reports")
         };
 
-        let buf = list_reports_new(test_name, &arena, src, finalize_render);
+        let buf = list_reports_new(test_name, &arena, src, include_hints, finalize_render);
 
         check_render(buf.as_str());
     }
@@ -446,7 +543,7 @@ mod test_reporting {
             #[test]
             $(#[$meta])*
             fn $test_name() {
-                __new_report_problem_as(std::stringify!($test_name), $program, $expecting)
+                __new_report_problem_as(std::stringify!($test_name), $program, true, $expecting)
             }
         }
     }
@@ -456,7 +553,7 @@ mod test_reporting {
             #[test]
             $(#[$meta])*
             fn $test_name() {
-                __new_report_problem_as(std::stringify!($test_name), $program, |golden| pretty_assertions::assert_eq!(golden, ""))
+                __new_report_problem_as(std::stringify!($test_name), $program, false, |golden| pretty_assertions::assert_eq!(golden, ""))
             }
         }
     }
@@ -658,10 +755,10 @@ mod test_reporting {
 
     Did you mean one of these?
 
-        Str
         Frac
         Num
-        U8
+        Str
+        Deque
     "###
     );
 
@@ -1468,11 +1565,11 @@ mod test_reporting {
             Red,
         ]
 
-    Tip: Seems like a tag typo. Maybe `Blue` should be `Red`?
+    Tip: Looks like a closed tag union does not have the `Blue` tag. Maybe
+    `Blue` should be `Red`?
 
-    Tip: Can more type annotations be added? Type annotations always help
-    me give more specific messages, and I think they could help a lot in
-    this case
+    Tip: Closed tag unions can't grow, because that might change the size
+    in memory. Can you use an open tag union?
     "
     );
 
@@ -1505,11 +1602,11 @@ mod test_reporting {
             Red (Int *),
         ]
 
-    Tip: Seems like a tag typo. Maybe `Blue` should be `Red`?
+    Tip: Looks like a closed tag union does not have the `Blue` tag. Maybe
+    `Blue` should be `Red`?
 
-    Tip: Can more type annotations be added? Type annotations always help
-    me give more specific messages, and I think they could help a lot in
-    this case
+    Tip: Closed tag unions can't grow, because that might change the size
+    in memory. Can you use an open tag union?
     "
     );
 
@@ -1867,7 +1964,7 @@ mod test_reporting {
 
         Num *
 
-    But all the previous branches match:
+    But all the previous patterns in this branch match:
 
         {}a
     "
@@ -2543,9 +2640,6 @@ mod test_reporting {
         [B, …]
 
     Tip: Looks like a closed tag union does not have the `B` tag.
-
-    Tip: Closed tag unions can't grow, because that might change the size
-    in memory. Can you use an open tag union?
     "
     );
 
@@ -2581,9 +2675,6 @@ mod test_reporting {
         ]
 
     Tip: Looks like a closed tag union does not have the `B` and `C` tags.
-
-    Tip: Closed tag unions can't grow, because that might change the size
-    in memory. Can you use an open tag union?
     "
     );
 
@@ -2635,9 +2726,6 @@ mod test_reporting {
         [Right Str, …] -> {}
 
     Tip: Looks like a closed tag union does not have the `Right` tag.
-
-    Tip: Closed tag unions can't grow, because that might change the size
-    in memory. Can you use an open tag union?
     "
     );
 
@@ -3360,7 +3448,38 @@ mod test_reporting {
     4│      Foo a : [Foo]
                 ^
 
-    Roc does not allow unused type parameters!
+    An unused type parameter has no effect on the type, so it can be
+    removed.
+
+    Tip: If you want an unused type parameter (a so-called "phantom
+    type"), read the guide section on phantom values.
+    "#
+    );
+
+    test_report!(
+        phantom_type_variable_keeps_alias,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Foo a : [A, B]
+
+            main : Foo Str -> U8
+            main = \x -> when x is
+                A -> 1
+                B -> 2
+            "#
+        ),
+        @r#"
+    ── UNUSED TYPE ALIAS PARAMETER in /code/proj/Main.roc ──────────────────────────
+
+    The `a` type parameter is not used in the `Foo` alias definition:
+
+    3│  Foo a : [A, B]
+            ^
+
+    An unused type parameter has no effect on the type, so it can be
+    removed.
 
     Tip: If you want an unused type parameter (a so-called "phantom
     type"), read the guide section on phantom values.
@@ -4080,6 +4199,56 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        optional_record_default_uses_sibling_field,
+        indoc!(
+            r#"
+            f = \{ x, y ? x } -> x + y
+            f { x: 1u8 }
+            "#
+        ),
+        @r#"
+    ── DEFAULT USES PATTERN NAME in /code/proj/Main.roc ────────────────────────────
+
+    This default value uses `x`, which is bound by the same pattern:
+
+    4│      f = \{ x, y ? x } -> x + y
+                          ^
+
+    The default of an optional field is computed before any of the names
+    in its pattern are available, so it can't refer to them.
+
+    Tip: Give the field a default that doesn't depend on the pattern, and
+    compute the value you need in the body instead.
+    "#
+    );
+
+    test_report!(
+        optional_record_default_uses_sibling_field_in_def,
+        indoc!(
+            r#"
+            f = \r ->
+                { x, y ? x } = r
+                x + y
+            f { x: 1u8 }
+            "#
+        ),
+        @r#"
+    ── DEFAULT USES PATTERN NAME in /code/proj/Main.roc ────────────────────────────
+
+    This default value uses `x`, which is bound by the same pattern:
+
+    5│          { x, y ? x } = r
+                         ^
+
+    The default of an optional field is computed before any of the names
+    in its pattern are available, so it can't refer to them.
+
+    Tip: Give the field a default that doesn't depend on the pattern, and
+    compute the value you need in the body instead.
+    "#
+    );
+
     test_report!(
         optional_field_mismatch_with_annotation,
         indoc!(
@@ -6685,6 +6854,34 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        expect_expr_type_error_in_function_body,
+        indoc!(
+            r#"
+            f = \x ->
+                expect "x is positive"
+                x
+
+            f 1
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This `expect` condition needs to be a Bool:
+
+    5│          expect "x is positive"
+                       ^^^^^^^^^^^^^^^
+
+    Right now it’s a string of type:
+
+        Str
+
+    But I need every `expect` condition to evaluate to a Bool—either
+    `Bool.true` or `Bool.false`.
+    "#
+    );
+
     test_report!(
         num_too_general_wildcard,
         indoc!(
@@ -7074,11 +7271,11 @@ All branches in an `if` must have the same type!
 
         [Email Str]
 
-    Tip: Seems like a tag typo. Maybe `Name` should be `Email`?
+    Tip: Looks like a closed tag union does not have the `Name` tag. Maybe
+    `Name` should be `Email`?
 
-    Tip: Can more type annotations be added? Type annotations always help
-    me give more specific messages, and I think they could help a lot in
-    this case
+    Tip: Closed tag unions can't grow, because that might change the size
+    in memory. Can you use an open tag union?
     "#
     );
 
@@ -7544,6 +7741,22 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        hex_u8_overflow,
+        "0x1FFu8",
+        @r"
+    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+
+    This integer literal overflows the type indicated by its suffix:
+
+    4│      0x1FFu8
+            ^^^^^^^
+
+    Tip: The suffix indicates this integer is a U8, whose maximum value is
+    255.
+    "
+    );
+
     test_report!(
         negative_u8,
         "-1u8",
@@ -7840,6 +8053,9 @@ All branches in an `if` must have the same type!
     But `get` needs its 2nd argument to be:
 
         U64
+
+    Tip: A `U64` can only hold whole numbers from 0 to 18446744073709551615,
+    so this number doesn't fit in one.
     "
     );
 
@@ -7866,6 +8082,9 @@ All branches in an `if` must have the same type!
     But `get` needs its 2nd argument to be:
 
         U64
+
+    Tip: A `U64` can only hold whole numbers from 0 to 18446744073709551615,
+    so this number doesn't fit in one.
     "
     );
 
@@ -7893,6 +8112,9 @@ All branches in an `if` must have the same type!
     But `get` needs its 2nd argument to be:
 
         U64
+
+    Tip: A `U64` can only hold whole numbers from 0 to 18446744073709551615,
+    so this number doesn't fit in one.
     "
     );
 
@@ -7923,6 +8145,9 @@ All branches in an `if` must have the same type!
         U8
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+    doesn't fit in one.
     "
     );
 
@@ -8174,10 +8399,10 @@ All branches in an `if` must have the same type!
     Is there an import missing? Perhaps there is a typo. Did you mean one
     of these?
 
+        Sort
         Encode
         Inspect
         Dict
-        List
     "
     );
 
@@ -8585,6 +8810,216 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        inferred_type_hole,
+        indoc!(
+            r#"
+            f : Str, _ -> List _
+            f = \prefix, n -> List.repeat (Str.concat prefix (Num.toStr n)) 2u64
+
+            f "x" 1u8
+            "#
+        ),
+        @r###"
+        ── INFERRED TYPE HOLE in /code/proj/Main.roc ───────────────────────────────────
+
+        I inferred the type of this hole in an annotation:
+
+        4│      f : Str, _ -> List _
+                         ^
+
+        It is:
+
+            Num *
+
+        You can replace the hole with this type, or keep the hole if you'd
+        like it to stay inferred.
+
+        ── INFERRED TYPE HOLE in /code/proj/Main.roc ───────────────────────────────────
+
+        I inferred the type of this hole in an annotation:
+
+        4│      f : Str, _ -> List _
+                                   ^
+
+        It is:
+
+            Str
+
+        You can replace the hole with this type, or keep the hole if you'd
+        like it to stay inferred.
+        "###
+    );
+
+    test_report!(
+        inline_type_annotation_mismatch,
+        indoc!(
+            r#"
+            x = (Str.concat "a" "b" : U64)
+
+            x
+            "#
+        ),
+        @r###"
+        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+        Something is off with the body of this definition:
+
+        4│      x = (Str.concat "a" "b" : U64)
+                     ^^^^^^^^^^^^^^^^^^
+
+        This `concat` call produces:
+
+            Str
+
+        But the type annotation says it should be:
+
+            U64
+        "###
+    );
+
+    test_report!(
+        annotation_constrained_with_has,
+        indoc!(
+            r"
+            toStr : a -> Str | a has Inspect
+            toStr = \_ -> ""
+
+            toStr 1
+            "
+        ),
+        @r###"
+        ── OLD ABILITY SYNTAX in tmp/annotation_constrained_with_has/Test.roc ──────────
+
+        This type annotation constrains its type variables with the old `|`
+        syntax:
+
+        4│      toStr : a -> Str | a has Inspect
+                                 ^
+
+        Ability constraints are now written with `where` and `implements` instead:
+
+            toStr : a -> Str where a implements Inspect
+        "###
+    );
+
+    test_report!(
+        number_literal_does_not_fit_annotation,
+        indoc!(
+            r"
+            x : U8
+            x = 300
+
+            x
+            "
+        ),
+        @r###"
+        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+        Something is off with the body of the `x` definition:
+
+        4│      x : U8
+        5│      x = 300
+                    ^^^
+
+        The body is a number of type:
+
+            I16, U16, F32, I32, U32, F64, I64, U64, I128, Dec, or U128
+
+        But the type annotation on `x` says it should be:
+
+            U8
+
+        Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+        doesn't fit in one.
+        "###
+    );
+
+    test_report!(
+        tuple_annotation_arity_mismatch,
+        indoc!(
+            r"
+            x : (U8, U8, U8)
+            x = (1, 2)
+
+            x
+            "
+        ),
+        @r"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    Something is off with the body of the `x` definition:
+
+    4│      x : (U8, U8, U8)
+    5│      x = (1, 2)
+                ^^^^^^
+
+    The body is a tuple of type:
+
+        (
+            Num *,
+            Num *,
+        )
+
+    But the type annotation on `x` says it should be:
+
+        (
+            U8,
+            U8,
+            U8,
+        )
+    "
+    );
+
+    test_report!(
+        opaque_type_derives_with_has,
+        indoc!(
+            r"
+            Id := U64 has [Eq, Inspect]
+
+            1
+            "
+        ),
+        @r###"
+        ── OLD ABILITY SYNTAX in tmp/opaque_type_derives_with_has/Test.roc ─────────────
+
+        This opaque type lists its abilities after the `has` keyword:
+
+        4│      Id := U64 has [Eq, Inspect]
+                          ^^^
+
+        Abilities are now listed after the `implements` keyword instead:
+
+            Id := U64 implements [Eq, Inspect]
+        "###
+    );
+
+    test_report!(
+        ability_defined_with_has,
+        indoc!(
+            r"
+            MEq has
+                eq : a, a -> Bool | a has MEq
+
+            1
+            "
+        ),
+        @r###"
+        ── OLD ABILITY SYNTAX in tmp/ability_defined_with_has/Test.roc ─────────────────
+
+        This looks like an ability definition, but it uses the `has` keyword:
+
+        4│      MEq has
+                    ^^^
+
+        Abilities are now defined with the `implements` keyword instead, and
+        ability constraints are written with `where` and `implements` as well:
+
+            MEq implements
+                eq : a, a -> Bool where a implements MEq
+        "###
+    );
+
     test_report!(
         ability_demands_not_indented_with_first,
         indoc!(
@@ -10080,7 +10515,8 @@ All branches in an `if` must have the same type!
 
     Only builtin abilities can be derived.
 
-    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Inspect`
+    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Inspect`,
+    `Sort`
     "
     );
 
@@ -11469,6 +11905,41 @@ All branches in an `if` must have the same type!
     "###
     );
 
+    test_report!(
+        record_with_optional_field_types_cannot_derive_sort,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main =
+                 compareRecords : {x : Str, y ? Str}, {x : Str, y ? Str} -> [LT, EQ, GT]
+                 compareRecords = Sort.compare
+
+                 compareRecords
+             "#
+        ),
+        @r###"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    5│      compareRecords = Sort.compare
+                             ^^^^^^^^^^^^
+
+    I can't generate an implementation of the `Sort` ability for
+
+        {
+            x : Str,
+            y ? Str,
+        }
+
+    Note: I can't derive `Sort.compare` for a record with an optional
+    field, which in this case is `.y`. Default value record fields are
+    polymorphic over records that may or may not contain them at compile
+    time, but are not a concept that extends to runtime!
+    "###
+    );
+
     test_report!(
         uninhabited_type_is_trivially_exhaustive,
         indoc!(
@@ -12077,7 +12548,7 @@ All branches in an `if` must have the same type!
         (
             Str,
             {}a -> {},
-        )a
+        )
 
     In particular, an implementation for
 
@@ -12116,6 +12587,9 @@ All branches in an `if` must have the same type!
 
         U8
 
+    Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+    doesn't fit in one.
+
     ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
 
     This 2nd argument to `shiftRightBy` has an unexpected type:
@@ -12131,6 +12605,9 @@ All branches in an `if` must have the same type!
 
         U8
 
+    Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+    doesn't fit in one.
+
     ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
 
     This 2nd argument to `shiftLeftBy` has an unexpected type:
@@ -12145,6 +12622,9 @@ All branches in an `if` must have the same type!
     But `shiftLeftBy` needs its 2nd argument to be:
 
         U8
+
+    Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+    doesn't fit in one.
     "
     );
 
@@ -12228,6 +12708,9 @@ All branches in an `if` must have the same type!
         U16, I32, U32, I64, U64, I128, or U128
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: A `U8` can only hold whole numbers from 0 to 255, so this number
+    doesn't fit in one.
     "#
     );
 
@@ -12509,7 +12992,7 @@ All branches in an `if` must have the same type!
         (
             Str,
             F64,
-        )a
+        )
 
     In particular, an implementation for
 
@@ -12844,6 +13327,51 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        list_pattern_rest_without_as,
+        indoc!(
+            r#"
+            when [] is
+                [head, .. tail] -> ""
+            "#
+        ),
+    @r#"
+    ── INCORRECT REST PATTERN in tmp/list_pattern_rest_without_as/Test.roc ─────────
+
+    It looks like you are trying to name the rest of this list, but there
+    is no `as` before the name:
+
+    5│          [head, .. tail] -> ""
+                          ^
+
+    To bind the rest of a list to a name, put `as` between the .. and the
+    name, like so:
+
+        [head, .. as tail] -> ""
+    "#
+    );
+
+    test_report!(
+        as_pattern_with_uppercase_name,
+        indoc!(
+            r"
+            when A 1 is
+                A _ as Whole -> 1
+            "
+        ),
+    @r#"
+    ── UNFINISHED AS PATTERN in tmp/as_pattern_with_uppercase_name/Test.roc ────────
+
+    I am partway through parsing an `as` pattern, but I got stuck here:
+
+    5│          A _ as Whole -> 1
+                       ^
+
+    I was expecting a lowercase name for the whole value, like
+    A x as whole.
+    "#
+    );
+
     test_report!(
         unnecessary_extension_variable,
         indoc!(
@@ -13065,6 +13593,129 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        list_match_non_exhaustive_nested_in_tag,
+        indoc!(
+            r#"
+            x : [C (List [A Str, B])]
+
+            when x is
+                C [] -> ""
+                C [B, ..] -> ""
+            "#
+        ),
+    @r#"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when x is
+    7│>          C [] -> ""
+    8│>          C [B, ..] -> ""
+
+    Other possibilities include:
+
+        C [A _, ..]
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "#
+    );
+
+    test_report!(
+        int_range_patterns_non_exhaustive,
+        indoc!(
+            r#"
+            x : U8
+
+            when x is
+                0..9 -> ""
+                20..255 -> ""
+            "#
+        ),
+    @r#"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when x is
+    7│>          0..9 -> ""
+    8│>          20..255 -> ""
+
+    Other possibilities include:
+
+        10..19
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "#
+    );
+
+    test_no_problem!(
+        int_range_patterns_cover_whole_type,
+        indoc!(
+            r#"
+            f : I8 -> Str
+            f = \x ->
+                when x is
+                    -128..-1 -> "negative"
+                    0 -> "zero"
+                    1..127 -> "positive"
+
+            f 1
+            "#
+        )
+    );
+
+    test_report!(
+        int_range_pattern_redundant,
+        indoc!(
+            r#"
+            x : U8
+
+            when x is
+                0..9 -> ""
+                10..19 -> ""
+                5..15 -> ""
+                _ -> ""
+            "#
+        ),
+    @r#"
+    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+
+    The 3rd pattern is redundant:
+
+     6│       when x is
+     7│           0..9 -> ""
+     8│           10..19 -> ""
+     9│>          5..15 -> ""
+    10│           _ -> ""
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "#
+    );
+
+    test_report!(
+        int_range_pattern_empty,
+        indoc!(
+            r#"
+            when 1 is
+                9..1 -> ""
+                _ -> ""
+            "#
+        ),
+    @r#"
+    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+
+    This range pattern is malformed:
+
+    5│          9..1 -> ""
+                ^^^^
+
+    Tip: The start of a range pattern can't be bigger than its end, so
+    this range can never match
+    "#
+    );
+
     test_no_problem!(
         list_match_spread_required_front_back,
         indoc!(
@@ -14053,6 +14704,37 @@ All branches in an `if` must have the same type!
         )
     );
 
+    test_report!(
+        explicit_inferred_open_in_output_position_hints_its_type,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main : List [One, Two] -> List [One]_
+            main = \tags ->
+                List.map tags \tag ->
+                    when tag is
+                        One -> One
+                        Two -> Two
+            "#
+        ),
+        @r###"
+        ── INFERRED TYPE HOLE in /code/proj/Main.roc ───────────────────────────────────
+
+        I inferred the type of this hole in an annotation:
+
+        3│  main : List [One, Two] -> List [One]_
+                                                ^
+
+        It is:
+
+            [Two]
+
+        You can replace the hole with this type, or keep the hole if you'd
+        like it to stay inferred.
+        "###
+    );
+
     test_no_problem!(
         derive_decoding_for_tuple,
         indoc!(
@@ -14506,11 +15188,11 @@ All branches in an `if` must have the same type!
           (
               Str,
               Str,
-          )a
+          )
 
       But you are trying to use it as:
 
-          { abcde : * }b
+          { abcde : * }a
       "###
     );
 
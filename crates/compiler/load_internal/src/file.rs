@@ -1675,7 +1675,8 @@ fn state_thread_step<'a>(
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
-                    let buf = to_file_problem_report_string(filename, error, true);
+                    let buf =
+                        to_file_problem_report_string(filename, error, state.render, state.palette);
                     Err(LoadingProblem::FormattedReport(buf))
                 }
 
@@ -1702,6 +1703,7 @@ fn state_thread_step<'a>(
                         filename,
                         bytes,
                         state.render,
+                        state.palette,
                     );
                     Err(LoadingProblem::FormattedReport(buf))
                 }
@@ -1750,6 +1752,7 @@ fn state_thread_step<'a>(
                                 cycle,
                                 filename,
                                 render,
+                                palette,
                             );
                             return Err(LoadingProblem::FormattedReport(buf));
                         }
@@ -1767,6 +1770,7 @@ fn state_thread_step<'a>(
                                 filename,
                                 bytes,
                                 render,
+                                palette,
                             );
                             return Err(LoadingProblem::FormattedReport(buf));
                         }
@@ -1791,6 +1795,7 @@ fn state_thread_step<'a>(
                                 shorthand,
                                 available,
                                 render,
+                                palette,
                             );
                             return Err(LoadingProblem::FormattedReport(buf));
                         }
@@ -1824,7 +1829,14 @@ pub fn report_loading_problem(
         LoadingProblem::ImportCycle(filename, cycle) => {
             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
 
-            to_import_cycle_report(module_ids, root_exposed_ident_ids, cycle, filename, render)
+            to_import_cycle_report(
+                module_ids,
+                root_exposed_ident_ids,
+                cycle,
+                filename,
+                render,
+                palette,
+            )
         }
         LoadingProblem::IncorrectModuleName(FileError {
             problem: SourceError { problem, bytes },
@@ -1839,11 +1851,12 @@ pub fn report_loading_problem(
                 filename,
                 bytes,
                 render,
+                palette,
             )
         }
         LoadingProblem::FormattedReport(report) => report,
         LoadingProblem::FileProblem { filename, error } => {
-            to_file_problem_report_string(filename, error, true)
+            to_file_problem_report_string(filename, error, render, palette)
         }
         LoadingProblem::NoPlatformPackage {
             filename,
@@ -1858,6 +1871,7 @@ pub fn report_loading_problem(
             region,
             source,
             render,
+            palette,
         ),
         LoadingProblem::MultiplePlatformPackages {
             filename,
@@ -1872,6 +1886,7 @@ pub fn report_loading_problem(
             region,
             source,
             render,
+            palette,
         ),
         LoadingProblem::UnrecognizedPackageShorthand {
             filename,
@@ -1890,6 +1905,7 @@ pub fn report_loading_problem(
             shorthand,
             available,
             render,
+            palette,
         ),
         err => todo!("Loading error: {:?}", err),
     }
@@ -2342,6 +2358,9 @@ fn update<'a>(
                 extend_module_with_builtin_import(parsed, ModuleId::HASH);
                 extend_module_with_builtin_import(parsed, ModuleId::INSPECT);
                 extend_module_with_builtin_import(parsed, ModuleId::TASK);
+                extend_module_with_builtin_import(parsed, ModuleId::SORT);
+                extend_module_with_builtin_import(parsed, ModuleId::DEQUE);
+                extend_module_with_builtin_import(parsed, ModuleId::SORTED_DICT);
             }
             state
                 .module_cache
@@ -2460,6 +2479,10 @@ fn update<'a>(
                 .module_cache
                 .type_problems
                 .insert(module_id, solved_module.problems);
+            state
+                .module_cache
+                .type_hints
+                .insert(module_id, solved_module.hints);
             state
                 .module_cache
                 .exposes
@@ -3168,7 +3191,13 @@ fn finish_specialization<'a>(
                     }
                     Valid(To::NewPackage(p_or_p)) => PathBuf::from(p_or_p.as_str()),
                     other => {
-                        let buf = report_cannot_run(state.root_id, state.root_path, other);
+                        let buf = report_cannot_run(
+                            state.root_id,
+                            state.root_path,
+                            other,
+                            state.render,
+                            state.palette,
+                        );
                         return Err(LoadingProblem::FormattedReport(buf));
                     }
                 };
@@ -3230,6 +3259,7 @@ fn finish_specialization<'a>(
 
     let ModuleCache {
         type_problems,
+        type_hints,
         can_problems,
         sources,
         ..
@@ -3251,6 +3281,7 @@ fn finish_specialization<'a>(
     Ok(MonomorphizedModule {
         can_problems,
         type_problems,
+        type_hints,
         expectations: module_expectations,
         exposed_to_host,
         module_id,
@@ -3341,6 +3372,7 @@ fn finish(
         solved,
         can_problems: state.module_cache.can_problems,
         type_problems: state.module_cache.type_problems,
+        type_hints: state.module_cache.type_hints,
         declarations_by_id,
         typechecked: state.module_cache.checked,
         dep_idents,
@@ -3664,6 +3696,9 @@ fn load_module<'a>(
         "Hash", ModuleId::HASH
         "Inspect", ModuleId::INSPECT
         "Task", ModuleId::TASK
+        "Sort", ModuleId::SORT
+        "Deque", ModuleId::DEQUE
+        "SortedDict", ModuleId::SORTED_DICT
     }
 
     let (filename, opt_shorthand) = module_name_to_path(src_dir, &module_name, arc_shorthands);
@@ -4648,6 +4683,7 @@ struct SolveResult {
     solved_implementations: ResolvedImplementations,
     exposed_vars_by_symbol: Vec<(Symbol, Variable)>,
     problems: Vec<TypeError>,
+    hints: Vec<TypeError>,
     abilities_store: AbilitiesStore,
     imported_modules_with_params: Vec<ModuleId>,
 
@@ -4676,6 +4712,7 @@ fn run_solve_solve(
         rigid_variables,
         abilities_store: pending_abilities,
         module_params,
+        has_can_errors,
         ..
     } = module;
 
@@ -4745,6 +4782,7 @@ fn run_solve_solve(
             subs,
             solve_aliases,
             abilities_store,
+            has_can_errors,
         );
 
         let solved_implementations =
@@ -4778,6 +4816,7 @@ fn run_solve_solve(
         subs,
         scope: _,
         errors,
+        hints,
         resolved_abilities_store,
 
         #[cfg(debug_assertions)]
@@ -4789,6 +4828,7 @@ fn run_solve_solve(
         solved_implementations,
         exposed_vars_by_symbol,
         problems: errors,
+        hints,
         abilities_store: resolved_abilities_store,
         imported_modules_with_params,
 
@@ -4860,6 +4900,7 @@ fn run_solve<'a>(
                     solved_implementations,
                     exposed_vars_by_symbol,
                     problems: vec![],
+                    hints: vec![],
                     abilities_store: abilities,
                     imported_modules_with_params: vec![],
 
@@ -4891,6 +4932,7 @@ fn run_solve<'a>(
         solved_implementations,
         exposed_vars_by_symbol,
         mut problems,
+        hints,
         abilities_store,
         imported_modules_with_params,
 
@@ -4924,6 +4966,7 @@ fn run_solve<'a>(
     let solved_module = SolvedModule {
         exposed_vars_by_symbol,
         problems,
+        hints,
         aliases,
         solved_implementations,
         exposed_types,
@@ -5238,6 +5281,9 @@ fn canonicalize_and_constrain<'a>(
                         | ModuleId::HASH
                         | ModuleId::INSPECT
                         | ModuleId::TASK
+                        | ModuleId::SORT
+                        | ModuleId::DEQUE
+                        | ModuleId::SORTED_DICT
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -5247,6 +5293,11 @@ fn canonicalize_and_constrain<'a>(
         }
     }
 
+    let has_can_errors = module_output
+        .problems
+        .iter()
+        .any(|problem| matches!(problem.severity(), Severity::RuntimeError | Severity::Fatal));
+
     let module = Module {
         module_id,
         exposed_imports: module_output.exposed_imports,
@@ -5258,6 +5309,7 @@ fn canonicalize_and_constrain<'a>(
         loc_expects: module_output.loc_expects,
         loc_dbgs: module_output.loc_dbgs,
         module_params: module_output.module_params,
+        has_can_errors,
     };
 
     let constrained_module = ConstrainedModule {
@@ -6394,8 +6446,9 @@ fn to_import_cycle_report(
     import_cycle: Vec<ModuleId>,
     filename: PathBuf,
     render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
 
     // import_cycle looks like CycleModule, Import1, ..., ImportN, CycleModule
@@ -6439,10 +6492,10 @@ fn to_import_cycle_report(
         doc,
         title: "IMPORT CYCLE".to_string(),
         severity: Severity::RuntimeError,
+        region: None,
     };
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
     buf
 }
@@ -6454,8 +6507,9 @@ fn to_incorrect_module_name_report<'a>(
     filename: PathBuf,
     src: &'a [u8],
     render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
 
     let IncorrectModuleName {
@@ -6469,7 +6523,7 @@ fn to_incorrect_module_name_report<'a>(
     // SAFETY: if the module was not UTF-8, that would be reported as a parsing problem, rather
     // than an incorrect module name problem (the latter can happen only after parsing).
     let src = unsafe { from_utf8_unchecked(src) };
-    let src_lines = src.lines().collect::<Vec<_>>();
+    let src_lines = src.split('\n').collect::<Vec<_>>();
     let lines = LineInfo::new(src);
 
     let interns = Interns {
@@ -6490,10 +6544,10 @@ fn to_incorrect_module_name_report<'a>(
         doc,
         title: "INCORRECT MODULE NAME".to_string(),
         severity,
+        region: None,
     };
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
     buf
 }
@@ -6506,15 +6560,16 @@ fn to_no_platform_package_report(
     region: Region,
     src: &[u8],
     render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
     let severity = Severity::RuntimeError;
 
     // SAFETY: if the module was not UTF-8, that would be reported as a parsing problem, rather
     // than an incorrect module name problem (the latter can happen only after parsing).
     let src = unsafe { from_utf8_unchecked(src) };
-    let src_lines = src.lines().collect::<Vec<_>>();
+    let src_lines = src.split('\n').collect::<Vec<_>>();
     let lines = LineInfo::new(src);
 
     let interns = Interns {
@@ -6538,10 +6593,10 @@ fn to_no_platform_package_report(
         doc,
         title: "UNSPECIFIED PLATFORM".to_string(),
         severity,
+        region: None,
     };
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
     buf
 }
@@ -6554,15 +6609,16 @@ fn to_multiple_platform_packages_report(
     region: Region,
     src: &[u8],
     render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
     let severity = Severity::RuntimeError;
 
     // SAFETY: if the module was not UTF-8, that would be reported as a parsing problem, rather
     // than an incorrect module name problem (the latter can happen only after parsing).
     let src = unsafe { from_utf8_unchecked(src) };
-    let src_lines = src.lines().collect::<Vec<_>>();
+    let src_lines = src.split('\n').collect::<Vec<_>>();
     let lines = LineInfo::new(src);
 
     let interns = Interns {
@@ -6582,10 +6638,10 @@ fn to_multiple_platform_packages_report(
         doc,
         title: "MULTIPLE PLATFORMS".to_string(),
         severity,
+        region: None,
     };
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
     buf
 }
@@ -6600,11 +6656,12 @@ fn to_unrecognized_package_shorthand_report(
     shorthand: &str,
     available: AvailableShorthands,
     render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
 
-    let src_lines = src.lines().collect::<Vec<_>>();
+    let src_lines = src.split('\n').collect::<Vec<_>>();
     let lines = LineInfo::new(src);
 
     let interns = Interns {
@@ -6689,10 +6746,10 @@ fn to_unrecognized_package_shorthand_report(
         doc,
         title: "UNRECOGNIZED PACKAGE".to_string(),
         severity,
+        region: None,
     };
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
     buf
 }
@@ -6708,7 +6765,7 @@ fn to_parse_problem_report<'a>(
 
     // TODO this is not in fact safe
     let src = unsafe { from_utf8_unchecked(problem.problem.bytes) };
-    let src_lines = src.lines().collect::<Vec<_>>();
+    let src_lines = src.split('\n').collect::<Vec<_>>();
     // let mut src_lines: Vec<&str> = problem.prefix.lines().collect();
     // src_lines.extend(src.lines().skip(1));
 
@@ -6745,8 +6802,10 @@ fn report_cannot_run(
     module_id: ModuleId,
     filename: PathBuf,
     platform_path: &PlatformPath,
+    render: RenderTarget,
+    palette: Palette,
 ) -> String {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
     use PlatformPath::*;
 
@@ -6772,6 +6831,7 @@ fn report_cannot_run(
                     doc,
                     title: "NO PLATFORM".to_string(),
                     severity: Severity::RuntimeError,
+                    region: None,
                 }
             }
             RootIsModule => {
@@ -6787,6 +6847,7 @@ fn report_cannot_run(
                     doc,
                     title: "NO PLATFORM".to_string(),
                     severity: Severity::RuntimeError,
+                    region: None,
                 }
             }
             RootIsHosted => {
@@ -6802,6 +6863,7 @@ fn report_cannot_run(
                     doc,
                     title: "NO PLATFORM".to_string(),
                     severity: Severity::RuntimeError,
+                    region: None,
                 }
             }
             RootIsPlatformModule => {
@@ -6817,14 +6879,14 @@ fn report_cannot_run(
                     doc,
                     title: "NO PLATFORM".to_string(),
                     severity: Severity::RuntimeError,
+                    region: None,
                 }
             }
         }
     };
 
-    let palette = DEFAULT_PALETTE;
     let mut buf = String::new();
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(render, &mut buf, &alloc, &palette);
 
     buf
 }
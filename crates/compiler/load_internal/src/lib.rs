@@ -26,4 +26,7 @@ pub const BUILTIN_MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::HASH, "Hash"),
     (ModuleId::INSPECT, "Inspect"),
     (ModuleId::TASK, "Task"),
+    (ModuleId::SORT, "Sort"),
+    (ModuleId::DEQUE, "Deque"),
+    (ModuleId::SORTED_DICT, "SortedDict"),
 ];
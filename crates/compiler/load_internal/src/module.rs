@@ -35,6 +35,8 @@ pub struct LoadedModule {
     pub solved: Solved<Subs>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
+    /// Reports that are not problems, like the inferred types of `_` holes.
+    pub type_hints: MutMap<ModuleId, Vec<TypeError>>,
     pub declarations_by_id: MutMap<ModuleId, Declarations>,
     pub exposed_to_host: MutMap<Symbol, Variable>,
     pub dep_idents: IdentIdsByModule,
@@ -177,6 +179,7 @@ pub struct MonomorphizedModule<'a> {
     pub layout_interner: STLayoutInterner<'a>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
+    pub type_hints: MutMap<ModuleId, Vec<TypeError>>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     pub host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
     pub toplevel_expects: MutMap<ModuleId, ToplevelExpects>,
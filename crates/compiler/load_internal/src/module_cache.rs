@@ -43,6 +43,7 @@ pub(crate) struct ModuleCache<'a> {
     pub(crate) documentation: VecMap<ModuleId, ModuleDocumentation>,
     pub(crate) can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub(crate) type_problems: MutMap<ModuleId, Vec<TypeError>>,
+    pub(crate) type_hints: MutMap<ModuleId, Vec<TypeError>>,
 
     pub(crate) sources: MutMap<ModuleId, (PathBuf, &'a str)>,
 }
@@ -94,6 +95,9 @@ impl Default for ModuleCache<'_> {
             HASH,
             INSPECT,
             TASK,
+            SORT,
+            DEQUE,
+            SORTED_DICT,
         }
 
         Self {
@@ -116,6 +120,7 @@ impl Default for ModuleCache<'_> {
             documentation: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
+            type_hints: Default::default(),
             sources: Default::default(),
         }
     }
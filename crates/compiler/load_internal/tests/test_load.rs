@@ -530,6 +530,25 @@ fn load_docs() {
     // let has_all_docs = expected.map(|a| docs.contains(&a)).all(|a| a);
     // assert!(has_all_docs, "Some of the expected docs were not created")
     assert_eq!(expected, all_docs);
+
+    // Editor hover looks docs up by symbol rather than by walking the entries
+    let get_name_symbol = module_docs
+        .entries
+        .iter()
+        .find_map(|entry| match entry {
+            roc_load_internal::docs::DocEntry::DocDef(DocDef { name, symbol, .. })
+                if name == "getName" =>
+            {
+                Some(*symbol)
+            }
+            _ => None,
+        })
+        .expect("getName should have a doc entry");
+
+    assert_eq!(
+        module_docs.get_doc_for_symbol(&get_name_symbol).as_deref(),
+        Some("Gets the user's name\n")
+    );
 }
 
 #[test]
@@ -958,6 +977,153 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
     );
 }
 
+#[test]
+fn opaque_wrapped_unwrapped_from_imported_module() {
+    let modules = vec![
+        (
+            "Age.roc",
+            indoc!(
+                r"
+                    module [Age, twenty]
+
+                    Age := U32
+
+                    twenty = @Age 20
+                    "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r"
+                    module [twenty, readAge]
+
+                    import Age
+
+                    twenty = Age.twenty
+
+                    readAge = \@Age n -> n
+                    "
+            ),
+        ),
+    ];
+
+    let err =
+        multiple_modules("opaque_wrapped_unwrapped_from_imported_module", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r"
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...pped_from_imported_module/Main.roc ─
+
+                The unwrapped opaque type Age referenced here:
+
+                7│  readAge = \@Age n -> n
+                               ^^^^
+
+                is imported from another module:
+
+                3│  import Age
+                    ^^^^^^^^^^
+
+                Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
+                "
+        ),
+        "\n{}",
+        err
+    );
+}
+
+#[test]
+fn opaque_used_abstractly_outside_defining_module() {
+    let modules = vec![
+        (
+            "Age.roc",
+            indoc!(
+                r"
+                    module [Age, fromYears, toYears]
+
+                    Age := U32
+
+                    fromYears : U32 -> Age
+                    fromYears = \years -> @Age years
+
+                    toYears : Age -> U32
+                    toYears = \@Age years -> years
+                    "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r"
+                    module [nextYear]
+
+                    import Age exposing [Age]
+
+                    nextYear : Age -> Age
+                    nextYear = \age -> Age.fromYears (Age.toYears age + 1)
+                    "
+            ),
+        ),
+    ];
+
+    assert!(multiple_modules("opaque_used_abstractly_outside_defining_module", modules).is_ok());
+}
+
+#[test]
+fn opaque_wrapped_with_imported_structural_alias() {
+    let modules = vec![
+        (
+            "Age.roc",
+            indoc!(
+                r"
+                    module [Age, twenty]
+
+                    Age : U32
+
+                    twenty : Age
+                    twenty = 20
+                    "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r"
+                    module [twenty, readAge]
+
+                    import Age
+
+                    twenty = Age.twenty
+
+                    readAge = \@Age n -> n
+                    "
+            ),
+        ),
+    ];
+
+    let err =
+        multiple_modules("opaque_wrapped_with_imported_structural_alias", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r"
+                ── OPAQUE TYPE NOT DEFINED in ...apped_with_imported_structural_alias/Main.roc ─
+
+                The opaque type Age referenced here is not defined:
+
+                7│  readAge = \@Age n -> n
+                               ^^^^
+
+                Note: It looks like there are no opaque types declared in this scope yet!
+                "
+        ),
+        "\n{}",
+        err
+    );
+}
+
 #[test]
 fn unused_imports() {
     let modules = vec![
@@ -1323,6 +1489,31 @@ fn ingested_file_import_shadows_symbol() {
     );
 }
 
+#[test]
+fn ingested_file_unsupported_type() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r#"
+                module [main]
+
+                import "Main.roc" as source : U64
+
+                main = source
+                "#
+        ),
+    )];
+    let err = multiple_modules("ingested_file_unsupported_type", modules).unwrap_err();
+
+    // The report names the absolute path of the ingested file, so only check the parts
+    // that don't depend on where the test runs.
+    assert!(err.contains("INVALID TYPE FOR INGESTED FILE"), "{err}");
+    assert!(
+        err.contains("Ingested files can only be of type `List U8` or `Str`."),
+        "{err}"
+    );
+}
+
 #[test]
 fn import_with_alias() {
     let modules = vec![
@@ -1353,6 +1544,38 @@ fn import_with_alias() {
     assert!(loaded_module.is_ok(), "should check");
 }
 
+#[test]
+fn import_with_alias_and_exposing() {
+    let modules = vec![
+        (
+            "Dep.roc",
+            indoc!(
+                r#"
+                interface Dep exposes [hello, world] imports []
+
+                hello = "Hello"
+
+                world = "World"
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main exposes [main] imports []
+
+                import Dep as D exposing [hello]
+
+                main = Str.concat hello D.world
+                "#
+            ),
+        ),
+    ];
+    let loaded_module = multiple_modules("import_with_alias_and_exposing", modules);
+    assert!(loaded_module.is_ok(), "should check");
+}
+
 #[test]
 fn duplicate_alias() {
     let modules = vec![
@@ -1934,9 +2157,9 @@ fn issue_2863_module_type_does_not_exist() {
                         Did you mean one of these?
 
                             Decoding
-                            Result
                             Dict
-                            DecodeError
+                            Result
+                            SortedDict
                         "
                       )
                 )
@@ -104,7 +104,8 @@ pub fn remove_module_param_arguments(
             | TypeError::FxInTopLevel(_, _)
             | TypeError::ExpectedEffectful(_, _)
             | TypeError::UnsuffixedEffectfulFunction(_, _)
-            | TypeError::SuffixedPureFunction(_, _) => {}
+            | TypeError::SuffixedPureFunction(_, _)
+            | TypeError::TypeHole(_, _) => {}
         }
     }
 }
@@ -131,6 +131,9 @@ impl ModuleName {
     pub const HASH: &'static str = "Hash";
     pub const INSPECT: &'static str = "Inspect";
     pub const TASK: &'static str = "Task";
+    pub const SORT: &'static str = "Sort";
+    pub const DEQUE: &'static str = "Deque";
+    pub const SORTED_DICT: &'static str = "SortedDict";
 
     pub fn as_str(&self) -> &str {
         self.0.as_str()
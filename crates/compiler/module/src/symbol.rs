@@ -56,6 +56,7 @@ pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
         Symbol::INSPECT_INSPECT_ABILITY,
         &[Symbol::INSPECT_TO_INSPECTOR],
     ),
+    (Symbol::SORT_SORT_ABILITY, &[Symbol::SORT_COMPARE]),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -1417,6 +1418,7 @@ define_builtins! {
         48 STR_RELEASE_EXCESS_CAPACITY: "releaseExcessCapacity"
         49 STR_DROP_PREFIX: "dropPrefix"
         50 STR_DROP_SUFFIX: "dropSuffix"
+        51 STR_COMPARE: "compare"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1514,6 +1516,7 @@ define_builtins! {
         92 LIST_WALK_FX: "walk!"
         93 LIST_SPLIT_ON: "splitOn"
         94 LIST_SPLIT_ON_LIST: "splitOnList"
+        95 LIST_SORT: "sort"
     }
     7 RESULT: "Result" => {
         0 RESULT_RESULT: "Result" exposed_type=true // the Result.Result type alias
@@ -1733,6 +1736,51 @@ define_builtins! {
         13 TASK_FOR_EACH: "forEach"
         14 TASK_RESULT: "result"
     }
-
-    num_modules: 16 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    16 SORT: "Sort" => {
+        0 SORT_SORT_ABILITY: "Sort" exposed_type=true
+        1 SORT_COMPARE: "compare"
+        2 SORT_COMPARE_BOOL: "compareBool"
+    }
+
+    17 DEQUE: "Deque" => {
+        0 DEQUE_DEQUE: "Deque" exposed_type=true // the Deque.Deque opaque type
+        1 DEQUE_EMPTY: "empty"
+        2 DEQUE_WITH_CAPACITY: "withCapacity"
+        3 DEQUE_SINGLE: "single"
+        4 DEQUE_LEN: "len"
+        5 DEQUE_IS_EMPTY: "isEmpty"
+        6 DEQUE_PUSH_FRONT: "pushFront"
+        7 DEQUE_PUSH_BACK: "pushBack"
+        8 DEQUE_POP_FRONT: "popFront"
+        9 DEQUE_POP_BACK: "popBack"
+        10 DEQUE_FIRST: "first"
+        11 DEQUE_LAST: "last"
+        12 DEQUE_WALK: "walk"
+        13 DEQUE_MAP: "map"
+        14 DEQUE_TO_LIST: "toList"
+        15 DEQUE_FROM_LIST: "fromList"
+    }
+
+    18 SORTED_DICT: "SortedDict" => {
+        0 SORTED_DICT_SORTED_DICT: "SortedDict" exposed_type=true // the SortedDict.SortedDict opaque type
+        1 SORTED_DICT_EMPTY: "empty"
+        2 SORTED_DICT_SINGLE: "single"
+        3 SORTED_DICT_LEN: "len"
+        4 SORTED_DICT_IS_EMPTY: "isEmpty"
+        5 SORTED_DICT_GET: "get"
+        6 SORTED_DICT_CONTAINS: "contains"
+        7 SORTED_DICT_INSERT: "insert"
+        8 SORTED_DICT_REMOVE: "remove"
+        9 SORTED_DICT_UPDATE: "update"
+        10 SORTED_DICT_MIN: "min"
+        11 SORTED_DICT_MAX: "max"
+        12 SORTED_DICT_WALK: "walk"
+        13 SORTED_DICT_WALK_RANGE: "walkRange"
+        14 SORTED_DICT_KEYS: "keys"
+        15 SORTED_DICT_VALUES: "values"
+        16 SORTED_DICT_TO_LIST: "toList"
+        17 SORTED_DICT_FROM_LIST: "fromList"
+    }
+
+    num_modules: 19 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
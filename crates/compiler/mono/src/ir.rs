@@ -2905,6 +2905,7 @@ fn pattern_to_when(
         Pattern::List { .. } => todo!(),
 
         IntLiteral(..)
+        | IntRange(..)
         | NumLiteral(..)
         | FloatLiteral(..)
         | StrLiteral(..)
@@ -10061,6 +10062,7 @@ fn find_lambda_sets_help(
                     }
                 }
                 FlatType::EmptyRecord => {}
+                FlatType::EmptyTuple => {}
                 FlatType::EmptyTagUnion => {}
                 FlatType::EffectfulFunc => {}
             },
@@ -108,6 +108,7 @@ enum Test<'a> {
         arguments: Vec<(Pattern<'a>, InLayout<'a>)>,
     },
     IsInt([u8; 16], IntWidth),
+    IsIntRange([u8; 16], [u8; 16], IntWidth),
     // stores the f64 bits; u64 so that this type can impl Hash
     IsFloat(u64, FloatWidth),
     IsDecimal([u8; 16]),
@@ -131,6 +132,7 @@ impl<'a> Test<'a> {
                 // llvm does not like switching on 128-bit values
                 !matches!(int_width, IntWidth::U128 | IntWidth::I128)
             }
+            Test::IsIntRange(..) => false,
             Test::IsFloat(_, _) => false,
             Test::IsDecimal(_) => false,
             Test::IsStr(_) => false,
@@ -186,6 +188,12 @@ impl<'a> Hash for Test<'a> {
                 state.write_u8(7);
                 (len, bound).hash(state);
             }
+            IsIntRange(start, end, width) => {
+                state.write_u8(8);
+                start.hash(state);
+                end.hash(state);
+                width.hash(state);
+            }
         }
     }
 }
@@ -365,6 +373,7 @@ fn tests_are_complete_help(last_test: &Test, number_of_tests: usize) -> bool {
         Test::IsByte { num_alts, .. } => number_of_tests == *num_alts,
         Test::IsBit(_) => number_of_tests == 2,
         Test::IsInt(_, _) => false,
+        Test::IsIntRange(..) => false,
         Test::IsFloat(_, _) => false,
         Test::IsDecimal(_) => false,
         Test::IsStr(_) => false,
@@ -480,9 +489,42 @@ fn gather_edges<'a>(
 
     let check = guarded_tests_are_complete(&relevant_tests);
 
+    // The edges are tested in order, so an edge is only taken when the integer tests before it
+    // have failed. Ranges can overlap those tests, so rule out what the failed tests covered.
+    let has_int_ranges = relevant_tests.iter().any(|t| {
+        matches!(
+            t,
+            GuardedTest::TestNotGuarded {
+                test: Test::IsIntRange(..)
+            }
+        )
+    });
+    let mut failed_int_ranges = Vec::new();
+
     let all_edges = relevant_tests
         .into_iter()
-        .map(|t| edges_for(interner, path, &branches, t))
+        .filter_map(|t| {
+            let test_range = match &t {
+                GuardedTest::TestNotGuarded { test } if has_int_ranges => int_test_range(test),
+                _ => None,
+            };
+
+            if failed_int_ranges.is_empty() {
+                failed_int_ranges.extend(test_range);
+
+                return Some(edges_for(interner, path, &branches, t));
+            }
+
+            if let Some(range) = test_range {
+                // a test that can only fail is dead code
+                exclude_failed_int_ranges(range, &failed_int_ranges)?;
+            }
+
+            let remaining = exclude_failed_int_patterns(path, &branches, &failed_int_ranges);
+            failed_int_ranges.extend(test_range);
+
+            Some(edges_for(interner, path, &remaining, t))
+        })
         .collect();
 
     let fallbacks = if check {
@@ -672,6 +714,7 @@ fn test_for_pattern<'a>(pattern: &Pattern<'a>) -> Option<Test<'a>> {
             num_alts: union.alternatives.len(),
         },
         IntLiteral(v, precision) => IsInt(*v, *precision),
+        IntRange(start, end, precision) => IsIntRange(*start, *end, *precision),
         FloatLiteral(v, precision) => IsFloat(*v, *precision),
         DecimalLiteral(v) => IsDecimal(*v),
         StrLiteral(v) => IsStr(v.clone()),
@@ -1099,9 +1142,21 @@ fn to_relevant_branch_help<'a>(
                     patterns: start,
                 })
             }
+            IsIntRange(..) => {
+                let int = i128::from_ne_bytes(int);
+                to_relevant_int_range_branch((int, int), test, start, end, branch)
+            }
             _ => None,
         },
 
+        IntRange(range_start, range_end, _) => {
+            let range = (
+                i128::from_ne_bytes(range_start),
+                i128::from_ne_bytes(range_end),
+            );
+            to_relevant_int_range_branch(range, test, start, end, branch)
+        }
+
         FloatLiteral(float, p1) => match test {
             IsFloat(test_float, p2) if float == *test_float => {
                 debug_assert_eq!(p1, *p2);
@@ -1201,6 +1256,128 @@ fn is_irrelevant_to(selected_path: &[PathInstruction], branch: &Branch) -> bool
     }
 }
 
+/// Integer tests can overlap once ranges are involved, unlike all other literal tests. A branch
+/// whose integers are only partly covered by the test stays relevant, but must be tested again.
+fn to_relevant_int_range_branch<'a>(
+    (pattern_start, pattern_end): (i128, i128),
+    test: &Test<'a>,
+    mut start: Vec<(Vec<PathInstruction>, Pattern<'a>)>,
+    end: Vec<(Vec<PathInstruction>, Pattern<'a>)>,
+    branch: &Branch<'a>,
+) -> Option<Branch<'a>> {
+    let (test_start, test_end) = match test {
+        Test::IsInt(int, _) => (i128::from_ne_bytes(*int), i128::from_ne_bytes(*int)),
+        Test::IsIntRange(test_start, test_end, _) => (
+            i128::from_ne_bytes(*test_start),
+            i128::from_ne_bytes(*test_end),
+        ),
+        _ => return None,
+    };
+
+    if pattern_start <= test_start && test_end <= pattern_end {
+        // every integer that passes the test matches this pattern
+        start.extend(end);
+        Some(Branch {
+            goal: branch.goal,
+            guard: branch.guard.clone(),
+            patterns: start,
+        })
+    } else if test_start <= pattern_end && pattern_start <= test_end {
+        // only some integers that pass the test match this pattern; keep it around
+        Some(branch.clone())
+    } else {
+        None
+    }
+}
+
+/// The inclusive range of integers that pass this test, if it is an integer test.
+fn int_test_range(test: &Test) -> Option<(i128, i128)> {
+    match test {
+        Test::IsInt(int, _) => Some((i128::from_ne_bytes(*int), i128::from_ne_bytes(*int))),
+        Test::IsIntRange(start, end, _) => {
+            Some((i128::from_ne_bytes(*start), i128::from_ne_bytes(*end)))
+        }
+        _ => None,
+    }
+}
+
+/// Shrinks an inclusive range of integers by the ranges of integer tests that already failed.
+/// Returns `None` if those tests rule out the whole range.
+fn exclude_failed_int_ranges(
+    (mut start, mut end): (i128, i128),
+    failed: &[(i128, i128)],
+) -> Option<(i128, i128)> {
+    // A failed test can only shrink the range when it covers one of the range's ends. Every
+    // change shrinks the range, so this terminates.
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for &(failed_start, failed_end) in failed {
+            if start > end {
+                return None;
+            }
+
+            if failed_start <= start && start <= failed_end {
+                start = failed_end.checked_add(1)?;
+                changed = true;
+            } else if failed_start <= end && end <= failed_end {
+                end = failed_start.checked_sub(1)?;
+                changed = true;
+            }
+        }
+    }
+
+    (start <= end).then_some((start, end))
+}
+
+/// Drops the branches whose integer pattern at this path can no longer match after the given
+/// integer tests failed, and narrows the ones that still partly can.
+fn exclude_failed_int_patterns<'a>(
+    path: &[PathInstruction],
+    branches: &[Branch<'a>],
+    failed: &[(i128, i128)],
+) -> Vec<Branch<'a>> {
+    branches
+        .iter()
+        .filter_map(|branch| {
+            let mut branch = branch.clone();
+
+            let pattern = match branch
+                .patterns
+                .iter_mut()
+                .find(|(branch_path, _)| branch_path == path)
+            {
+                Some((_, pattern)) => pattern,
+                None => return Some(branch),
+            };
+
+            let (range, width) = match pattern {
+                Pattern::IntLiteral(int, width) => {
+                    let int = i128::from_ne_bytes(*int);
+                    ((int, int), *width)
+                }
+                Pattern::IntRange(start, end, width) => (
+                    (i128::from_ne_bytes(*start), i128::from_ne_bytes(*end)),
+                    *width,
+                ),
+                _ => return Some(branch),
+            };
+
+            let (start, end) = exclude_failed_int_ranges(range, failed)?;
+
+            *pattern = if start == end {
+                Pattern::IntLiteral(start.to_ne_bytes(), width)
+            } else {
+                Pattern::IntRange(start.to_ne_bytes(), end.to_ne_bytes(), width)
+            };
+
+            Some(branch)
+        })
+        .collect()
+}
+
 /// Does this pattern need a branch test?
 ///
 /// Keep up to date with [needs_path_instruction].
@@ -1220,6 +1397,7 @@ fn needs_tests(pattern: &Pattern) -> bool {
         | BitLiteral { .. }
         | EnumLiteral { .. }
         | IntLiteral(_, _)
+        | IntRange(_, _, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
         | StrLiteral(_)
@@ -1694,7 +1872,7 @@ fn test_to_comparison<'a>(
 
                     (
                         stores,
-                        (lhs_symbol, Comparator::Eq, rhs_symbol),
+                        Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
                         Some(ConstructorKnown::OneTag {
                             scrutinee: path_symbol,
                             layout: *cond_layout,
@@ -1711,7 +1889,41 @@ fn test_to_comparison<'a>(
             let lhs_symbol = env.unique_symbol();
             stores.push((lhs_symbol, Layout::int_width(precision), lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
+        }
+
+        Test::IsIntRange(test_start, test_end, precision) => {
+            let int_layout = Layout::int_width(precision);
+
+            let start_symbol = env.unique_symbol();
+            let end_symbol = env.unique_symbol();
+            stores.push((
+                start_symbol,
+                int_layout,
+                Expr::Literal(Literal::Int(test_start)),
+            ));
+            stores.push((
+                end_symbol,
+                int_layout,
+                Expr::Literal(Literal::Int(test_end)),
+            ));
+
+            let above_start = store_bool_lowlevel(
+                env,
+                &mut stores,
+                LowLevel::NumGte,
+                [rhs_symbol, start_symbol],
+            );
+            let below_end =
+                store_bool_lowlevel(env, &mut stores, LowLevel::NumLte, [rhs_symbol, end_symbol]);
+            let in_range =
+                store_bool_lowlevel(env, &mut stores, LowLevel::And, [above_start, below_end]);
+
+            (stores, Comparison::IsTrue(in_range), None)
         }
 
         Test::IsFloat(test_int, precision) => {
@@ -1721,7 +1933,11 @@ fn test_to_comparison<'a>(
             let lhs_symbol = env.unique_symbol();
             stores.push((lhs_symbol, Layout::float_width(precision), lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
         }
 
         Test::IsDecimal(test_dec) => {
@@ -1729,7 +1945,11 @@ fn test_to_comparison<'a>(
             let lhs_symbol = env.unique_symbol();
             stores.push((lhs_symbol, *cond_layout, lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
         }
 
         Test::IsByte {
@@ -1741,7 +1961,11 @@ fn test_to_comparison<'a>(
             let lhs_symbol = env.unique_symbol();
             stores.push((lhs_symbol, Layout::U8, lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
         }
 
         Test::IsBit(test_bit) => {
@@ -1749,7 +1973,11 @@ fn test_to_comparison<'a>(
             let lhs_symbol = env.unique_symbol();
             stores.push((lhs_symbol, Layout::BOOL, lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
         }
 
         Test::IsStr(test_str) => {
@@ -1758,7 +1986,11 @@ fn test_to_comparison<'a>(
 
             stores.push((lhs_symbol, Layout::STR, lhs));
 
-            (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+            (
+                stores,
+                Comparison::Compare(lhs_symbol, Comparator::Eq, rhs_symbol),
+                None,
+            )
         }
 
         Test::IsListLen { bound, len } => {
@@ -1785,8 +2017,12 @@ fn test_to_comparison<'a>(
                     stores.push((test_len, usize_layout, test_len_expr));
 
                     let comparison = match bound {
-                        ListLenBound::Exact => (real_len, Comparator::Eq, test_len),
-                        ListLenBound::AtLeast => (real_len, Comparator::Geq, test_len),
+                        ListLenBound::Exact => {
+                            Comparison::Compare(real_len, Comparator::Eq, test_len)
+                        }
+                        ListLenBound::AtLeast => {
+                            Comparison::Compare(real_len, Comparator::Geq, test_len)
+                        }
                     };
 
                     (stores, comparison, None)
@@ -1800,13 +2036,37 @@ fn test_to_comparison<'a>(
     }
 }
 
+fn store_bool_lowlevel<'a>(
+    env: &mut Env<'a, '_>,
+    stores: &mut StoresVec<'a>,
+    op: LowLevel,
+    arguments: [Symbol; 2],
+) -> Symbol {
+    let symbol = env.unique_symbol();
+    let expr = Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: env.arena.alloc(arguments),
+    });
+    stores.push((symbol, Layout::BOOL, expr));
+
+    symbol
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Comparator {
     Eq,
     Geq,
 }
 
-type Comparison = (Symbol, Comparator, Symbol);
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Compare(Symbol, Comparator, Symbol),
+    /// A bool the test's stores already computed, like whether an int is in a range
+    IsTrue(Symbol),
+}
 
 type Tests<'a> = std::vec::Vec<(
     bumpalo::collections::Vec<'a, (Symbol, InLayout<'a>, Expr<'a>)>,
@@ -1843,9 +2103,7 @@ fn compile_test<'a>(
     env: &mut Env<'a, '_>,
     ret_layout: InLayout<'a>,
     stores: bumpalo::collections::Vec<'a, (Symbol, InLayout<'a>, Expr<'a>)>,
-    lhs: Symbol,
-    cmp: Comparator,
-    rhs: Symbol,
+    comparison: Comparison,
     fail: &'a Stmt<'a>,
     cond: Stmt<'a>,
 ) -> Stmt<'a> {
@@ -1854,9 +2112,7 @@ fn compile_test<'a>(
         ConstructorKnown::None,
         ret_layout,
         stores,
-        lhs,
-        cmp,
-        rhs,
+        comparison,
         fail,
         cond,
     )
@@ -1868,14 +2124,15 @@ fn compile_test_help<'a>(
     branch_info: ConstructorKnown<'a>,
     ret_layout: InLayout<'a>,
     stores: bumpalo::collections::Vec<'a, (Symbol, InLayout<'a>, Expr<'a>)>,
-    lhs: Symbol,
-    cmp: Comparator,
-    rhs: Symbol,
+    comparison: Comparison,
     fail: &'a Stmt<'a>,
     mut cond: Stmt<'a>,
 ) -> Stmt<'a> {
     // if test_symbol then cond else fail
-    let test_symbol = env.unique_symbol();
+    let test_symbol = match comparison {
+        Comparison::Compare(..) => env.unique_symbol(),
+        Comparison::IsTrue(symbol) => symbol,
+    };
     let arena = env.arena;
 
     let (pass_info, fail_info) = {
@@ -1936,20 +2193,22 @@ fn compile_test_help<'a>(
         default_branch,
     };
 
-    let op = match cmp {
-        Comparator::Eq => LowLevel::Eq,
-        Comparator::Geq => LowLevel::NumGte,
-    };
-    let test = Expr::Call(crate::ir::Call {
-        call_type: crate::ir::CallType::LowLevel {
-            op,
-            update_mode: env.next_update_mode_id(),
-        },
-        arguments: arena.alloc([lhs, rhs]),
-    });
+    if let Comparison::Compare(lhs, cmp, rhs) = comparison {
+        let op = match cmp {
+            Comparator::Eq => LowLevel::Eq,
+            Comparator::Geq => LowLevel::NumGte,
+        };
+        let test = Expr::Call(crate::ir::Call {
+            call_type: crate::ir::CallType::LowLevel {
+                op,
+                update_mode: env.next_update_mode_id(),
+            },
+            arguments: arena.alloc([lhs, rhs]),
+        });
 
-    // write to the test symbol
-    cond = Stmt::Let(test_symbol, test, Layout::BOOL, arena.alloc(cond));
+        // write to the test symbol
+        cond = Stmt::Let(test_symbol, test, Layout::BOOL, arena.alloc(cond));
+    }
 
     // stores are in top-to-bottom order, so we have to add them in reverse
     for (symbol, layout, expr) in stores.into_iter().rev() {
@@ -1966,15 +2225,14 @@ fn compile_tests<'a>(
     fail: &'a Stmt<'a>,
     mut cond: Stmt<'a>,
 ) -> Stmt<'a> {
-    for (new_stores, (lhs, cmp, rhs), opt_constructor_info) in tests.into_iter() {
+    for (new_stores, comparison, opt_constructor_info) in tests.into_iter() {
         match opt_constructor_info {
             None => {
-                cond = compile_test(env, ret_layout, new_stores, lhs, cmp, rhs, fail, cond);
+                cond = compile_test(env, ret_layout, new_stores, comparison, fail, cond);
             }
             Some(cinfo) => {
-                cond = compile_test_help(
-                    env, cinfo, ret_layout, new_stores, lhs, cmp, rhs, fail, cond,
-                );
+                cond =
+                    compile_test_help(env, cinfo, ret_layout, new_stores, comparison, fail, cond);
             }
         }
     }
@@ -2177,16 +2435,14 @@ fn decide_to_branching<'a>(
             if number_of_tests == 1 {
                 // if there is just one test, compile to a simple if-then-else
 
-                let (new_stores, (lhs, cmp, rhs), _cinfo) = tests.into_iter().next().unwrap();
+                let (new_stores, comparison, _cinfo) = tests.into_iter().next().unwrap();
 
                 compile_test_help(
                     env,
                     chain_branch_info,
                     ret_layout,
                     new_stores,
-                    lhs,
-                    cmp,
-                    rhs,
+                    comparison,
                     fail,
                     pass_expr,
                 )
@@ -2252,6 +2508,7 @@ fn decide_to_branching<'a>(
 
                 let tag = match test {
                     Test::IsInt(v, _) => i128::from_ne_bytes(v) as u64,
+                    Test::IsIntRange(..) => unreachable!("int ranges cannot be switched on"),
                     Test::IsFloat(_, _) => unreachable!("floats cannot be switched on"),
                     Test::IsBit(v) => v as u64,
                     Test::IsByte { tag_id, .. } => tag_id as u64,
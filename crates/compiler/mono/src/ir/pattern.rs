@@ -25,6 +25,8 @@ pub enum Pattern<'a> {
     Underscore,
     As(Box<Pattern<'a>>, Symbol),
     IntLiteral([u8; 16], IntWidth),
+    /// An inclusive range of integers
+    IntRange([u8; 16], [u8; 16], IntWidth),
     FloatLiteral(u64, FloatWidth),
     DecimalLiteral([u8; 16]),
     BitLiteral {
@@ -79,6 +81,7 @@ impl<'a> Pattern<'a> {
                 Pattern::Identifier(_)
                 | Pattern::Underscore
                 | Pattern::IntLiteral(_, _)
+                | Pattern::IntRange(_, _, _)
                 | Pattern::FloatLiteral(_, _)
                 | Pattern::DecimalLiteral(_)
                 | Pattern::BitLiteral { .. }
@@ -203,6 +206,7 @@ impl<'r, 'a> Iterator for PatternBindingIter<'r, 'a> {
                         }
                     }
                     IntLiteral(_, _)
+                    | IntRange(_, _, _)
                     | FloatLiteral(_, _)
                     | DecimalLiteral(_)
                     | BitLiteral { .. }
@@ -255,6 +259,7 @@ impl<'r, 'a> Iterator for PatternBindingIter<'r, 'a> {
                                 }
                             }
                             IntLiteral(_, _)
+                            | IntRange(_, _, _)
                             | FloatLiteral(_, _)
                             | DecimalLiteral(_)
                             | BitLiteral { .. }
@@ -348,6 +353,17 @@ fn from_can_pattern_help<'a>(
             int_str,
             IntOrFloatValue::Int(*int),
         )),
+        IntRange(var, _, start, end, _bound) => {
+            let layout = layout_cache.from_var(env.arena, *var, env.subs);
+            match layout.map(|l| layout_cache.get_repr(l)) {
+                Ok(LayoutRepr::Builtin(Builtin::Int(width))) => Ok(Pattern::IntRange(
+                    start.to_ne_bytes(),
+                    end.to_ne_bytes(),
+                    width,
+                )),
+                o => internal_error!("an integer width was expected, but we found {:?}", o),
+            }
+        }
         FloatLiteral(var, _, float_str, float, _bound) => Ok(make_num_literal_pattern(
             env,
             layout_cache,
@@ -1205,6 +1221,7 @@ fn store_pattern_help<'a>(
             return StorePattern::Productive(stmt);
         }
         IntLiteral(_, _)
+        | IntRange(_, _, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
         | EnumLiteral { .. }
@@ -1502,6 +1519,7 @@ fn store_list_pattern<'a>(
             }
             Underscore
             | IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -1665,6 +1683,7 @@ fn store_tag_pattern<'a>(
                 // ignore
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -1740,6 +1759,7 @@ fn store_newtype_pattern<'a>(
                 // ignore
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -1815,6 +1835,7 @@ fn store_tuple_destruct<'a>(
             return StorePattern::NotProductive(stmt);
         }
         IntLiteral(_, _)
+        | IntRange(_, _, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
         | EnumLiteral { .. }
@@ -1881,6 +1902,7 @@ fn store_record_destruct<'a>(
                 return StorePattern::NotProductive(stmt);
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -499,6 +499,9 @@ pub enum Expr<'a> {
     /// The `try` keyword that performs early return on errors
     Try,
 
+    /// An inline type annotation, e.g. `(List.len list : U64)`
+    TypeAscription(&'a Loc<Expr<'a>>, &'a Loc<TypeAnnotation<'a>>),
+
     // Application
     /// To apply by name, do Apply(Var(...), ...)
     /// To apply a tag by name, do Apply(Tag(...), ...)
@@ -674,6 +677,7 @@ pub fn is_expr_suffixed(expr: &Expr) -> bool {
         Expr::DbgStmt(a, b) => is_expr_suffixed(&a.value) || is_expr_suffixed(&b.value),
         Expr::LowLevelDbg(_, a, b) => is_expr_suffixed(&a.value) || is_expr_suffixed(&b.value),
         Expr::Try => false,
+        Expr::TypeAscription(a, _) => is_expr_suffixed(&a.value),
         Expr::UnaryOp(a, _) => is_expr_suffixed(&a.value),
         Expr::When(cond, branches) => {
             is_expr_suffixed(&cond.value) || branches.iter().any(|x| is_when_branch_suffixed(x))
@@ -966,6 +970,7 @@ impl<'a, 'b> RecursiveValueDefIter<'a, 'b> {
                     expr_stack.push(&expr.value);
                 }
                 UnaryOp(expr, _) => expr_stack.push(&expr.value),
+                TypeAscription(expr, _) => expr_stack.push(&expr.value),
                 If {
                     if_thens,
                     final_else,
@@ -1719,6 +1724,9 @@ pub enum Pattern<'a> {
         is_negative: bool,
     },
     FloatLiteral(&'a str),
+    /// An inclusive range of integers, e.g. `1..9` or `-5..-1`
+    /// Can only occur in `when` branches
+    IntRange(&'a str, &'a str),
     StrLiteral(StrLiteral<'a>),
 
     /// Underscore pattern
@@ -1849,6 +1857,13 @@ impl<'a> Pattern<'a> {
                     false
                 }
             }
+            IntRange(start_x, end_x) => {
+                if let IntRange(start_y, end_y) = other {
+                    start_x == start_y && end_x == end_y
+                } else {
+                    false
+                }
+            }
             StrLiteral(x) => {
                 if let StrLiteral(y) = other {
                     x == y
@@ -2483,6 +2498,7 @@ impl<'a> Malformed for Expr<'a> {
             Apply(func, args, _) => func.is_malformed() || args.iter().any(|arg| arg.is_malformed()),
             BinOps(firsts, last) => firsts.iter().any(|(expr, _)| expr.is_malformed()) || last.is_malformed(),
             UnaryOp(expr, _) => expr.is_malformed(),
+            TypeAscription(expr, ann) => expr.is_malformed() || ann.is_malformed(),
             If { if_thens, final_else, ..} => if_thens.iter().any(|(cond, body)| cond.is_malformed() || body.is_malformed()) || final_else.is_malformed(),
             When(cond, branches) => cond.is_malformed() || branches.iter().any(|branch| branch.is_malformed()),
 
@@ -2586,7 +2602,8 @@ impl<'a> Malformed for Pattern<'a> {
             NonBase10Literal { .. } |
             Underscore(_) |
             SingleQuote(_) | // This is just a &str - not a bunch of segments
-            FloatLiteral(_) => false,
+            FloatLiteral(_) |
+            IntRange(_, _) => false,
 
             StrLiteral(lit) => lit.is_malformed(),
             Tuple(items) => items.iter().any(|item| item.is_malformed()),
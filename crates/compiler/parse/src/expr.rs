@@ -16,10 +16,10 @@ use crate::ident::{
 use crate::parser::{
     self, and, backtrackable, between, byte, byte_indent, collection_inner,
     collection_trailing_sep_e, either, increment_min_indent, indented_seq_skip_first, loc, map,
-    map_with_arena, optional, reset_min_indent, sep_by1, sep_by1_e, set_min_indent, skip_first,
-    skip_second, specialize_err, specialize_err_ref, then, two_bytes, zero_or_more, EClosure,
-    EExpect, EExpr, EIf, EImport, EImportParams, EInParens, EList, ENumber, EPattern, ERecord,
-    EReturn, EString, EType, EWhen, Either, ParseResult, Parser, SpaceProblem,
+    map_with_arena, optional, reset_min_indent, sep_by1, sep_by1_e, skip_first, skip_second,
+    specialize_err, specialize_err_ref, then, two_bytes, zero_or_more, EAbility, EClosure, EExpect,
+    EExpr, EIf, EImport, EImportParams, EInParens, EList, ENumber, EPattern, ERecord, EReturn,
+    EString, EType, EWhen, Either, ParseResult, Parser, SpaceProblem,
 };
 use crate::pattern::closure_param;
 use crate::state::State;
@@ -619,6 +619,32 @@ fn parse_stmt_operator_chain<'a>(
                 return parse_ability_def(expr_state, state, arena, implements, call_min_indent)
                     .map(|(td, s)| (MadeProgress, Stmt::TypeDef(td), s));
             }
+            Ok((
+                _,
+                Loc {
+                    value:
+                        Expr::Var {
+                            module_name: "",
+                            ident: crate::keyword::HAS,
+                            ..
+                        },
+                    region,
+                },
+                state,
+            )) if matches!(expr_state.expr.value, Expr::Tag(..))
+                && expr_state.arguments.is_empty()
+                && ability::is_demand_on_next_line(arena, state.clone(), call_min_indent) =>
+            {
+                // This looks like an ability definition written with the old `has` keyword,
+                // e.g. `Eq has` followed by an indented `isEq : ...` demand.
+                return Err((
+                    MadeProgress,
+                    EExpr::Ability(
+                        EAbility::HasInsteadOfImplements(region.start()),
+                        expr_state.expr.region.start(),
+                    ),
+                ));
+            }
             Err((NoProgress, _)) => {
                 // try an operator
                 return parse_stmt_after_apply(
@@ -1115,12 +1141,49 @@ fn parse_stmt_alias_or_opaque<'a>(
     arena: &'a Bump,
     state: State<'a>,
     min_indent: u32,
-    expr_state: ExprState<'a>,
+    mut expr_state: ExprState<'a>,
     kind: Loc<AliasOrOpaque>,
     spaces_after_operator: &'a [CommentOrNewline<'a>],
 ) -> ParseResult<'a, Stmt<'a>, EExpr<'a>> {
     let expr_region = expr_state.expr.region;
     let indented_more = min_indent + 1;
+    let op_pos = state.pos();
+
+    // Aliases, value annotations and inline type annotations like `(List.len list : U64)`
+    // all have a type after the `:`. Only the last of those is followed by a `)`.
+    let (alias_ann, state) = match kind.value {
+        AliasOrOpaque::Alias => {
+            let is_alias = expr_state.operators.is_empty()
+                && extract_tag_and_spaces(arena, expr_state.expr.value).is_some();
+
+            let (ann_type, state) = match alias_signature().parse(arena, state, min_indent) {
+                Ok((_, ann_type, state)) => (ann_type, state),
+                Err((progress, fail)) if is_alias => return Err((progress, fail)),
+                Err((_, fail)) => return Err((MadeProgress, fail)),
+            };
+
+            if at_parens_end(arena, &state) {
+                expr_state.consume_spaces(arena);
+
+                let start = match expr_state.operators.first() {
+                    Some((first, _)) => first.region,
+                    None => expr_state.expr.region,
+                };
+                let end = match expr_state.arguments.last() {
+                    Some(last) => last.region,
+                    None => expr_state.expr.region,
+                };
+                let region = Region::span_across(&start, &end);
+                let expr = arena.alloc(Loc::at(region, parse_expr_final(expr_state, arena)));
+                let expr = Expr::TypeAscription(expr, arena.alloc(ann_type));
+
+                return Ok((MadeProgress, Stmt::Expr(expr), state));
+            }
+
+            (Some(ann_type), state)
+        }
+        AliasOrOpaque::Opaque => (None, state),
+    };
 
     let (expr, arguments) = expr_state
         .validate_is_type_def(arena, kind)
@@ -1138,19 +1201,14 @@ fn parse_stmt_alias_or_opaque<'a>(
                 Err(()) => {
                     return Err((
                         MadeProgress,
-                        EExpr::Pattern(
-                            arena.alloc(EPattern::NotAPattern(state.pos())),
-                            state.pos(),
-                        ),
+                        EExpr::Pattern(arena.alloc(EPattern::NotAPattern(op_pos)), op_pos),
                     ));
                 }
             }
         }
 
-        match kind.value {
-            AliasOrOpaque::Alias => {
-                let (_, signature, state) = alias_signature().parse(arena, state, min_indent)?;
-
+        match alias_ann {
+            Some(signature) => {
                 // TODO: this code used to be broken and it dropped the spaces after the operator.
                 // The formatter is not expecting this, so let's keep it as is for now.
                 // let signature = signature.map(|v| v.maybe_before(arena, spaces_after_operator));
@@ -1168,7 +1226,7 @@ fn parse_stmt_alias_or_opaque<'a>(
                 (Stmt::TypeDef(def), state)
             }
 
-            AliasOrOpaque::Opaque => {
+            None => {
                 let (_, (signature, derived), state) =
                     opaque_signature().parse(arena, state, indented_more)?;
 
@@ -1193,33 +1251,20 @@ fn parse_stmt_alias_or_opaque<'a>(
     } else {
         let call = to_call(arena, arguments, expr);
 
-        match expr_to_pattern_help(arena, &call.value) {
-            Ok(good) => {
-                let parser = specialize_err(
-                    EExpr::Type,
-                    space0_before_e(
-                        set_min_indent(indented_more, type_annotation::located(false)),
-                        EType::TIndentStart,
-                    ),
-                );
-
-                match parser.parse(arena, state.clone(), min_indent) {
-                    Err((_, fail)) => return Err((MadeProgress, fail)),
-                    Ok((_, mut ann_type, state)) => {
-                        // put the spaces from after the operator in front of the call
-                        if !spaces_after_operator.is_empty() {
-                            ann_type = arena
-                                .alloc(ann_type.value)
-                                .with_spaces_before(spaces_after_operator, ann_type.region);
-                        }
+        match (expr_to_pattern_help(arena, &call.value), alias_ann) {
+            (Ok(good), Some(mut ann_type)) => {
+                // put the spaces from after the operator in front of the call
+                if !spaces_after_operator.is_empty() {
+                    ann_type = arena
+                        .alloc(ann_type.value)
+                        .with_spaces_before(spaces_after_operator, ann_type.region);
+                }
 
-                        let value_def = ValueDef::Annotation(Loc::at(expr_region, good), ann_type);
+                let value_def = ValueDef::Annotation(Loc::at(expr_region, good), ann_type);
 
-                        (Stmt::ValueDef(value_def), state)
-                    }
-                }
+                (Stmt::ValueDef(value_def), state)
             }
-            Err(_) => {
+            _ => {
                 // this `:`/`:=` likely occurred inline; treat it as an invalid operator
                 let op = match kind.value {
                     AliasOrOpaque::Alias => ":",
@@ -1235,6 +1280,14 @@ fn parse_stmt_alias_or_opaque<'a>(
     Ok((MadeProgress, res, state))
 }
 
+/// Check whether the next thing after any spaces is a closing paren
+fn at_parens_end<'a>(arena: &'a Bump, state: &State<'a>) -> bool {
+    match space0_e(EExpr::IndentEnd).parse(arena, state.clone(), 0) {
+        Ok((_, _, state)) => state.bytes().first() == Some(&b')'),
+        Err(_) => false,
+    }
+}
+
 mod ability {
     use parser::absolute_indented_seq;
 
@@ -1266,6 +1319,27 @@ mod ability {
         )
     }
 
+    /// Checks whether the next line starts with something like `name :`, which is how every
+    /// ability demand begins. Used to recognize ability definitions that use the old `has`
+    /// keyword instead of `implements`.
+    pub fn is_demand_on_next_line<'a>(arena: &'a Bump, state: State<'a>, min_indent: u32) -> bool {
+        let parser = and(
+            space0_e(EAbility::DemandName),
+            and(
+                specialize_err(|_, pos| EAbility::DemandName(pos), lowercase_ident()),
+                and(
+                    space0_e(EAbility::DemandName),
+                    byte(b':', EAbility::DemandColon),
+                ),
+            ),
+        );
+
+        match parser.parse(arena, state, min_indent) {
+            Ok((_, (spaces, _), _)) => spaces.iter().any(|space| space.is_newline()),
+            Err(_) => false,
+        }
+    }
+
     pub enum IndentLevel {
         PendingMin(u32),
         Exact(u32),
@@ -2183,6 +2257,7 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::RecordUpdate { .. }
         | Expr::RecordUpdater(_)
         | Expr::UnaryOp(_, _)
+        | Expr::TypeAscription(_, _)
         | Expr::TrySuffix { .. }
         | Expr::Crash
         | Expr::RecordBuilder { .. } => return Err(()),
@@ -18,6 +18,10 @@ pub const EXPOSING: &str = "exposing";
 pub const IMPLEMENTS: &str = "implements";
 pub const WHERE: &str = "where";
 
+// Abilities used to be declared with `has` rather than `implements`. It is not a keyword, but
+// the parser looks for it in order to point people to the new syntax.
+pub const HAS: &str = "has";
+
 // These keywords are valid in headers
 pub const PLATFORM: &str = "platform";
 
@@ -757,6 +757,10 @@ impl<'a> Normalize<'a> for Expr<'a> {
             Expr::UnaryOp(a, b) => {
                 Expr::UnaryOp(arena.alloc(a.normalize(arena)), b.normalize(arena))
             }
+            Expr::TypeAscription(a, b) => Expr::TypeAscription(
+                arena.alloc(a.normalize(arena)),
+                arena.alloc(b.normalize(arena)),
+            ),
             Expr::If {
                 if_thens,
                 final_else,
@@ -847,6 +851,7 @@ impl<'a> Normalize<'a> for Pattern<'a> {
                 is_negative,
             },
             Pattern::FloatLiteral(a) => Pattern::FloatLiteral(a),
+            Pattern::IntRange(a, b) => Pattern::IntRange(a, b),
             Pattern::StrLiteral(a) => Pattern::StrLiteral(a),
             Pattern::Underscore(a) => Pattern::Underscore(a),
             Pattern::Malformed(a) => Pattern::Malformed(a),
@@ -1264,6 +1269,8 @@ impl<'a> Normalize<'a> for EType<'a> {
             EType::TFunctionArgument(_) => EType::TFunctionArgument(Position::zero()),
             EType::TWhereBar(_) => EType::TWhereBar(Position::zero()),
             EType::TImplementsClause(_) => EType::TImplementsClause(Position::zero()),
+            EType::THasInsteadOfImplements(_) => EType::THasInsteadOfImplements(Position::zero()),
+            EType::THasClauseInsteadOfWhere(_) => EType::THasClauseInsteadOfWhere(Position::zero()),
             EType::TAbilityImpl(inner_err, _) => {
                 EType::TAbilityImpl(inner_err.normalize(arena), Position::zero())
             }
@@ -1444,6 +1451,7 @@ impl<'a> Normalize<'a> for PList<'a> {
             PList::End(_) => PList::End(Position::zero()),
             PList::Open(_) => PList::Open(Position::zero()),
             PList::Rest(_) => PList::Rest(Position::zero()),
+            PList::RestWithoutAs(_) => PList::RestWithoutAs(Position::zero()),
             PList::Pattern(inner_err, _) => {
                 PList::Pattern(arena.alloc(inner_err.normalize(arena)), Position::zero())
             }
@@ -1552,6 +1560,9 @@ impl<'a> Normalize<'a> for EAbility<'a> {
             }
             EAbility::DemandName(_) => EAbility::DemandName(Position::zero()),
             EAbility::DemandColon(_) => EAbility::DemandColon(Position::zero()),
+            EAbility::HasInsteadOfImplements(_) => {
+                EAbility::HasInsteadOfImplements(Position::zero())
+            }
         }
     }
 }
@@ -484,6 +484,8 @@ pub enum EAbility<'a> {
     DemandAlignment(i32, Position),
     DemandName(Position),
     DemandColon(Position),
+
+    HasInsteadOfImplements(Position),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -609,6 +611,7 @@ pub enum PList<'a> {
     Open(Position),
 
     Rest(Position),
+    RestWithoutAs(Position),
     Pattern(&'a EPattern<'a>, Position),
 
     Space(BadInputError, Position),
@@ -643,6 +646,8 @@ pub enum EType<'a> {
     TFunctionArgument(Position),
     TWhereBar(Position),
     TImplementsClause(Position),
+    THasInsteadOfImplements(Position),
+    THasClauseInsteadOfWhere(Position),
     TAbilityImpl(ETypeAbilityImpl<'a>, Position),
     ///
     TIndentStart(Position),
@@ -13,7 +13,7 @@ use crate::string_literal::StrLikeLiteral;
 use bumpalo::collections::string::String;
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
-use roc_region::all::{Loc, Region};
+use roc_region::all::{Loc, Position, Region};
 
 /// Different patterns are supported in different circumstances.
 /// For example, when branches can pattern match on number literals, but
@@ -228,26 +228,63 @@ fn loc_pattern_in_parens_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PInPare
 }
 
 fn number_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
-    specialize_err(
-        EPattern::NumLiteral,
-        map(crate::number_literal::number_literal(), |literal| {
-            use crate::number_literal::NumLiteral::*;
-
-            match literal {
-                Num(s) => Pattern::NumLiteral(s),
-                Float(s) => Pattern::FloatLiteral(s),
-                NonBase10Int {
-                    string,
-                    base,
-                    is_negative,
-                } => Pattern::NonBase10Literal {
-                    string,
-                    base,
-                    is_negative,
-                },
+    move |arena, state: State<'a>, min_indent| {
+        let start_state = state.clone();
+        let (_, literal, state) = specialize_err(
+            EPattern::NumLiteral,
+            crate::number_literal::number_literal(),
+        )
+        .parse(arena, state, min_indent)?;
+
+        use crate::number_literal::NumLiteral::*;
+
+        if is_int_literal(&literal) && state.bytes().starts_with(b"..") {
+            // An inclusive range like `1..9`, `-5..-1` or `0x10..0x20`
+            let end_start_state = state.clone().advance(2);
+
+            if let Ok((_, end, end_state)) = crate::number_literal::number_literal().parse(
+                arena,
+                end_start_state.clone(),
+                min_indent,
+            ) {
+                if is_int_literal(&end) {
+                    let start = chomped_since(&start_state, &state);
+                    let end = chomped_since(&end_start_state, &end_state);
+
+                    return Ok((MadeProgress, Pattern::IntRange(start, end), end_state));
+                }
             }
-        }),
-    )
+        }
+
+        let pattern = match literal {
+            Num(s) => Pattern::NumLiteral(s),
+            Float(s) => Pattern::FloatLiteral(s),
+            NonBase10Int {
+                string,
+                base,
+                is_negative,
+            } => Pattern::NonBase10Literal {
+                string,
+                base,
+                is_negative,
+            },
+        };
+
+        Ok((MadeProgress, pattern, state))
+    }
+}
+
+fn is_int_literal(literal: &crate::number_literal::NumLiteral<'_>) -> bool {
+    use crate::number_literal::NumLiteral::*;
+
+    matches!(literal, Num(_) | NonBase10Int { .. })
+}
+
+/// The source text a parser consumed to get from `before` to `after`
+fn chomped_since<'a>(before: &State<'a>, after: &State<'a>) -> &'a str {
+    let chomped = after.pos().offset - before.pos().offset;
+
+    std::str::from_utf8(&before.bytes()[..chomped as usize]).unwrap()
 }
 
 fn string_like_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
@@ -267,16 +304,39 @@ fn string_like_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>>
 }
 
 fn list_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, PList<'a>> {
-    map(
-        collection_trailing_sep_e(
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let original_state = state.clone();
+
+        match collection_trailing_sep_e(
             byte(b'[', PList::Open),
             list_element_pattern(),
             byte(b',', PList::End),
             byte(b']', PList::End),
             Pattern::SpaceBefore,
-        ),
-        Pattern::List,
-    )
+        )
+        .parse(arena, state, min_indent)
+        {
+            Ok((progress, patterns, state)) => Ok((progress, Pattern::List(patterns), state)),
+            Err((progress, PList::End(pos))) if is_rest_without_as(&original_state, pos) => {
+                // e.g. `[first, .. rest]`, which should be `[first, .. as rest]`
+                Err((progress, PList::RestWithoutAs(pos)))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Whether the list pattern got stuck on a name right after a `..`
+fn is_rest_without_as(original_state: &State<'_>, pos: Position) -> bool {
+    let consumed = (pos.offset - original_state.pos().offset) as usize;
+    let (before, after) = original_state.bytes().split_at(consumed);
+
+    let before_spaces = before
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |index| index + 1);
+
+    matches!(after.first(), Some(b'a'..=b'z')) && before[..before_spaces].ends_with(b"..")
 }
 
 fn list_element_pattern<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PList<'a>> {
@@ -686,6 +686,10 @@ fn expression<'a>(
                 ))
             }
             Err(_) => {
+                if let Some(bar_pos) = old_has_clause(arena, state.clone(), min_indent) {
+                    return Err((MadeProgress, EType::THasClauseInsteadOfWhere(bar_pos)));
+                }
+
                 // Ran into a problem parsing a where clause; don't suppose there is one.
                 Ok((progress, annot, state))
             }
@@ -694,6 +698,33 @@ fn expression<'a>(
     .trace("type_annotation:expression")
 }
 
+/// Look for an ability constraint written with the old syntax, e.g. ` | a has Hash`,
+/// and return the position of its `|` if there is one.
+fn old_has_clause<'a>(arena: &'a Bump, state: State<'a>, min_indent: u32) -> Option<Position> {
+    let (_, _, state) = space0_e(EType::TIndentStart)
+        .parse(arena, state, min_indent)
+        .ok()?;
+    let bar_pos = state.pos();
+
+    let (_, _, state) = skip_first(
+        and(byte(b'|', EType::TWhereBar), space0_e(EType::TIndentStart)),
+        skip_first(
+            and(
+                specialize_err(|_, pos| EType::TBadTypeVariable(pos), lowercase_ident()),
+                space0_e(EType::TIndentStart),
+            ),
+            word(keyword::HAS, EType::TImplementsClause),
+        ),
+    )
+    .parse(arena, state, min_indent)
+    .ok()?;
+
+    match state.bytes().first() {
+        None | Some(b' ' | b'\n' | b'\r') => Some(bar_pos),
+        _ => None,
+    }
+}
+
 /// Parse a basic type annotation that's a combination of variables
 /// (which are lowercase and unqualified, e.g. `a` in `List a`),
 /// type applications (which are uppercase and optionally qualified, e.g.
@@ -746,15 +777,21 @@ fn parse_type_variable<'a>(
         state.clone(),
         min_indent,
     ) {
-        Ok((_, name, state)) => {
+        Ok((_, name, next_state)) => {
             if name == crate::keyword::WHERE
                 || (name == crate::keyword::IMPLEMENTS && stop_at_surface_has)
             {
-                Err((NoProgress, EType::TEnd(state.pos())))
+                Err((NoProgress, EType::TEnd(next_state.pos())))
+            } else if name == crate::keyword::HAS
+                && stop_at_surface_has
+                && next_state.bytes().iter().find(|&&b| b != b' ') == Some(&b'[')
+            {
+                // An opaque type listing its abilities with the old `has [...]` syntax
+                Err((MadeProgress, EType::THasInsteadOfImplements(state.pos())))
             } else {
                 let answer = TypeAnnotation::BoundVariable(name);
 
-                Ok((MadeProgress, answer, state))
+                Ok((MadeProgress, answer, next_state))
             }
         }
         Err((progress, _)) => Err((progress, EType::TBadTypeVariable(state.pos()))),
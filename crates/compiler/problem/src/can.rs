@@ -413,6 +413,7 @@ impl Problem {
                 record_region: region,
                 ..
             })
+            | Problem::RuntimeError(RuntimeError::DefaultUsesPatternBinding { region, .. })
             | Problem::RuntimeError(RuntimeError::UnsupportedPattern(region))
             | Problem::RuntimeError(RuntimeError::MalformedPattern(_, region))
             | Problem::RuntimeError(RuntimeError::LookupNotInScope {
@@ -603,6 +604,12 @@ pub enum RuntimeError {
         record_region: Region,
         field_region: Region,
     },
+    /// The default of an optional record field uses a name bound by the same pattern,
+    /// e.g. `\{ x, y ? x } -> ...`
+    DefaultUsesPatternBinding {
+        symbol: Symbol,
+        region: Region,
+    },
     // Example: (5 = 1 + 2) is an unsupported pattern in an assignment; Int patterns aren't allowed in assignments!
     UnsupportedPattern(Region),
     // Example: when 1 is 1.X -> 32
@@ -737,6 +744,7 @@ impl RuntimeError {
             RuntimeError::Shadowing { shadow, .. } => shadow.region,
             RuntimeError::InvalidOptionalValue { field_region, .. } => *field_region,
             RuntimeError::UnsupportedPattern(region)
+            | RuntimeError::DefaultUsesPatternBinding { region, .. }
             | RuntimeError::MalformedPattern(_, region)
             | RuntimeError::OpaqueOutsideScope {
                 referenced_region: region,
@@ -790,4 +798,5 @@ pub enum MalformedPatternProblem {
     EmptySingleQuote,
     MultipleCharsInSingleQuote,
     DuplicateListRestPattern,
+    EmptyIntRange,
 }
@@ -18,4 +18,8 @@ pub enum Severity {
     /// but should be cleaned up
     /// (e.g. unused def, unused import)
     Warning,
+
+    /// This is not a problem at all, just something the user may want to know
+    /// (e.g. the inferred type of a `_` in an annotation)
+    Info,
 }
@@ -10,7 +10,8 @@ use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
 use roc_solve_problem::{
-    NotDerivableContext, NotDerivableEq, TypeError, UnderivableReason, Unfulfilled,
+    NotDerivableContext, NotDerivableEq, NotDerivableSort, TypeError, UnderivableReason,
+    Unfulfilled,
 };
 use roc_solve_schema::UnificationMode;
 use roc_types::num::NumericRange;
@@ -310,6 +311,10 @@ impl ObligationCache {
                 var,
             )),
 
+            Symbol::SORT_SORT_ABILITY => {
+                Some(DeriveSort::is_derivable(self, abilities_store, subs, var))
+            }
+
             _ => None,
         };
 
@@ -392,6 +397,7 @@ impl ObligationCache {
             DeriveEq::ABILITY => DeriveEq::is_derivable_builtin_opaque(opaque),
             DeriveHash::ABILITY => DeriveHash::is_derivable_builtin_opaque(opaque),
             DeriveInspect::ABILITY => DeriveInspect::is_derivable_builtin_opaque(opaque),
+            DeriveSort::ABILITY => DeriveSort::is_derivable_builtin_opaque(opaque),
             _ => false,
         };
 
@@ -779,6 +785,8 @@ trait DerivableVisitor {
                         }
                     }
                     EmptyRecord => Self::visit_empty_record(var)?,
+                    // Only reachable as the extension of a closed tuple, which was already visited.
+                    EmptyTuple => {}
                     EmptyTagUnion => Self::visit_empty_tag_union(var)?,
                     EffectfulFunc => {
                         return Err(NotDerivable {
@@ -1388,6 +1396,138 @@ impl DerivableVisitor for DeriveEq {
     }
 }
 
+struct DeriveSort;
+impl DerivableVisitor for DeriveSort {
+    const ABILITY: Symbol = Symbol::SORT_SORT_ABILITY;
+    const ABILITY_SLICE: SubsSlice<Symbol> = Subs::AB_SORT;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_fixed_int_alias(symbol)
+            || is_builtin_dec_alias(symbol)
+            || is_builtin_bool_alias(symbol)
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(symbol, Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        for (field_name, _, field) in fields.iter_all() {
+            if subs[field].is_optional() {
+                return Err(NotDerivable {
+                    var,
+                    context: NotDerivableContext::Sort(NotDerivableSort::OptionalRecordField(
+                        subs[field_name].clone(),
+                    )),
+                });
+            }
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tuple(
+        _subs: &Subs,
+        _var: Variable,
+        _elems: TupleElems,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_float_alias(symbol) {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::Sort(NotDerivableSort::FloatingPoint),
+            })
+        } else if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    fn visit_floating_point_content(
+        var: Variable,
+        subs: &mut Subs,
+        content_var: Variable,
+    ) -> Result<Descend, NotDerivable> {
+        use roc_unify::unify::unify;
+
+        // Of the floating-point types, only Dec has a total order.
+        // TODO(checkmate): pass checkmate through
+        let unified = unify(
+            &mut with_checkmate!({
+                on => UEnv::new(subs, None),
+                off => UEnv::new(subs),
+            }),
+            content_var,
+            Variable::DECIMAL,
+            UnificationMode::EQ,
+            Polarity::Pos,
+        );
+        match unified {
+            roc_unify::unify::Unified::Success { .. } => Ok(Descend(false)),
+            roc_unify::unify::Unified::Failure(..) => Err(NotDerivable {
+                var,
+                context: NotDerivableContext::Sort(NotDerivableSort::FloatingPoint),
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        // As with `Eq`, ranged numbers are allowed because they are always possibly ints.
+        Ok(())
+    }
+}
+
 /// Determines what type implements an ability member of a specialized signature, given the
 /// [MustImplementAbility] constraints of the signature.
 pub fn type_implementing_specialization(
@@ -10,9 +10,11 @@ use roc_collections::{VecMap, VecSet};
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
+use roc_problem::Severity;
+use roc_region::all::Loc;
 use roc_solve_problem::TypeError;
 use roc_types::subs::{Content, ExposedTypesStorageSubs, FlatType, StorageSubs, Subs, Variable};
-use roc_types::types::{Alias, MemberImpl, Types};
+use roc_types::types::{Alias, MemberImpl, Polarity, Types};
 
 /// A marker that a given Subs has been solved.
 /// The only way to obtain a Solved<Subs> is by running the solver on it.
@@ -36,6 +38,8 @@ impl<T> Solved<T> {
 #[derive(Debug)]
 pub struct SolvedModule {
     pub problems: Vec<TypeError>,
+    /// Reports that are not problems, like the inferred types of `_` holes.
+    pub hints: Vec<TypeError>,
 
     /// all aliases and their definitions. this has to include non-exposed aliases
     /// because exposed aliases can depend on non-exposed ones)
@@ -92,6 +96,7 @@ pub struct SolveOutput {
     pub subs: Solved<Subs>,
     pub scope: solve::Scope,
     pub errors: Vec<TypeError>,
+    pub hints: Vec<TypeError>,
     pub resolved_abilities_store: AbilitiesStore,
 
     #[cfg(debug_assertions)]
@@ -104,6 +109,7 @@ pub fn run_solve(
     mut subs: Subs,
     mut aliases: Aliases,
     mut abilities_store: AbilitiesStore,
+    has_can_errors: bool,
 ) -> SolveOutput {
     for (var, name) in rigid_variables.named {
         subs.rigid_var(var, name);
@@ -117,13 +123,15 @@ pub fn run_solve(
         subs.rigid_var(var, "*".into());
     }
 
+    let home = config.home;
+
     // Now that the module is parsed, canonicalized, and constrained,
     // we need to type check it.
     let mut problems = Vec::new();
 
     // Run the solver to populate Subs.
     let RunSolveOutput {
-        solved,
+        mut solved,
         scope,
         #[cfg(debug_assertions)]
         checkmate,
@@ -135,16 +143,48 @@ pub fn run_solve(
         &mut abilities_store,
     );
 
+    // Builtins use holes liberally, so only report them for user code. A hole's type is
+    // only trustworthy once the module type checks, so don't add to existing errors.
+    let has_errors = has_can_errors
+        || problems
+            .iter()
+            .any(|problem| matches!(problem.severity(), Severity::RuntimeError | Severity::Fatal));
+
+    let mut hints = Vec::new();
+
+    if !home.is_builtin() && !has_errors {
+        report_type_holes(&mut solved, rigid_variables.inferred, &mut hints);
+    }
+
     SolveOutput {
         subs: solved,
         scope,
         errors: problems,
+        hints,
         resolved_abilities_store: abilities_store,
         #[cfg(debug_assertions)]
         checkmate,
     }
 }
 
+/// Reports the solved type of each `_` hole in the module's annotations.
+fn report_type_holes(
+    solved_subs: &mut Solved<Subs>,
+    holes: Vec<Loc<Variable>>,
+    hints: &mut Vec<TypeError>,
+) {
+    let subs = solved_subs.inner_mut();
+
+    for Loc { region, value: var } in holes {
+        // Naming the type's variables must not affect the solved module.
+        let snapshot = subs.snapshot();
+        let typ = subs.var_to_error_type(var, Polarity::Pos);
+        subs.rollback_to(snapshot);
+
+        hints.push(TypeError::TypeHole(region, typ));
+    }
+}
+
 /// Copies exposed types and all ability specializations, which may be implicitly exposed.
 pub fn exposed_types_storage_subs(
     home: ModuleId,
@@ -363,6 +363,18 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn infer_interpolated_call() {
+        infer_eq(
+            indoc!(
+                r#"
+                \rec -> "$(Str.trim rec.name) is $(Num.toStr rec.age)"
+            "#
+            ),
+            "{ age : Num *, name : Str }* -> Str",
+        );
+    }
+
     // LIST MISMATCH
 
     #[test]
@@ -1076,6 +1088,24 @@ mod solve_expr {
         infer_eq("{ x: 5, y : 3.14 }", "{ x : Num *, y : Frac * }");
     }
 
+    #[test]
+    fn record_builder_drops_ignored_fields() {
+        infer_eq(
+            indoc!(
+                r#"
+                map2 = \a, b, combine -> combine a b
+
+                { map2 <-
+                    a: 1u8,
+                    _b: "ignored",
+                    c: "kept",
+                }
+            "#
+            ),
+            "{ a : U8, c : Str }",
+        );
+    }
+
     #[test]
     fn record_literal_accessor() {
         infer_eq("{ x: 5, y : 3.14 }.x", "Num *");
@@ -1098,7 +1128,7 @@ mod solve_expr {
 
     #[test]
     fn tuple_literal_ty() {
-        infer_eq("(5, 3.14 )", "( Num *, Frac * )*");
+        infer_eq("(5, 3.14 )", "( Num *, Frac * )");
     }
 
     #[test]
@@ -2087,6 +2117,18 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn inline_annotation() {
+        infer_eq(
+            indoc!(
+                r"
+                   (Num.add 1 2 : U8)
+                "
+            ),
+            "U8",
+        );
+    }
+
     #[test]
     fn qualified_annotation_f32() {
         infer_eq(
@@ -48,6 +48,8 @@ pub enum TypeError {
     ExpectedEffectful(Region, ExpectEffectfulReason),
     UnsuffixedEffectfulFunction(Region, FxSuffixKind),
     SuffixedPureFunction(Region, FxSuffixKind),
+    /// The type the solver inferred for a `_` hole in an annotation.
+    TypeHole(Region, ErrorType),
 }
 
 impl TypeError {
@@ -77,6 +79,7 @@ impl TypeError {
             TypeError::FxInTopLevel(_, _) => Warning,
             TypeError::UnsuffixedEffectfulFunction(_, _) => Warning,
             TypeError::SuffixedPureFunction(_, _) => Warning,
+            TypeError::TypeHole(..) => Info,
         }
     }
 
@@ -97,7 +100,8 @@ impl TypeError {
             | TypeError::FxInTopLevel(region, _)
             | TypeError::ExpectedEffectful(region, _)
             | TypeError::UnsuffixedEffectfulFunction(region, _)
-            | TypeError::SuffixedPureFunction(region, _) => Some(*region),
+            | TypeError::SuffixedPureFunction(region, _)
+            | TypeError::TypeHole(region, _) => Some(*region),
             TypeError::UnfulfilledAbility(ab, ..) => ab.region(),
             TypeError::Exhaustive(e) => Some(e.region()),
             TypeError::CircularDef(c) => c.first().map(|ce| ce.symbol_region),
@@ -154,9 +158,16 @@ pub enum NotDerivableContext {
     Opaque(Symbol),
     DecodeOptionalRecordField(Lowercase),
     Eq(NotDerivableEq),
+    Sort(NotDerivableSort),
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum NotDerivableEq {
     FloatingPoint,
 }
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum NotDerivableSort {
+    FloatingPoint,
+    OptionalRecordField(Lowercase),
+}
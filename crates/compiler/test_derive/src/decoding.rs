@@ -188,8 +188,8 @@ fn record_2_fields() {
 fn tuple_2_fields() {
     derive_test(Decoder, v!((v!(STR), v!(U8),)), |golden| {
         assert_snapshot!(golden, @r###"
-        # derived for ( Str, U8 )*
-        # Decoder ( val, val1 )* fmt where fmt implements DecoderFormatting, val implements Decoding, val1 implements Decoding
+        # derived for ( Str, U8 )
+        # Decoder ( val, val1 ) fmt where fmt implements DecoderFormatting, val implements Decoding, val1 implements Decoding
         # List U8, fmt -[[custom(22)]]-> { rest : List U8, result : [Err [TooShort], Ok ( val, val1 )a] } where fmt implements DecoderFormatting, val implements Decoding, val1 implements Decoding
         # Specialization lambda sets:
         #   @<1>: [[custom(22)]]
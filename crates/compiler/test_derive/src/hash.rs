@@ -213,9 +213,9 @@ fn two_field_record() {
 fn two_element_tuple() {
     derive_test(Hash, v!((v!(U8), v!(STR),)), |golden| {
         assert_snapshot!(golden, @r###"
-        # derived for ( U8, Str )*
-        # hasher, ( a, a1 )* -[[hash_(arity:2)(0)]]-> hasher where a implements Hash, a1 implements Hash, hasher implements Hasher
-        # hasher, ( a, a1 )* -[[hash_(arity:2)(0)]]-> hasher where a implements Hash, a1 implements Hash, hasher implements Hasher
+        # derived for ( U8, Str )
+        # hasher, ( a, a1 ) -[[hash_(arity:2)(0)]]-> hasher where a implements Hash, a1 implements Hash, hasher implements Hasher
+        # hasher, ( a, a1 ) -[[hash_(arity:2)(0)]]-> hasher where a implements Hash, a1 implements Hash, hasher implements Hasher
         # Specialization lambda sets:
         #   @<1>: [[hash_(arity:2)(0)]]
         #Derived.hash_(arity:2) =
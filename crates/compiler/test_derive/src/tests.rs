@@ -4,5 +4,6 @@ mod decoding;
 mod encoding;
 mod eq;
 mod hash;
+mod sort;
 
 mod util;
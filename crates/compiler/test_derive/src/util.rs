@@ -72,6 +72,11 @@ fn module_source_and_path(builtin: DeriveBuiltin) -> (ModuleId, &'static str, Pa
             module_source(ModuleId::INSPECT),
             builtins_path.join("Inspect.roc"),
         ),
+        DeriveBuiltin::Compare => (
+            ModuleId::SORT,
+            module_source(ModuleId::SORT),
+            builtins_path.join("Sort.roc"),
+        ),
     }
 }
 
@@ -455,6 +460,7 @@ fn check_derived_typechecks_and_golden(
         test_subs,
         Default::default(),
         abilities_store,
+        false,
     );
     dbg_do!(
         roc_debug_flags::ROC_PRINT_UNIFICATIONS_DERIVED,
@@ -2219,6 +2219,111 @@ fn issue_4772_weakened_monomorphic_destructure() {
     })
 }
 
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+mod sort {
+    #[cfg(feature = "gen-llvm")]
+    use crate::helpers::llvm::assert_evals_to;
+
+    #[cfg(feature = "gen-wasm")]
+    use crate::helpers::wasm::assert_evals_to;
+
+    use indoc::indoc;
+    use roc_std::{RocList, RocStr};
+
+    #[test]
+    fn list_sort_numbers() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = List.sort [3u8, 1, 2]
+                "#
+            ),
+            RocList::from_slice(&[1u8, 2, 3]),
+            RocList<u8>
+        )
+    }
+
+    #[test]
+    fn list_sort_strings() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = List.sort ["pear", "apple", "app"]
+                "#
+            ),
+            RocList::from_slice(&[
+                RocStr::from("app"),
+                RocStr::from("apple"),
+                RocStr::from("pear"),
+            ]),
+            RocList<RocStr>
+        )
+    }
+
+    #[test]
+    fn derive_sort_for_record() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    [{ name: "b", age: 30u8 }, { name: "a", age: 30 }, { name: "c", age: 20 }]
+                    |> List.sort
+                    |> List.map .name
+                    |> Str.joinWith ","
+                "#
+            ),
+            RocStr::from("c,a,b"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn derive_sort_for_tag_union() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main =
+                    when Sort.compare (Circle 2u8) (Square 1u8 1u8) is
+                        LT -> "lt"
+                        EQ -> "eq"
+                        GT -> "gt"
+                "#
+            ),
+            RocStr::from("lt"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn derive_sort_for_opaque() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Version := (U8, U8) implements [Sort]
+
+                main =
+                    when Sort.compare (@Version (1, 10)) (@Version (1, 2)) is
+                        LT -> "lt"
+                        EQ -> "eq"
+                        GT -> "gt"
+                "#
+            ),
+            RocStr::from("gt"),
+            RocStr
+        )
+    }
+}
+
 mod inspect {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
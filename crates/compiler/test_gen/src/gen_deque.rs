@@ -0,0 +1,152 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+// #[cfg(feature = "gen-dev")]
+// use crate::helpers::dev::assert_evals_to;
+
+// #[cfg(feature = "gen-wasm")]
+// use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+use roc_std::{RocList, RocResult};
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn empty_len() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            Deque.len (Deque.empty {})
+            "
+        ),
+        0,
+        u64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn push_both_ends() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            Deque.empty {}
+            |> Deque.pushBack 3i64
+            |> Deque.pushFront 2
+            |> Deque.pushBack 4
+            |> Deque.pushFront 1
+            |> Deque.toList
+            "
+        ),
+        RocList::from_slice(&[1, 2, 3, 4]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn pop_front_from_back_half() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            when Deque.popFront (Deque.fromList [1i64, 2, 3]) is
+                Ok { deque, elem } -> List.prepend (Deque.toList deque) elem
+                Err DequeWasEmpty -> []
+            "
+        ),
+        RocList::from_slice(&[1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn pop_back_from_front_half() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            deque =
+                Deque.empty {}
+                |> Deque.pushFront 3i64
+                |> Deque.pushFront 2
+                |> Deque.pushFront 1
+
+            when Deque.popBack deque is
+                Ok { deque: rest, elem } -> List.append (Deque.toList rest) elem
+                Err DequeWasEmpty -> []
+            "
+        ),
+        RocList::from_slice(&[1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn pop_empty() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            Deque.popFront (Deque.empty {})
+            |> Result.map .elem
+            |> Result.mapErr \DequeWasEmpty -> 0u8
+            "
+        ),
+        RocResult::err(0),
+        RocResult<i64, u8>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn first_and_last() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            deque =
+                Deque.single 2i64
+                |> Deque.pushFront 1
+                |> Deque.pushBack 3
+
+            when (Deque.first deque, Deque.last deque) is
+                (Ok a, Ok b) -> [a, b]
+                _ -> []
+            "
+        ),
+        RocList::from_slice(&[1, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn walk_in_order() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            Deque.fromList [2i64, 3]
+            |> Deque.pushFront 1
+            |> Deque.walk [] List.append
+            "
+        ),
+        RocList::from_slice(&[1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn eq_ignores_layout() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            left = Deque.fromList [1u8, 2, 3]
+            right = Deque.empty {} |> Deque.pushFront 3 |> Deque.pushFront 2 |> Deque.pushFront 1
+
+            left == right
+            "
+        ),
+        true,
+        bool
+    );
+}
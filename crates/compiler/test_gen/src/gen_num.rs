@@ -417,6 +417,22 @@ fn character_literal_new_line() {
     );
 }
 
+#[test]
+fn character_literal_unicode_escape() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                    x : U32
+                    x = '\u(1F600)'
+
+                    x
+                "
+        ),
+        0x1F600,
+        u32
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dec_float_alias() {
@@ -27,6 +27,22 @@ fn crash_literal() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn crash_not_reached() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main = if Bool.false then crash "unreachable" else 1u8
+            "#
+        ),
+        1u8,
+        u8
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 #[should_panic = r#"User crash with message: "hello crash""#]
@@ -231,6 +231,112 @@ fn gen_large_when_int() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_when_overlapping_int_ranges() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                foo = \num ->
+                    when num is
+                        0..9 -> 1
+                        5..20 -> 2
+                        15..30 -> 3
+                        _ -> 4
+
+                [foo 3, foo 7, foo 12, foo 18, foo 25, foo 31]
+            "
+        ),
+        RocList::from_slice(&[1, 1, 2, 2, 3, 4]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_when_negative_int_ranges() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                foo = \num ->
+                    when num is
+                        -10..-1 -> 1
+                        0 -> 2
+                        1..10 -> 3
+                        _ -> 4
+
+                [foo -10, foo -1, foo 0, foo 10, foo -11, foo 11]
+            "
+        ),
+        RocList::from_slice(&[1, 1, 2, 3, 4, 4]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_when_hex_int_ranges() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                foo = \num ->
+                    when num is
+                        0x10..0x1f -> 1
+                        -0x10..-0x1 -> 2
+                        0b100..0b111 -> 3
+                        _ -> 4
+
+                [foo 16, foo 31, foo -16, foo -1, foo 4, foo 7, foo 32, foo 0]
+            "
+        ),
+        RocList::from_slice(&[1, 1, 2, 2, 3, 3, 4, 4]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_when_int_ranges_and_literals() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                foo = \num ->
+                    when num is
+                        3 -> 30
+                        1..5 -> 10
+                        7 -> 70
+                        6..8 -> 60
+                        _ -> 0
+
+                [foo 3, foo 1, foo 5, foo 7, foo 6, foo 8, foo 9]
+            "
+        ),
+        RocList::from_slice(&[30, 10, 10, 70, 60, 60, 0]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_when_consecutive_negative_ints() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                foo = \num ->
+                    when num is
+                        -1 -> 10
+                        -2 -> 20
+                        0 -> 0
+                        _ -> -1
+
+                foo -2
+            "
+        ),
+        20,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_large_when_float() {
@@ -0,0 +1,196 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+// #[cfg(feature = "gen-dev")]
+// use crate::helpers::dev::assert_evals_to;
+
+// #[cfg(feature = "gen-wasm")]
+// use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+use roc_std::{RocList, RocResult, RocStr};
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn empty_len() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            SortedDict.len (SortedDict.empty {})
+            "
+        ),
+        0,
+        u64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn keys_are_sorted() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            SortedDict.empty {}
+            |> SortedDict.insert 3i64 {}
+            |> SortedDict.insert 1 {}
+            |> SortedDict.insert 2 {}
+            |> SortedDict.insert 1 {}
+            |> SortedDict.keys
+            "
+        ),
+        RocList::from_slice(&[1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn str_keys_are_sorted() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            SortedDict.fromList [("pear", 1u8), ("apple", 2), ("fig", 3)]
+            |> SortedDict.keys
+            "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from("apple"),
+            RocStr::from("fig"),
+            RocStr::from("pear"),
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn from_list_keeps_last_duplicate() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            SortedDict.fromList [(3i64, 30i64), (1, 10), (3, 31), (2, 20), (1, 11)]
+            |> SortedDict.values
+            "
+        ),
+        RocList::from_slice(&[11, 20, 31]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn get_and_remove() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            dict =
+                SortedDict.fromList [(1i64, 10i64), (2, 20), (3, 30)]
+                |> SortedDict.remove 2
+
+            SortedDict.get dict 3
+            |> Result.mapErr \KeyNotFound -> 0u8
+            "
+        ),
+        RocResult::ok(30),
+        RocResult<i64, u8>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn get_missing() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            SortedDict.fromList [(1i64, 10i64)]
+            |> SortedDict.get 2
+            |> Result.mapErr \KeyNotFound -> 0u8
+            "
+        ),
+        RocResult::err(0),
+        RocResult<i64, u8>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn min_and_max() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            dict = SortedDict.fromList [(5i64, {}), (-3, {}), (8, {}), (0, {})]
+
+            when (SortedDict.min dict, SortedDict.max dict) is
+                (Ok (lo, _), Ok (hi, _)) -> [lo, hi]
+                _ -> []
+            "
+        ),
+        RocList::from_slice(&[-3, 8]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn walk_range() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            SortedDict.fromList [(10i64, {}), (20, {}), (30, {}), (40, {})]
+            |> SortedDict.walkRange { start: After 10, end: At 35 } [] \state, k, _ -> List.append state k
+            "
+        ),
+        RocList::from_slice(&[20, 30]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn update_inserts_and_removes() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            toggle = \value ->
+                when value is
+                    Ok {} -> Err Missing
+                    Err Missing -> Ok {}
+
+            SortedDict.fromList [(1i64, {}), (2, {})]
+            |> SortedDict.update 1 toggle
+            |> SortedDict.update 3 toggle
+            |> SortedDict.keys
+            "
+        ),
+        RocList::from_slice(&[2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn many_inserts_and_removes() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            inserted =
+                List.range { start: At 1i64, end: At 200 }
+                |> List.walk (SortedDict.empty {}) \dict, k -> SortedDict.insert dict ((k * 37) % 200) {}
+
+            removed =
+                List.range { start: At 0i64, end: Before 200 }
+                |> List.walk inserted \dict, k -> if k % 10 == 0 then dict else SortedDict.remove dict k
+
+            (SortedDict.len inserted, SortedDict.keys removed)
+            "
+        ),
+        (
+            200,
+            RocList::from_slice(&[
+                0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 110, 120, 130, 140, 150, 160, 170, 180,
+                190
+            ])
+        ),
+        (u64, RocList<i64>)
+    );
+}
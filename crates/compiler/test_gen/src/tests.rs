@@ -7,6 +7,7 @@
 pub mod gen_abilities;
 pub mod gen_compare;
 pub mod gen_definitions;
+pub mod gen_deque;
 pub mod gen_dict;
 pub mod gen_erased;
 pub mod gen_list;
@@ -18,6 +19,7 @@ pub mod gen_refcount;
 pub mod gen_result;
 pub mod gen_return;
 pub mod gen_set;
+pub mod gen_sorted_dict;
 pub mod gen_str;
 pub mod gen_tags;
 pub mod gen_tuples;
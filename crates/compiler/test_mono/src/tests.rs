@@ -447,6 +447,21 @@ fn when_on_two_values() {
     "
 }
 
+#[mono_test]
+fn when_on_int_ranges() {
+    r"
+    f : U8, U8 -> U8
+    f = \x, y ->
+        when (x, y) is
+            (1..9, 0) -> 1
+            (5, _) -> 2
+            (0..99, _) -> 3
+            _ -> 4
+
+    f 5 1
+    "
+}
+
 #[mono_test]
 fn dict() {
     r"
@@ -3710,3 +3725,14 @@ fn return_annotated() {
         "#
     )
 }
+
+#[mono_test]
+fn sort_derived_record() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Sort.compare { a: 1u8, b: "x" } { a: 1u8, b: "y" }
+        "#
+    )
+}
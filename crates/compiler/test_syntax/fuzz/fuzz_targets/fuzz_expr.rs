@@ -8,9 +8,12 @@ fuzz_target!(|data: &[u8]| {
     if let Ok(input) = std::str::from_utf8(data) {
         let input = Input::Expr(input);
         let arena = Bump::new();
-        let ast = input.parse_in(&arena);
-        if let Ok(ast) = ast {
-            if !ast.is_malformed() {
+        if let Ok(ast) = input.parse_in(&arena) {
+            if ast.is_malformed() {
+                // Malformed nodes don't always survive a round trip through the
+                // formatter, but formatting them twice should still be a no-op.
+                input.check_formatting_is_stable();
+            } else {
                 input.check_invariants(|_| (), true);
             }
         }
@@ -1,14 +1,21 @@
 #![no_main]
-use libfuzzer_sys::fuzz_target;
 use bumpalo::Bump;
+use libfuzzer_sys::fuzz_target;
+use roc_parse::ast::Malformed;
 use test_syntax::test_helpers::Input;
 
 fuzz_target!(|data: &[u8]| {
     if let Ok(input) = std::str::from_utf8(data) {
         let input = Input::Full(input);
         let arena = Bump::new();
-        if input.parse_in(&arena).is_ok() {
-            input.check_invariants(|_| (), true);
+        if let Ok(ast) = input.parse_in(&arena) {
+            if ast.is_malformed() {
+                // Malformed nodes don't always survive a round trip through the
+                // formatter, but formatting them twice should still be a no-op.
+                input.check_formatting_is_stable();
+            } else {
+                input.check_invariants(|_| (), true);
+            }
         }
     }
 });
@@ -192,6 +192,40 @@ impl<'a> Input<'a> {
     /// expectations (or, overwrite the expectation based on a command-line flag)
     /// Optionally, based on the value of `check_idempotency`, also verify that the formatting
     /// is idempotent - that if we reformat the output, we get the same result.
+    /// Checks that formatting this input and then formatting the result again gives the same
+    /// output. Unlike [Input::check_invariants], this doesn't require the AST to survive the
+    /// round trip, so it also works for inputs that parse into malformed nodes.
+    pub fn check_formatting_is_stable(&self) {
+        let arena = Bump::new();
+
+        let ast = match self.parse_in(&arena) {
+            Ok(ast) => ast,
+            Err(_) => return,
+        };
+
+        let output = ast.format();
+
+        // Formatted malformed code doesn't always parse again; check_invariants
+        // covers that for well-formed code.
+        let reparsed_ast = match output.as_ref().parse_in(&arena) {
+            Ok(ast) => ast,
+            Err(_) => return,
+        };
+
+        let reformatted = reparsed_ast.format();
+
+        if output != reformatted {
+            eprintln!(
+                "Formatting bug; formatting is not stable.\nOriginal code:\n{}\n\nFormatted code:\n{}\n\n",
+                self.as_str(),
+                output.as_ref().as_str(),
+            );
+            eprintln!("Reformatting the formatted code changed it again, as follows:\n\n");
+
+            assert_multiline_str_eq!(output.as_ref().as_str(), reformatted.as_ref().as_str());
+        }
+    }
+
     pub fn check_invariants(
         &self,
         handle_formatted_output: impl Fn(Input),
@@ -0,0 +1,4 @@
+MEq has
+    eq : a, a -> Bool | a has MEq
+
+1
@@ -0,0 +1,4 @@
+hash : a -> U64 | a has Hash
+hash = \_ -> 0
+
+hash 1
\ No newline at end of file
@@ -0,0 +1,2 @@
+when [] is
+    [first, .. rest] -> ""
@@ -0,0 +1,3 @@
+Age := U32 has [Eq, Hash]
+
+1
@@ -0,0 +1,2 @@
+x = (List.len [1] : U64)
+x
\ No newline at end of file
@@ -0,0 +1,4 @@
+when x is
+    1..9 -> 2
+    -5..-1 -> 3
+    _ -> 4
\ No newline at end of file
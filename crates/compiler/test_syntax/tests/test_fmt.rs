@@ -2223,6 +2223,26 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn trailing_comma_on_single_line_collections() {
+        expr_formats_to(
+            indoc!(
+                r"
+                f : [A, B,], { x : U8, } -> List U8
+                f = \_, _ -> [1, 2, 3,]
+
+                f A { x: 1, }"
+            ),
+            indoc!(
+                r"
+                f : [A, B], { x : U8 } -> List U8
+                f = \_, _ -> [1, 2, 3]
+
+                f A { x: 1 }"
+            ),
+        );
+    }
+
     #[test]
     fn trailing_comma_in_record_annotation_same() {
         expr_formats_same(indoc!(
@@ -5886,6 +5906,31 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn multiline_pipeline_one_step_per_line() {
+        expr_formats_to(
+            indoc!(
+                r"
+                x =
+                    a |> b
+                    |> c
+
+                x
+                "
+            ),
+            indoc!(
+                r"
+                x =
+                    a
+                    |> b
+                    |> c
+
+                x
+                "
+            ),
+        );
+    }
+
     #[test]
     fn ability_member_doc_comments() {
         module_formats_same(indoc!(
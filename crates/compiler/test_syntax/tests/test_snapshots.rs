@@ -182,11 +182,13 @@ mod test_snapshots {
     // see tests/snapshots to see test input(.roc) and expected output(.result-ast)
     snapshot_tests! {
         // BEGIN SNAPSHOTS (for automatic test detection via `env ROC_SNAPSHOT_TEST_OVERWRITE=1 cargo test`)
+        fail/ability_defined_with_has.expr,
         fail/ability_demand_value_has_args.expr,
         fail/ability_demands_not_indented_with_first.expr,
         fail/ability_first_demand_not_indented_enough.expr,
         fail/ability_non_signature_expression.expr,
         fail/alias_or_opaque_fail.expr,
+        fail/annotation_constrained_with_has.expr,
         fail/backpassing_after_annotation.expr,
         fail/bound_variable.expr,
         fail/comment_with_tab.expr,
@@ -217,6 +219,7 @@ mod test_snapshots {
         fail/lambda_missing_indent.expr,
         fail/list_double_comma.expr,
         fail/list_pattern_not_terminated.expr,
+        fail/list_pattern_rest_without_as.expr,
         fail/list_pattern_weird_rest_pattern.expr,
         fail/list_without_end.expr,
         fail/module_params_with_missing_arrow.header,
@@ -224,6 +227,7 @@ mod test_snapshots {
         fail/multi_no_end.expr,
         fail/newline_before_operator_with_defs.expr,
         fail/opaque_type_def_with_newline.expr,
+        fail/opaque_type_derives_with_has.expr,
         fail/pattern_binds_keyword.expr,
         fail/pattern_in_parens_end.expr,
         fail/pattern_in_parens_end_comma.expr,
@@ -357,6 +361,7 @@ mod test_snapshots {
         pass/inline_import.expr,
         pass/inline_ingested_file.expr,
         pass/inline_ingested_file_no_ann.expr,
+        pass/inline_type_annotation.expr,
         pass/int_with_underscore.expr,
         pass/lambda_in_chain.expr,
         pass/lambda_indent.expr,
@@ -542,6 +547,7 @@ mod test_snapshots {
         pass/when_result_list.expr,
         pass/when_with_alternative_patterns.expr,
         pass/when_with_function_application.expr,
+        pass/when_with_int_ranges.expr,
         pass/when_with_negative_numbers.expr,
         pass/when_with_numbers.expr,
         pass/when_with_records.expr,
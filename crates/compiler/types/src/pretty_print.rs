@@ -14,6 +14,7 @@ use roc_module::symbol::{Interns, ModuleId, Symbol};
 
 pub static WILDCARD: &str = "*";
 static EMPTY_RECORD: &str = "{}";
+static EMPTY_TUPLE: &str = "()";
 static EMPTY_TAG_UNION: &str = "[]";
 static EFFECTFUL_FUNC: &str = "! : ... => ?";
 
@@ -412,6 +413,7 @@ fn find_names_needed(
         }
         Error
         | Structure(EmptyRecord)
+        | Structure(EmptyTuple)
         | Structure(EmptyTagUnion)
         | Pure
         | Effectful
@@ -1128,6 +1130,7 @@ fn write_flat_type<'a>(
             pol,
         ),
         EmptyRecord => buf.push_str(EMPTY_RECORD),
+        EmptyTuple => buf.push_str(EMPTY_TUPLE),
         EmptyTagUnion => buf.push_str(EMPTY_TAG_UNION),
         Func(args, closure, ret, fx) => write_fn(
             env,
@@ -1236,12 +1239,19 @@ fn write_flat_type<'a>(
 
             buf.push_str(" )");
 
-            // This is an open tuple, so print the variable
-            // right after the ')'
-            //
-            // e.g. the "*" at the end of `( I64, I64 )*`
-            // or the "r" at the end of `( I64, I64 )r`
-            write_content(env, ctx, ext_var, subs, buf, parens, pol)
+            match subs.get_content_without_compacting(ext_var) {
+                Content::Structure(EmptyTuple) => {
+                    // This is a closed tuple. We're done!
+                }
+                _ => {
+                    // This is an open tuple, so print the variable
+                    // right after the ')'
+                    //
+                    // e.g. the "*" at the end of `( I64, I64 )*`
+                    // or the "r" at the end of `( I64, I64 )r`
+                    write_content(env, ctx, ext_var, subs, buf, parens, pol)
+                }
+            }
         }
         TagUnion(tags, ext_var) => {
             buf.push('[');
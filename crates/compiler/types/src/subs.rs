@@ -832,6 +832,7 @@ fn subs_fmt_flat_type(this: &FlatType, subs: &Subs, f: &mut fmt::Formatter) -> f
             write!(f, "]<{new_ext:?}> as <{rec:?}>")
         }
         FlatType::EmptyRecord => write!(f, "EmptyRecord"),
+        FlatType::EmptyTuple => write!(f, "EmptyTuple"),
         FlatType::EmptyTagUnion => write!(f, "EmptyTagUnion"),
         FlatType::EffectfulFunc => write!(f, "EffectfulFunc"),
     }
@@ -1540,6 +1541,8 @@ impl Subs {
     pub const AB_EQ: SubsSlice<Symbol>              = SubsSlice::new(4, 1);
     #[rustfmt::skip]
     pub const AB_INSPECT: SubsSlice<Symbol>         = SubsSlice::new(5, 1);
+    #[rustfmt::skip]
+    pub const AB_SORT: SubsSlice<Symbol>            = SubsSlice::new(6, 1);
     // END INIT-SymbolSubsSlice
 
     pub fn new() -> Self {
@@ -1569,6 +1572,7 @@ impl Subs {
         symbol_names.push(Symbol::HASH_HASH_ABILITY);
         symbol_names.push(Symbol::BOOL_EQ);
         symbol_names.push(Symbol::INSPECT_INSPECT_ABILITY);
+        symbol_names.push(Symbol::SORT_SORT_ABILITY);
         // END INIT-SymbolSubsSlice
 
         // IFTTT INIT-VariableSubsSlice
@@ -1601,6 +1605,10 @@ impl Subs {
             Variable::EMPTY_RECORD,
             Content::Structure(FlatType::EmptyRecord),
         );
+        subs.set_content(
+            Variable::EMPTY_TUPLE,
+            Content::Structure(FlatType::EmptyTuple),
+        );
         subs.set_content(
             Variable::EMPTY_TAG_UNION,
             Content::Structure(FlatType::EmptyTagUnion),
@@ -2598,6 +2606,7 @@ pub enum FlatType {
 
     RecursiveTagUnion(Variable, UnionTags, TagExt),
     EmptyRecord,
+    EmptyTuple,
     EmptyTagUnion,
 }
 
@@ -3495,7 +3504,7 @@ fn occurs(
 
                     short_circuit_help(subs, root_var, ctx, ext_var)
                 }
-                EmptyRecord | EmptyTagUnion | EffectfulFunc => Ok(()),
+                EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => Ok(()),
             },
             Alias(_, args, _, _) => {
                 // THEORY: we only need to explore the args, as that is the surface of all
@@ -3689,7 +3698,7 @@ fn explicit_substitute(
                         subs.set_content(in_var, Structure(Tuple(vars_by_elem, new_ext)));
                     }
 
-                    EmptyRecord | EmptyTagUnion | EffectfulFunc => {}
+                    EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => {}
                 }
 
                 in_var
@@ -3875,9 +3884,10 @@ fn get_var_names(
                     accum
                 }
 
-                FlatType::EmptyRecord | FlatType::EmptyTagUnion | FlatType::EffectfulFunc => {
-                    taken_names
-                }
+                FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion
+                | FlatType::EffectfulFunc => taken_names,
 
                 FlatType::Record(vars_by_field, ext) => {
                     let mut accum = get_var_names(subs, ext, taken_names);
@@ -4243,6 +4253,7 @@ fn flat_type_to_err_type(
 
         EffectfulFunc => ErrorType::EffectfulFunc,
         EmptyRecord => ErrorType::Record(SendMap::default(), TypeExt::Closed),
+        EmptyTuple => ErrorType::Tuple(Vec::new(), TypeExt::Closed),
         EmptyTagUnion => ErrorType::TagUnion(SendMap::default(), TypeExt::Closed, pol),
 
         Record(vars_by_field, ext) => {
@@ -4699,6 +4710,7 @@ impl StorageSubs {
                 ext.map(|v| Self::offset_variable(offsets, v)),
             ),
             FlatType::EmptyRecord => FlatType::EmptyRecord,
+            FlatType::EmptyTuple => FlatType::EmptyTuple,
             FlatType::EmptyTagUnion => FlatType::EmptyTagUnion,
             FlatType::EffectfulFunc => FlatType::EffectfulFunc,
         }
@@ -4998,7 +5010,7 @@ fn storage_copy_var_to_help(env: &mut StorageCopyVarToEnv<'_>, var: Variable) ->
                     Func(new_arguments, new_closure_var, new_ret_var, new_fx_var)
                 }
 
-                same @ EmptyRecord | same @ EmptyTagUnion => same,
+                same @ EmptyRecord | same @ EmptyTuple | same @ EmptyTagUnion => same,
 
                 Record(fields, ext) => {
                     let record_fields = {
@@ -5344,7 +5356,9 @@ fn is_registered(content: &Content) -> bool {
         | Content::RigidVar(_)
         | Content::FlexAbleVar(..)
         | Content::RigidAbleVar(..) => false,
-        Content::Structure(FlatType::EmptyRecord | FlatType::EmptyTagUnion) => false,
+        Content::Structure(
+            FlatType::EmptyRecord | FlatType::EmptyTuple | FlatType::EmptyTagUnion,
+        ) => false,
         Content::ErasedLambda => false,
         Content::Pure | Content::Effectful => false,
 
@@ -5469,7 +5483,7 @@ fn copy_import_to_help(env: &mut CopyImportEnv<'_>, max_rank: Rank, var: Variabl
                     Func(new_arguments, new_closure_var, new_ret_var, new_fx_var)
                 }
 
-                same @ EmptyRecord | same @ EmptyTagUnion => same,
+                same @ EmptyRecord | same @ EmptyTuple | same @ EmptyTagUnion => same,
 
                 Record(fields, ext) => {
                     let record_fields = {
@@ -5855,7 +5869,7 @@ fn instantiate_rigids_help(subs: &mut Subs, max_rank: Rank, initial: Variable) {
                     stack.push(fx_var);
                 }
 
-                EmptyRecord | EmptyTagUnion => (),
+                EmptyRecord | EmptyTuple | EmptyTagUnion => (),
 
                 Record(fields, ext) => {
                     let fields = *fields;
@@ -6009,7 +6023,10 @@ pub fn get_member_lambda_sets_at_region(subs: &Subs, var: Variable, target_regio
                     );
                     stack.push(ext.var());
                 }
-                FlatType::EffectfulFunc | FlatType::EmptyRecord | FlatType::EmptyTagUnion => {}
+                FlatType::EffectfulFunc
+                | FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion => {}
             },
             Content::Alias(_, _, real_var, _) => {
                 stack.push(*real_var);
@@ -6088,6 +6105,7 @@ fn is_inhabited(subs: &Subs, var: Variable) -> bool {
                 FlatType::EffectfulFunc => {}
                 FlatType::FunctionOrTagUnion(_, _, _) => {}
                 FlatType::EmptyRecord => {}
+                FlatType::EmptyTuple => {}
                 FlatType::EmptyTagUnion => {
                     return false;
                 }
@@ -4274,13 +4274,11 @@ pub fn gather_tuple_elems_unsorted_iter(
             Structure(Tuple(sub_elems, sub_ext)) => {
                 stack.push(*sub_elems);
 
-                if var == Variable::EMPTY_TUPLE {
-                    break;
-                } else {
-                    var = *sub_ext;
-                }
+                var = *sub_ext;
             }
 
+            Structure(EmptyTuple) => break,
+
             Alias(_, _, actual_var, _) => {
                 // TODO according to elm/compiler: "TODO may be dropping useful alias info here"
                 var = *actual_var;
@@ -662,7 +662,7 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
         target: roc_target::Target::LinuxX64, // This is just type-checking for docs, so "target" doesn't matter
         function_kind: roc_solve::FunctionKind::LambdaSet,
         render: roc_reporting::report::RenderTarget::ColorTerminal,
-        palette: roc_reporting::report::DEFAULT_PALETTE,
+        palette: roc_reporting::report::terminal_palette(),
         threading: Threading::AllAvailable,
         exec_mode: ExecutionMode::Check,
     };
@@ -1395,7 +1395,7 @@ fn report_markdown_link_problem(
     link_markdown: &str,
     problem: LinkProblem,
 ) {
-    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use roc_reporting::report::{terminal_palette, Report, RocDocAllocator};
     use ven_pretty::DocAllocator;
 
     // Report parsing and canonicalization problems
@@ -1435,10 +1435,11 @@ fn report_markdown_link_problem(
             doc,
             title: "INVALID DOCS LINK".to_string(),
             severity: Severity::Warning,
+            region: None,
         }
     };
 
-    let palette = DEFAULT_PALETTE;
+    let palette = terminal_palette();
     let mut buf = String::new();
 
     report.render_color_terminal(&mut buf, &alloc, &palette);
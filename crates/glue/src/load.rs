@@ -16,7 +16,7 @@ use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingPro
 use roc_mono::ir::{generate_glue_procs, CrashTag, GlueProc, OptLevel};
 use roc_mono::layout::{GlobalLayoutInterner, LayoutCache, LayoutInterner};
 use roc_packaging::cache::{self, RocCacheDir};
-use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_reporting::report::{terminal_palette, RenderTarget, Report};
 use roc_target::{Architecture, Target, TargetFromTripleError::TripleUnsupported};
 use roc_types::subs::{Subs, Variable};
 use std::fs::File;
@@ -58,6 +58,7 @@ pub fn generate(
                 opt_level: OptLevel::Development,
                 emit_debug_info: false,
                 emit_llvm_ir: false,
+                emit_asm: false,
                 fuzz: false,
             };
 
@@ -93,6 +94,7 @@ pub fn generate(
                     linking_strategy,
                     build_host,
                     suppress_build_host_warning,
+                    false,
                     None,
                     RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                     load_config,
@@ -129,9 +131,11 @@ pub fn generate(
                     );
                     if problems.warnings > 0 {
                         problems.print_error_warning_count(total_time);
+                        let palette = terminal_palette();
                         println!(
-                            ".\n\nRunning glue despite warnings…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
+                            ".\n\nRunning glue despite warnings…\n\n{}{}",
+                            Report::horizontal_rule(&palette),
+                            palette.reset
                         );
                     }
 
@@ -196,6 +200,9 @@ pub fn generate(
                     handle_error_module(module, total_time, spec_path.as_os_str(), true)
                 }
                 Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
+                Err(BuildFileError::WarningsAsErrors { .. }) => {
+                    unreachable!("glue never promotes warnings to errors")
+                }
             };
 
             // Extend the lifetime of the tempdir to after we're done with everything,
@@ -329,6 +336,7 @@ fn number_lambda_sets(subs: &Subs, initial: Variable) -> Vec<Variable> {
                 }
 
                 EmptyRecord => (),
+                EmptyTuple => (),
                 EmptyTagUnion => (),
                 EffectfulFunc => internal_error!(),
 
@@ -431,7 +439,7 @@ pub fn load_types(
             target,
             function_kind,
             render: RenderTarget::Generic,
-            palette: DEFAULT_PALETTE,
+            palette: terminal_palette(),
             threading,
             exec_mode: ExecutionMode::Check,
         },
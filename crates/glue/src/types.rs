@@ -1344,7 +1344,7 @@ fn add_type_help<'a>(
         | Content::Structure(FlatType::EffectfulFunc) => {
             todo!("TODO give a nice error message for a non-concrete type being passed to the host")
         }
-        Content::Structure(FlatType::Tuple(..)) => {
+        Content::Structure(FlatType::Tuple(..) | FlatType::EmptyTuple) => {
             todo!();
         }
         Content::Structure(FlatType::Record(fields, ext)) => {
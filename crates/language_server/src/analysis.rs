@@ -144,6 +144,7 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         interns,
         mut can_problems,
         mut type_problems,
+        mut type_hints,
         mut declarations_by_id,
         sources,
         mut typechecked,
@@ -174,6 +175,7 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         module_id_to_url: module_id_to_url_from_sources(&sources),
         can_problems: &mut can_problems,
         type_problems: &mut type_problems,
+        type_hints: &mut type_hints,
         declarations_by_id: &mut declarations_by_id,
         typechecked: &mut typechecked,
         root_module: &mut root_module,
@@ -264,6 +266,7 @@ struct AnalyzedDocumentBuilder<'a> {
     module_id_to_url: ModuleIdToUrl,
     can_problems: &'a mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &'a mut MutMap<ModuleId, Vec<TypeError>>,
+    type_hints: &'a mut MutMap<ModuleId, Vec<TypeError>>,
     declarations_by_id: &'a mut MutMap<ModuleId, Declarations>,
     typechecked: &'a mut MutMap<ModuleId, CheckedModule>,
     root_module: &'a mut Option<RootModule>,
@@ -372,13 +375,15 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
 
         let type_problems = self.type_problems.remove(&module_id).unwrap_or_default();
 
+        let type_hints = self.type_hints.remove(&module_id).unwrap_or_default();
+
         for can_problem in can_problems {
             if let Some(diag) = can_problem.into_lsp_diagnostic(&fmt) {
                 all_problems.push(diag);
             }
         }
 
-        for type_problem in type_problems {
+        for type_problem in type_problems.into_iter().chain(type_hints) {
             if let Some(diag) = type_problem.into_lsp_diagnostic(&fmt) {
                 all_problems.push(diag);
             }
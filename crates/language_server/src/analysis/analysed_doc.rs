@@ -100,6 +100,27 @@ impl DocInfo {
         }
     }
 
+    pub fn format_range(&self, range: Range) -> Option<Vec<TextEdit>> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse(arena, source).ok()?;
+
+        let start = range.start.to_roc_position(&self.line_info).offset;
+        let end = range.end.to_roc_position(&self.line_info).offset;
+        let (region, fmt) = ast.fmt_defs_in_range(start, end)?;
+
+        let original = &source[region.start().offset as usize..region.end().offset as usize];
+
+        if original == fmt.as_str() {
+            None
+        } else {
+            let range = region.to_range(&self.line_info);
+            let text_edit = TextEdit::new(range, fmt.to_string());
+            Some(vec![text_edit])
+        }
+    }
+
     pub fn semantic_tokens(&self) -> Option<SemanticTokensResult> {
         let source = &self.source;
         let arena = &Bump::new();
@@ -5,7 +5,7 @@ use roc_parse::{
     header::parse_module_defs,
     parser::SyntaxError,
 };
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Region};
 
 use self::format::FormattedAst;
 
@@ -52,6 +52,55 @@ impl<'a> Ast<'a> {
         FormattedAst::new(buf)
     }
 
+    /// Formats only the top-level defs overlapping the byte offsets `start..=end`.
+    ///
+    /// Returns the region those defs span in the source, along with their formatted text.
+    /// Comments and blank lines around the selected defs are left untouched.
+    pub fn fmt_defs_in_range(&self, start: u32, end: u32) -> Option<(Region, FormattedAst<'a>)> {
+        let defs = &self.defs;
+        let mut selected = (0..defs.len()).filter(|&index| {
+            let region = defs.regions[index];
+
+            region.start().offset <= end && start <= region.end().offset
+        });
+
+        let first = selected.next()?;
+        let last = selected.last().unwrap_or(first);
+
+        let mut selected_defs = Defs::default();
+
+        for (index, def) in defs.defs().enumerate().take(last + 1).skip(first) {
+            let spaces_before = if index == first {
+                &[][..]
+            } else {
+                &defs.spaces[defs.space_before[index].indices()]
+            };
+            let spaces_after = if index == last {
+                &[][..]
+            } else {
+                &defs.spaces[defs.space_after[index].indices()]
+            };
+            let region = defs.regions[index];
+
+            match def {
+                Ok(type_def) => {
+                    selected_defs.push_type_def(*type_def, region, spaces_before, spaces_after)
+                }
+                Err(value_def) => {
+                    selected_defs.push_value_def(*value_def, region, spaces_before, spaces_after)
+                }
+            }
+        }
+
+        let mut buf = Buf::new_in(self.arena);
+
+        roc_fmt::def::fmt_defs(&mut buf, &selected_defs, 0);
+
+        let region = Region::span_across(&defs.regions[first], &defs.regions[last]);
+
+        Some((region, FormattedAst::new(buf)))
+    }
+
     pub fn semantic_tokens(&self) -> impl IntoIterator<Item = Loc<Token>> + '_ {
         let header_tokens = self.module.item.iter_tokens(self.arena);
         let body_tokens = self.defs.iter_tokens(self.arena);
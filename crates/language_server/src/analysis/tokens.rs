@@ -774,6 +774,7 @@ impl IterTokens for Loc<Pattern<'_>> {
             Pattern::NumLiteral(_) => onetoken(Token::Number, region, arena),
             Pattern::NonBase10Literal { .. } => onetoken(Token::Number, region, arena),
             Pattern::FloatLiteral(_) => onetoken(Token::Number, region, arena),
+            Pattern::IntRange(..) => onetoken(Token::Number, region, arena),
             Pattern::StrLiteral(_) => onetoken(Token::String, region, arena),
             Pattern::Underscore(_) => onetoken(Token::Variable, region, arena),
             Pattern::SingleQuote(_) => onetoken(Token::String, region, arena),
@@ -91,6 +91,7 @@ pub(crate) mod diag {
                 Severity::RuntimeError => DiagnosticSeverity::ERROR,
                 Severity::Warning => DiagnosticSeverity::WARNING,
                 Severity::Fatal => DiagnosticSeverity::ERROR,
+                Severity::Info => DiagnosticSeverity::INFORMATION,
             }
         }
     }
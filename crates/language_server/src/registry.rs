@@ -9,8 +9,8 @@ use std::{
 use tokio::sync::{Mutex, MutexGuard};
 
 use tower_lsp::lsp_types::{
-    CompletionResponse, Diagnostic, GotoDefinitionResponse, Hover, Position, SemanticTokensResult,
-    TextEdit, Url,
+    CompletionResponse, Diagnostic, GotoDefinitionResponse, Hover, Position, Range,
+    SemanticTokensResult, TextEdit, Url,
 };
 
 use crate::analysis::{AnalyzedDocument, DocInfo};
@@ -192,6 +192,11 @@ impl Registry {
         document.format()
     }
 
+    pub async fn range_formatting(&self, url: &Url, range: Range) -> Option<Vec<TextEdit>> {
+        let document = self.document_info_by_url(url).await?;
+        document.format_range(range)
+    }
+
     pub async fn semantic_tokens(&self, url: &Url) -> Option<SemanticTokensResult> {
         let document = self.document_info_by_url(url).await?;
         document.semantic_tokens()
@@ -83,6 +83,11 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let document_range_formatting_provider = DocumentRangeFormattingOptions {
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        };
         let semantic_tokens_provider =
             SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
                 work_done_progress_options: WorkDoneProgressOptions {
@@ -108,6 +113,9 @@ impl RocServer {
             hover_provider: Some(hover_provider),
             definition_provider: Some(OneOf::Right(definition_provider)),
             document_formatting_provider: Some(OneOf::Right(document_formatting_provider)),
+            document_range_formatting_provider: Some(OneOf::Right(
+                document_range_formatting_provider,
+            )),
             semantic_tokens_provider: Some(semantic_tokens_provider),
             completion_provider: Some(completion_provider),
             ..ServerCapabilities::default()
@@ -308,6 +316,25 @@ impl LanguageServer for RocServer {
         unwind_async(self.state.registry.formatting(&text_document.uri)).await
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentRangeFormattingParams {
+            text_document,
+            range,
+            options: _,
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .range_formatting(&text_document.uri, range),
+        )
+        .await
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -612,4 +639,29 @@ mod tests {
         "#]]
         .assert_debug_eq(&actual);
     }
+
+    /// Test that range formatting only touches the defs inside the range
+    #[tokio::test]
+    async fn test_range_formatting() {
+        let doc = DOC_LIT.to_string()
+            + indoc! {r#"
+            a =   1
+
+            b =   2
+            "#};
+
+        let (inner, url) = test_setup(doc).await;
+        let registry = &inner.registry;
+
+        let range = Range::new(Position::new(5, 0), Position::new(5, 7));
+        let edits = registry.range_formatting(&url, range).await;
+
+        assert_eq!(
+            edits,
+            Some(vec![TextEdit::new(
+                Range::new(Position::new(5, 0), Position::new(5, 7)),
+                "b = 2".to_string(),
+            )])
+        );
+    }
 }
@@ -8,9 +8,13 @@ use roc_mono::ir::OptLevel;
 use roc_repl_eval::gen::Problems;
 use roc_repl_ui::colors::{CYAN, END_COL};
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_repl_ui::{format_output, is_incomplete, CONT_PROMPT, PROMPT, SHORT_INSTRUCTIONS, TIPS};
+use roc_repl_ui::{
+    format_output, format_type_output, format_unknown_command, is_incomplete, CONT_PROMPT, PROMPT,
+    SHORT_INSTRUCTIONS, TIPS, TYPE_OF_USAGE,
+};
 use roc_reporting::report::{
-    strip_colors, to_file_problem_report_string, ANSI_STYLE_CODES, DEFAULT_PALETTE,
+    strip_colors, terminal_palette, to_file_problem_report_string, RenderTarget, ANSI_STYLE_CODES,
+    NO_COLOR_PALETTE,
 };
 use roc_target::Target;
 use rustyline::highlight::{Highlighter, PromptInfo};
@@ -18,6 +22,7 @@ use rustyline::validate::{self, ValidationContext, ValidationResult, Validator};
 use rustyline::Config;
 use rustyline_derive::{Completer, Helper, Hinter};
 use std::borrow::Cow;
+use std::path::PathBuf;
 use target_lexicon::Triple;
 
 use crate::cli_gen::eval_llvm;
@@ -37,10 +42,13 @@ pub struct ReplHelper {
     state: ReplState,
 }
 
-pub fn main(has_color: bool, has_header: bool) -> i32 {
+pub fn main(has_header: bool) -> i32 {
     use rustyline::error::ReadlineError;
     use rustyline::Editor;
 
+    let palette = terminal_palette();
+    let has_color = palette != NO_COLOR_PALETTE;
+
     let strip_colors_if_necessary = |s: &str| {
         if has_color {
             s.to_string()
@@ -69,10 +77,18 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
         Editor::<ReplHelper>::with_config(Config::builder().color_mode(editor_color_mode).build());
     let repl_helper = ReplHelper::default();
     editor.set_helper(Some(repl_helper));
+
+    let history_path = history_path();
+
+    if let Some(path) = &history_path {
+        // There's no history file the first time the repl is run, which is fine.
+        let _ = editor.load_history(path);
+    }
+
     let target = Triple::host().into();
     let mut arena = Bump::new();
 
-    loop {
+    let exit_code = loop {
         match editor.readline(&strip_colors_if_necessary(PROMPT)) {
             Ok(line) => {
                 let line = line.trim();
@@ -85,7 +101,7 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
                     .state;
 
                 arena.reset();
-                match repl_state.step(&arena, line, target, DEFAULT_PALETTE) {
+                match repl_state.step(&arena, line, target, palette) {
                     ReplAction::Eval { opt_mono, problems } => {
                         let output = evaluate(opt_mono, problems, target);
                         // If there was no output, don't print a blank line!
@@ -94,17 +110,48 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
                             println!("{}", strip_colors_if_necessary(&output));
                         }
                     }
+                    ReplAction::TypeOf {
+                        expr,
+                        opt_loaded,
+                        problems,
+                    } => {
+                        let output =
+                            format_type_output(ANSI_STYLE_CODES, expr, opt_loaded, problems);
+
+                        if !output.is_empty() {
+                            println!("{}", strip_colors_if_necessary(&output));
+                        }
+                    }
                     ReplAction::Exit => {
-                        return 0;
+                        break 0;
                     }
                     ReplAction::FileProblem { filename, error } => {
                         println!(
                             "{}",
-                            to_file_problem_report_string(filename, error, has_color)
+                            to_file_problem_report_string(
+                                filename,
+                                error,
+                                RenderTarget::ColorTerminal,
+                                palette,
+                            )
                         );
                     }
                     ReplAction::Help => {
-                        println!("{TIPS}");
+                        println!("{}", strip_colors_if_necessary(TIPS));
+                    }
+                    ReplAction::TypeOfWithoutExpr => {
+                        println!("{}", strip_colors_if_necessary(TYPE_OF_USAGE));
+                    }
+                    ReplAction::UnknownCommand {
+                        command,
+                        suggestion,
+                    } => {
+                        println!(
+                            "{}",
+                            strip_colors_if_necessary(&format_unknown_command(
+                                &command, suggestion
+                            ))
+                        );
                     }
                     ReplAction::Nothing => {}
                 }
@@ -115,18 +162,32 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
             }
             Err(ReadlineError::Eof) => {
                 // End of input; we're done!
-                return 0;
+                break 0;
             }
             Err(ReadlineError::Interrupted) => {
                 eprintln!("CTRL-C");
-                return 1;
+                break 1;
             }
             Err(err) => {
                 eprintln!("REPL error: {err:?}");
-                return 1;
+                break 1;
             }
         }
+    };
+
+    if let Some(path) = &history_path {
+        // Failing to save history shouldn't turn a clean exit into an error.
+        let _ = editor.save_history(path);
     }
+
+    exit_code
+}
+
+/// Where to persist entered lines between repl sessions, e.g. `~/.roc_history`
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".roc_history"))
 }
 
 pub fn evaluate(
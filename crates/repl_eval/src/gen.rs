@@ -5,14 +5,18 @@ use roc_problem::Severity;
 use roc_reporting::report::Palette;
 use std::path::PathBuf;
 
+use roc_collections::MutMap;
 use roc_fmt::annotation::Formattable;
 use roc_fmt::annotation::{Newlines, Parens};
-use roc_load::{LoadingProblem, MonomorphizedModule};
-use roc_parse::ast::Expr;
-use roc_region::all::LineInfo;
+use roc_load::{LoadedModule, LoadingProblem, MonomorphizedModule};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_parse::ast::{AssignedField, Collection, Expr};
+use roc_region::all::{LineInfo, Loc};
 use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
 use roc_solve::FunctionKind;
+use roc_solve_problem::TypeError;
 use roc_target::Target;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 
 #[derive(Debug)]
 pub struct ReplOutput {
@@ -20,19 +24,126 @@ pub struct ReplOutput {
     pub expr_type: String,
 }
 
-pub fn format_answer<'a>(arena: &'a Bump, answer: Expr<'_>) -> &'a str {
+/// Lists longer than this have the rest of their elements elided in the repl output,
+/// e.g. `[0, 0, 0, … 997 more]`, so a big list doesn't flood the terminal.
+pub const MAX_LIST_ELEMS: usize = 100;
+
+/// Lists, records, tuples and tag payloads nested deeper than this are printed as `…`.
+pub const MAX_DEPTH: usize = 16;
+
+/// How much of a large answer the repl prints before eliding the rest.
+#[derive(Debug, Clone, Copy)]
+pub struct Elision {
+    pub max_list_elems: usize,
+    pub max_depth: usize,
+}
+
+impl Default for Elision {
+    fn default() -> Self {
+        Self {
+            max_list_elems: MAX_LIST_ELEMS,
+            max_depth: MAX_DEPTH,
+        }
+    }
+}
+
+pub fn format_answer<'a>(arena: &'a Bump, answer: Expr<'a>) -> &'a str {
+    format_answer_with(arena, answer, Elision::default())
+}
+
+pub fn format_answer_with<'a>(arena: &'a Bump, answer: Expr<'a>, elision: Elision) -> &'a str {
     match answer {
         Expr::Closure(_, _) => "<function>",
         _ => {
             let mut expr = roc_fmt::Buf::new_in(arena);
 
-            answer.format_with_options(&mut expr, Parens::NotNeeded, Newlines::Yes, 0);
+            elide(arena, &answer, elision, 0).format_with_options(
+                &mut expr,
+                Parens::NotNeeded,
+                Newlines::Yes,
+                0,
+            );
 
             expr.into_bump_str()
         }
     }
 }
 
+/// Rebuild the answer with long lists cut short and deeply nested values replaced by `…`.
+/// The markers are plain identifiers, so the formatter lays them out (and aligns
+/// the surrounding record fields) like any other element.
+fn elide<'a>(arena: &'a Bump, expr: &Expr<'a>, elision: Elision, depth: usize) -> Expr<'a> {
+    let elide_loc = |loc_expr: &Loc<Expr<'a>>| -> &'a Loc<Expr<'a>> {
+        arena.alloc(Loc::at(
+            loc_expr.region,
+            elide(arena, &loc_expr.value, elision, depth + 1),
+        ))
+    };
+
+    match expr {
+        Expr::List(_) | Expr::Record(_) | Expr::Tuple(_) | Expr::Apply(..)
+            if depth >= elision.max_depth =>
+        {
+            elided_marker("…")
+        }
+        Expr::List(items) => {
+            let shown = items.len().min(elision.max_list_elems);
+            let mut elems = bumpalo::collections::Vec::with_capacity_in(shown + 1, arena);
+
+            elems.extend(items.items[..shown].iter().map(|item| elide_loc(item)));
+
+            if items.len() > shown {
+                let more = bumpalo::format!(in arena, "… {} more", items.len() - shown);
+
+                elems.push(&*arena.alloc(Loc::at_zero(elided_marker(more.into_bump_str()))));
+            }
+
+            Expr::List(Collection::with_items(elems.into_bump_slice()))
+        }
+        Expr::Tuple(items) => {
+            let elems = bumpalo::collections::Vec::from_iter_in(
+                items.iter().map(|item| elide_loc(item)),
+                arena,
+            );
+
+            Expr::Tuple(Collection::with_items(elems.into_bump_slice()))
+        }
+        Expr::Record(fields) => {
+            let fields = bumpalo::collections::Vec::from_iter_in(
+                fields.iter().map(|field| {
+                    let value = match field.value {
+                        AssignedField::RequiredValue(label, spaces, value) => {
+                            AssignedField::RequiredValue(label, spaces, elide_loc(value))
+                        }
+                        other => other,
+                    };
+
+                    Loc::at(field.region, value)
+                }),
+                arena,
+            );
+
+            Expr::Record(Collection::with_items(fields.into_bump_slice()))
+        }
+        Expr::Apply(tag, args, called_via) => {
+            let args = bumpalo::collections::Vec::from_iter_in(
+                args.iter().map(|arg| elide_loc(arg)),
+                arena,
+            );
+
+            Expr::Apply(*tag, args.into_bump_slice(), *called_via)
+        }
+        other => *other,
+    }
+}
+
+fn elided_marker(ident: &str) -> Expr<'_> {
+    Expr::Var {
+        module_name: "",
+        ident,
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct Problems {
     pub errors: Vec<String>,
@@ -45,6 +156,21 @@ impl Problems {
     }
 }
 
+/// Pretty-print the type of the expression the REPL type checked, without evaluating it.
+pub fn expr_type_str(loaded: &mut LoadedModule) -> String {
+    debug_assert_eq!(loaded.exposed_to_host.len(), 1);
+    let (_, main_fn_var) = loaded.exposed_to_host.iter().next().unwrap();
+    let main_fn_var = *main_fn_var;
+
+    name_and_print_var(
+        main_fn_var,
+        loaded.solved.inner_mut(),
+        loaded.module_id,
+        &loaded.interns,
+        DebugPrint::NOTHING,
+    )
+}
+
 pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
     arena: &'a Bump,
     defs: I,
@@ -102,6 +228,97 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         ..
     } = &mut loaded;
 
+    let problems = repl_problems(
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    (Some(loaded), problems)
+}
+
+/// Like [compile_to_mono], but stops after type checking, for when we only need the type.
+pub fn compile_to_typechecked<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target: Target,
+    palette: Palette,
+) -> (Option<LoadedModule>, Problems) {
+    let filename = PathBuf::from("replfile.roc");
+    let src_dir = PathBuf::from(".");
+    let (bytes_before_expr, module_src) = promote_expr_to_module(arena, defs, expr);
+    let loaded = roc_load::load_and_typecheck_str(
+        arena,
+        filename,
+        module_src,
+        src_dir,
+        None,
+        target,
+        FunctionKind::LambdaSet,
+        roc_reporting::report::RenderTarget::ColorTerminal,
+        RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
+        palette,
+    );
+
+    let mut loaded = match loaded {
+        Ok(v) => v,
+        Err(LoadingProblem::FormattedReport(report)) => {
+            return (
+                None,
+                Problems {
+                    errors: vec![report],
+                    warnings: Vec::new(),
+                },
+            );
+        }
+        Err(problem) => {
+            // The repl source never imports files or packages, so load has nothing
+            // nicer to report for these; show the problem rather than crashing.
+            return (
+                None,
+                Problems {
+                    errors: vec![format!("Error while loading the repl module: {problem:?}")],
+                    warnings: Vec::new(),
+                },
+            );
+        }
+    };
+
+    let LoadedModule {
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        ..
+    } = &mut loaded;
+
+    let problems = repl_problems(
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    (Some(loaded), problems)
+}
+
+fn repl_problems(
+    interns: &Interns,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    module_src: &str,
+    bytes_before_expr: usize,
+    palette: Palette,
+) -> Problems {
     let mut problems = Problems::default();
 
     let errors = &mut problems.errors;
@@ -142,6 +359,7 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
                     Severity::Fatal | Severity::RuntimeError => {
                         errors.push(buf);
                     }
+                    Severity::Info => {}
                 }
             }
         }
@@ -160,12 +378,13 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
                     Severity::Fatal | Severity::RuntimeError => {
                         errors.push(buf);
                     }
+                    Severity::Info => {}
                 }
             }
         }
     }
 
-    (Some(loaded), problems)
+    problems
 }
 
 fn promote_expr_to_module<'a, 'i, I: Iterator<Item = &'i str>>(
@@ -1,9 +1,9 @@
 use bumpalo::Bump;
 use indoc::indoc;
 use roc_repl_cli::{evaluate, ReplHelper};
-use roc_repl_ui::is_incomplete;
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_reporting::report::DEFAULT_PALETTE;
+use roc_repl_ui::{format_type_output, is_incomplete};
+use roc_reporting::report::{ANSI_STYLE_CODES, DEFAULT_PALETTE};
 use rustyline::Editor;
 use target_lexicon::Triple;
 
@@ -25,6 +25,82 @@ fn persisted_defs() {
     complete("y = 6", &mut state, "6 : Num *");
 }
 
+#[test]
+fn persisted_function_captures_earlier_def() {
+    let mut state = ReplState::new();
+
+    complete("x = 5", &mut state, "5 : Num *");
+    complete(
+        "f = \\n -> n + x",
+        &mut state,
+        "<function> : Num a -> Num a",
+    );
+    complete("f 2", &mut state, "7 : Num *");
+}
+
+#[test]
+fn reset_forgets_defs() {
+    let mut state = ReplState::new();
+
+    complete("x = 5", &mut state, "5 : Num *");
+
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = state.step(&arena, ":reset", target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Nothing));
+
+    match state.step(&arena, "x", target, DEFAULT_PALETTE) {
+        ReplAction::Eval { problems, .. } => assert!(!problems.errors.is_empty()),
+        action => panic!("Unexpected action: {:?}", action),
+    }
+}
+
+#[test]
+fn unknown_command() {
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = ReplState::new().step(&arena, ":rest", target, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::UnknownCommand {
+            command,
+            suggestion,
+        } => {
+            assert_eq!(command, ":rest");
+            assert_eq!(suggestion, Some(":reset"));
+        }
+        _ => panic!("Unexpected action: {:?}", action),
+    }
+}
+
+#[test]
+fn unknown_command_without_close_match() {
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = ReplState::new().step(&arena, ":frobnicate", target, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::UnknownCommand { suggestion, .. } => assert_eq!(suggestion, None),
+        _ => panic!("Unexpected action: {:?}", action),
+    }
+}
+
+#[test]
+fn type_without_expr() {
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let mut state = ReplState::new();
+
+    for input in [":type", ":t", ":type   "] {
+        let action = state.step(&arena, input, target, DEFAULT_PALETTE);
+
+        assert!(
+            matches!(action, ReplAction::TypeOfWithoutExpr),
+            "Unexpected action for {input:?}: {action:?}"
+        );
+    }
+}
+
 #[test]
 fn annotated_body() {
     let mut input = "t : [A, B, C]".to_string();
@@ -80,6 +156,25 @@ fn exhaustiveness_problem() {
     }
 }
 
+#[test]
+fn partial_parens_and_list() {
+    {
+        let mut input = "(1".to_string();
+        incomplete(&mut input);
+
+        input.push_str(" + 2)");
+        complete(&input, &mut ReplState::new(), "3 : Num *");
+    }
+
+    {
+        let mut input = "[1,".to_string();
+        incomplete(&mut input);
+
+        input.push_str("2]");
+        complete(&input, &mut ReplState::new(), "[1, 2] : List (Num *)");
+    }
+}
+
 #[test]
 fn partial_record_definition() {
     // Partially define a record successfully
@@ -145,6 +240,21 @@ fn standalone_annotation() {
     assert!(matches!(action, ReplAction::Nothing));
 }
 
+#[test]
+fn type_of_expr() {
+    let mut state = ReplState::new();
+
+    complete("x = 5", &mut state, "5 : Num *");
+    type_of(
+        ":type Str.concat",
+        &mut state,
+        "Str.concat : Str, Str -> Str",
+    );
+
+    // Past defs are still in scope for :t
+    type_of(":t x", &mut state, "x : Num *");
+}
+
 /// validate and step the given input, then check the Result vs the output
 /// with ANSI escape codes stripped.
 fn complete(input: &str, state: &mut ReplState, expected_start: &str) {
@@ -173,6 +283,33 @@ fn complete(input: &str, state: &mut ReplState, expected_start: &str) {
     }
 }
 
+/// validate and step the given `:type` input, then check the given string vs the output
+/// with ANSI escape codes stripped.
+fn type_of(input: &str, state: &mut ReplState, expected: &str) {
+    assert!(!is_incomplete(input));
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = state.step(&arena, input, target, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::TypeOf {
+            expr,
+            opt_loaded,
+            problems,
+        } => {
+            let string = format_type_output(ANSI_STYLE_CODES, expr, opt_loaded, problems);
+            let escaped =
+                std::string::String::from_utf8(strip_ansi_escapes::strip(string.trim()).unwrap())
+                    .unwrap();
+
+            assert_eq!(expected, escaped);
+        }
+        _ => {
+            panic!("Unexpected action: {:?}", action);
+        }
+    }
+}
+
 fn incomplete(input: &mut String) {
     assert!(is_incomplete(input));
 
@@ -421,6 +421,42 @@ fn list_concat() {
     );
 }
 
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn long_list_is_elided() {
+    let expected = format!("[{}, … 50 more] : List (Num *)", ["0"; 100].join(", "));
+
+    expect_success("List.repeat 0 150", &expected);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn long_list_is_elided_inside_records_and_tags() {
+    let elided = format!("[{}, … 50 more]", ["0"; 100].join(", "));
+
+    expect_success(
+        "{ x: List.repeat 0 150, y: 4 }",
+        &format!("{{ x: {elided}, y: 4 }} : {{ x : List (Num *), y : Num * }}"),
+    );
+    expect_success(
+        "Foo (List.repeat 0 150)",
+        &format!("Foo {elided} : [Foo (List (Num *))]"),
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn deeply_nested_value_is_elided() {
+    let depth = 20;
+    let input = format!("{}1{}", "[".repeat(depth), "]".repeat(depth));
+    // Lists nested deeper than roc_repl_eval's MAX_DEPTH are printed as `…`.
+    let shown = 16;
+    let output = format!("{}…{}", "[".repeat(shown), "]".repeat(shown));
+    let expr_type = (0..depth).fold("Num *".to_string(), |ty, _| format!("List ({ty})"));
+
+    expect_success(&input, &format!("{output} : {expr_type}"));
+}
+
 #[cfg(not(feature = "wasm"))]
 #[test]
 fn list_contains() {
@@ -1221,7 +1257,7 @@ fn aliases_named_err_and_ok() {
             v = (Blah, Stuff)
             v"#
         ),
-        r#"(Blah, Stuff) : ( [Blah], [Stuff] )*"#,
+        r#"(Blah, Stuff) : ( [Blah], [Stuff] )"#,
     );
 }
 
@@ -1490,7 +1526,7 @@ fn tuple() {
             ("a", 2u32)
             "#
         ),
-        r#"("a", 2) : ( Str, U32 )*"#,
+        r#"("a", 2) : ( Str, U32 )"#,
     );
 }
 
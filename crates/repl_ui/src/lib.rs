@@ -7,8 +7,9 @@ use bumpalo::Bump;
 use colors::{CYAN, END_COL, GREEN};
 use const_format::concatcp;
 use repl_state::{parse_src, ParseOutcome};
+use roc_load::LoadedModule;
 use roc_parse::ast::{Expr, ExtractSpaces, ValueDef};
-use roc_repl_eval::gen::{Problems, ReplOutput};
+use roc_repl_eval::gen::{expr_type_str, Problems, ReplOutput};
 use roc_reporting::report::StyleCodes;
 
 // TODO add link to repl tutorial (does not yet exist).
@@ -48,6 +49,20 @@ pub const TIPS: &str = concatcp!(
             ":help",
             END_COL,
             " shows this text again\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
+            ":reset",
+            END_COL,
+            " forgets all the definitions entered so far\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
+            ":type",
+            END_COL,
+            " followed by an expression shows its type without evaluating it\n",
         )
     }
 );
@@ -84,12 +99,58 @@ pub fn is_incomplete(input: &str) -> bool {
                 false
             }
         }
-        ParseOutcome::Empty | ParseOutcome::Help | ParseOutcome::Exit | ParseOutcome::SyntaxErr => {
-            false
+        ParseOutcome::Empty
+        | ParseOutcome::Help
+        | ParseOutcome::Reset
+        | ParseOutcome::UnknownCommand(_)
+        | ParseOutcome::Exit
+        | ParseOutcome::SyntaxErr
+        | ParseOutcome::TypeOf(_)
+        | ParseOutcome::TypeOfWithoutExpr => false,
+    }
+}
+
+/// Shown when `:type` is entered without an expression to get the type of.
+pub const TYPE_OF_USAGE: &str = concatcp!(
+    "Enter ",
+    GREEN,
+    ":type <expression>",
+    END_COL,
+    " (or ",
+    GREEN,
+    ":t <expression>",
+    END_COL,
+    ") to see the type of an expression without evaluating it, like ",
+    GREEN,
+    ":type List.map",
+    END_COL,
+    "."
+);
+
+pub fn format_unknown_command(command: &str, suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(suggestion) => {
+            format!("Unknown command {command} - did you mean {suggestion}? Enter :help to see all the commands.")
         }
+        None => format!("Unknown command {command}. Enter :help to see all the commands."),
     }
 }
 
+/// Format the result of a `:type` command, e.g. `List.len : List * -> U64`
+pub fn format_type_output(
+    style_codes: StyleCodes,
+    expr: String,
+    opt_loaded: Option<LoadedModule>,
+    problems: Problems,
+) -> String {
+    let opt_output = opt_loaded.map(|mut loaded| ReplOutput {
+        expr,
+        expr_type: expr_type_str(&mut loaded),
+    });
+
+    format_output(style_codes, opt_output, problems)
+}
+
 pub fn format_output(
     style_codes: StyleCodes,
     opt_output: Option<ReplOutput>,
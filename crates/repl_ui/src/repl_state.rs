@@ -3,14 +3,15 @@ use std::{fs, io};
 
 use bumpalo::Bump;
 use roc_collections::MutSet;
-use roc_load::MonomorphizedModule;
+use roc_load::{LoadedModule, MonomorphizedModule};
 use roc_parse::ast::{Defs, Expr, Pattern, StrLiteral, TypeDef, TypeHeader, ValueDef};
 use roc_parse::expr::parse_repl_defs_and_optional_expr;
 use roc_parse::parser::EWhen;
 use roc_parse::parser::{EClosure, EExpr, EPattern};
 use roc_parse::state::State;
 use roc_region::all::Loc;
-use roc_repl_eval::gen::{compile_to_mono, Problems};
+use roc_repl_eval::gen::{compile_to_mono, compile_to_typechecked, Problems};
+use roc_reporting::error::r#type::suggest;
 use roc_reporting::report::Palette;
 use roc_target::Target;
 
@@ -38,8 +39,19 @@ pub enum ReplAction<'a> {
         opt_mono: Option<MonomorphizedModule<'a>>,
         problems: Problems,
     },
+    TypeOf {
+        expr: String,
+        opt_loaded: Option<LoadedModule>,
+        problems: Problems,
+    },
     Exit,
     Help,
+    /// `:type` was entered without an expression after it.
+    TypeOfWithoutExpr,
+    UnknownCommand {
+        command: String,
+        suggestion: Option<&'static str>,
+    },
     FileProblem {
         filename: PathBuf,
         error: io::ErrorKind,
@@ -66,6 +78,33 @@ impl ReplState {
         let src: &str = match parse_src(arena, line) {
             ParseOutcome::Empty | ParseOutcome::Help => return ReplAction::Help,
             ParseOutcome::Exit => return ReplAction::Exit,
+            ParseOutcome::Reset => {
+                self.past_defs.clear();
+                self.past_def_idents.clear();
+
+                return ReplAction::Nothing;
+            }
+            ParseOutcome::UnknownCommand(command) => {
+                // Only suggest a command that's a plausible typo, not just the least different one.
+                let suggestion = suggest::closest(command, COMMANDS.to_vec(), 2);
+
+                return ReplAction::UnknownCommand {
+                    command: command.to_string(),
+                    suggestion,
+                };
+            }
+            ParseOutcome::TypeOfWithoutExpr => return ReplAction::TypeOfWithoutExpr,
+            ParseOutcome::TypeOf(expr_src) => {
+                // Only run the compiler far enough to learn the type; don't evaluate anything.
+                let (opt_loaded, problems) =
+                    compile_to_typechecked(arena, self.past_def_srcs(), expr_src, target, palette);
+
+                return ReplAction::TypeOf {
+                    expr: expr_src.trim().to_string(),
+                    opt_loaded,
+                    problems,
+                };
+            }
             ParseOutcome::Incomplete | ParseOutcome::SyntaxErr => {
                 pending_past_def = None;
 
@@ -257,16 +296,8 @@ impl ReplState {
             }
         };
 
-        let (opt_mono, problems) = compile_to_mono(
-            arena,
-            self.past_defs.iter().map(|past_def| match past_def {
-                PastDef::Def { ident: _, src } => src.as_str(),
-                PastDef::Import(src) => src.as_str(),
-            }),
-            src,
-            target,
-            palette,
-        );
+        let (opt_mono, problems) =
+            compile_to_mono(arena, self.past_def_srcs(), src, target, palette);
 
         if let Some((ident, src)) = pending_past_def {
             self.add_past_def(ident, src);
@@ -275,6 +306,13 @@ impl ReplState {
         ReplAction::Eval { opt_mono, problems }
     }
 
+    fn past_def_srcs(&self) -> impl Iterator<Item = &str> {
+        self.past_defs.iter().map(|past_def| match past_def {
+            PastDef::Def { ident: _, src } => src.as_str(),
+            PastDef::Import(src) => src.as_str(),
+        })
+    }
+
     fn add_past_def(&mut self, ident: String, src: String) {
         let existing_idents = &mut self.past_def_idents;
 
@@ -289,17 +327,26 @@ pub enum ParseOutcome<'a> {
     DefsAndExpr(Defs<'a>, Option<Loc<Expr<'a>>>),
     Incomplete,
     SyntaxErr,
+    TypeOf(&'a str),
+    TypeOfWithoutExpr,
+    UnknownCommand(&'a str),
     Empty,
     Help,
+    Reset,
     Exit,
 }
 
+/// The colon commands the repl understands, used to suggest a fix for unknown ones.
+pub const COMMANDS: &[&str] = &[":help", ":type", ":reset", ":quit"];
+
 /// Special case some syntax errors to allow for multi-line inputs
 fn parse_outcome_for_error(e: EExpr<'_>) -> ParseOutcome<'_> {
     match e {
         EExpr::Closure(EClosure::Body(_, _), _)
         | EExpr::When(EWhen::Pattern(EPattern::Start(_), _), _)
         | EExpr::Record(_, _)
+        | EExpr::List(_, _)
+        | EExpr::InParens(_, _)
         | EExpr::Start(_)
         | EExpr::IndentStart(_) => ParseOutcome::Incomplete,
         _ => ParseOutcome::SyntaxErr,
@@ -307,14 +354,31 @@ fn parse_outcome_for_error(e: EExpr<'_>) -> ParseOutcome<'_> {
 }
 
 pub fn parse_src<'a>(arena: &'a Bump, line: &'a str) -> ParseOutcome<'a> {
+    let trimmed = line.trim_start();
+
+    if let Some(expr_src) = trimmed
+        .strip_prefix(":type ")
+        .or_else(|| trimmed.strip_prefix(":t "))
+    {
+        if expr_src.trim().is_empty() {
+            return ParseOutcome::TypeOfWithoutExpr;
+        }
+
+        return ParseOutcome::TypeOf(expr_src);
+    }
+
     match line.trim().to_lowercase().as_str() {
         "" => ParseOutcome::Empty,
         ":help" => ParseOutcome::Help,
+        ":reset" => ParseOutcome::Reset,
+        ":type" | ":t" => ParseOutcome::TypeOfWithoutExpr,
         // These are all common things beginners try.
         // Let people exit the repl easily!
         // If you really need to evaluate `exit` for some reason,
         // you can do `foo = exit` and then evaluate `foo` instead.
         ":exit" | ":quit" | ":q" | "exit" | "quit" | "exit()" | "quit()" => ParseOutcome::Exit,
+        // Nothing that starts with a colon is valid Roc, so it must be a mistyped command.
+        cmd if cmd.starts_with(':') => ParseOutcome::UnknownCommand(line.trim()),
         _ => {
             let src_bytes = line.as_bytes();
 
@@ -12,9 +12,9 @@ use roc_repl_eval::{
     ReplApp, ReplAppMemory,
 };
 use roc_repl_ui::{
-    format_output,
+    format_output, format_type_output, format_unknown_command,
     repl_state::{ReplAction, ReplState},
-    TIPS,
+    TIPS, TYPE_OF_USAGE,
 };
 use roc_target::Target;
 use roc_types::pretty_print::{name_and_print_var, DebugPrint};
@@ -200,6 +200,11 @@ pub async fn entrypoint_from_js(src: String) -> String {
     // Perform the action the state machine asked for, and return the appropriate output string
     match action {
         ReplAction::Help => TIPS.to_string(),
+        ReplAction::TypeOfWithoutExpr => TYPE_OF_USAGE.to_string(),
+        ReplAction::UnknownCommand {
+            command,
+            suggestion,
+        } => format_unknown_command(&command, suggestion),
         ReplAction::Exit => {
             "To exit the web version of the REPL, just close the browser tab!".to_string()
         }
@@ -207,6 +212,11 @@ pub async fn entrypoint_from_js(src: String) -> String {
             "The web version of the REPL cannot import files... for now!".to_string()
         }
         ReplAction::Nothing => String::new(),
+        ReplAction::TypeOf {
+            expr,
+            opt_loaded,
+            problems,
+        } => format_type_output(HTML_STYLE_CODES, expr, opt_loaded, problems),
         ReplAction::Eval { opt_mono, problems } => {
             let opt_output = match opt_mono {
                 Some(mono) => eval_wasm(arena, target, mono).await,
@@ -6,7 +6,7 @@ use roc_problem::can::Problem;
 use roc_region::all::LineInfo;
 use roc_solve_problem::TypeError;
 
-use crate::report::ANSI_STYLE_CODES;
+use crate::report::{terminal_palette, terminal_render_target, RenderTarget};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Problems {
@@ -27,30 +27,41 @@ impl Problems {
         }
     }
 
+    /// Count every warning as an error if `--warnings-as-errors` was set.
+    pub fn with_warnings_as_errors(self, warnings_as_errors: bool) -> Self {
+        if !warnings_as_errors {
+            return self;
+        }
+
+        Problems {
+            fatally_errored: self.fatally_errored,
+            errors: self.errors + self.warnings,
+            warnings: 0,
+        }
+    }
+
     // prints e.g. `1 error and 0 warnings found in 63 ms.`
     pub fn print_error_warning_count(&self, total_time: std::time::Duration) {
-        const GREEN: &str = ANSI_STYLE_CODES.green;
-        const YELLOW: &str = ANSI_STYLE_CODES.yellow;
-        const RESET: &str = ANSI_STYLE_CODES.reset;
+        let palette = terminal_palette();
 
         println!(
             "{}{}{} {} and {}{}{} {} found in {} ms",
             match self.errors {
-                0 => GREEN,
-                _ => YELLOW,
+                0 => palette.success,
+                _ => palette.warning,
             },
             self.errors,
-            RESET,
+            palette.reset,
             match self.errors {
                 1 => "error",
                 _ => "errors",
             },
             match self.warnings {
-                0 => GREEN,
-                _ => YELLOW,
+                0 => palette.success,
+                _ => palette.warning,
             },
             self.warnings,
-            RESET,
+            palette.reset,
             match self.warnings {
                 1 => "warning",
                 _ => "warnings",
@@ -60,16 +71,28 @@ impl Problems {
     }
 }
 
+/// Print a report the loader already rendered, e.g. from `LoadingProblem::FormattedReport`.
+pub fn print_formatted_report(report: &str) {
+    match terminal_render_target() {
+        RenderTarget::Json => eprintln!("{report}"),
+        RenderTarget::ColorTerminal | RenderTarget::Generic | RenderTarget::LanguageServer => {
+            print!("{report}")
+        }
+    }
+}
+
 pub fn report_problems(
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    type_hints: &mut MutMap<ModuleId, Vec<TypeError>>,
 ) -> Problems {
-    use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
+    use crate::report::{can_problem, type_problem, Report, RocDocAllocator};
     use roc_problem::Severity::*;
 
-    let palette = DEFAULT_PALETTE;
+    let palette = terminal_palette();
+    let render_target = terminal_render_target();
     let mut total_problems = 0;
 
     for problems in can_problems.values() {
@@ -84,6 +107,8 @@ pub fn report_problems(
     // never need to re-allocate either the warnings or the errors vec!
     let mut warnings = Vec::with_capacity(total_problems);
     let mut errors = Vec::with_capacity(total_problems);
+    // Hints aren't problems, so they're printed alongside warnings but never counted.
+    let mut hints = Vec::new();
     let mut fatally_errored = false;
 
     for (home, (module_path, src)) in sources.iter() {
@@ -97,15 +122,19 @@ pub fn report_problems(
         let alloc = RocDocAllocator::new(&src_lines, *home, interns);
 
         let problems = type_problems.remove(home).unwrap_or_default();
+        let module_hints = type_hints.remove(home).unwrap_or_default();
 
-        for problem in problems {
+        for problem in problems.into_iter().chain(module_hints) {
             if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
                 let severity = report.severity;
                 let mut buf = String::new();
 
-                report.render_color_terminal(&mut buf, &alloc, &palette);
+                report.render(render_target, &mut buf, &alloc, &palette);
 
                 match severity {
+                    Info => {
+                        hints.push(buf);
+                    }
                     Warning => {
                         warnings.push(buf);
                     }
@@ -141,9 +170,12 @@ pub fn report_problems(
             let severity = report.severity;
             let mut buf = String::new();
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            report.render(render_target, &mut buf, &alloc, &palette);
 
             match severity {
+                Info => {
+                    hints.push(buf);
+                }
                 Warning => {
                     warnings.push(buf);
                 }
@@ -161,31 +193,30 @@ pub fn report_problems(
     debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
     debug_assert_eq!(errors.len() + warnings.len(), total_problems);
 
-    let problems_reported;
-
-    // Only print warnings if there are no errors
-    if errors.is_empty() {
-        problems_reported = warnings.len();
-
-        for warning in warnings.iter() {
-            println!("\n{warning}\n");
-        }
+    // Only print warnings and hints if there are no errors
+    let reported = if errors.is_empty() {
+        warnings.iter().chain(hints.iter()).collect::<Vec<_>>()
     } else {
-        problems_reported = errors.len();
+        errors.iter().collect()
+    };
 
-        for error in errors.iter() {
-            println!("\n{error}\n");
+    if let RenderTarget::Json = render_target {
+        // Like rustc's `--error-format=json`, keep stdout for the human-readable summary.
+        for report in reported {
+            eprintln!("{report}");
+        }
+    } else if !reported.is_empty() {
+        for report in reported {
+            println!("\n{report}\n");
         }
-    }
 
-    // If we printed any problems, print a horizontal rule at the end,
-    // and then clear any ANSI escape codes (e.g. colors) we've used.
-    //
-    // The horizontal rule is nice when running the program right after
-    // compiling it, as it lets you clearly see where the compiler
-    // errors/warnings end and the program output begins.
-    if problems_reported > 0 {
-        println!("{}\u{001B}[0m\n", Report::horizontal_rule(&palette));
+        // Print a horizontal rule at the end, and then clear any
+        // ANSI escape codes (e.g. colors) we've used.
+        //
+        // The horizontal rule is nice when running the program right after
+        // compiling it, as it lets you clearly see where the compiler
+        // errors/warnings end and the program output begins.
+        println!("{}{}\n", Report::horizontal_rule(&palette), palette.reset);
     }
 
     Problems {
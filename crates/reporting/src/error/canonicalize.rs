@@ -77,6 +77,7 @@ pub fn can_problem<'b>(
     let doc;
     let title;
     let severity = problem.severity();
+    let region = problem.region().map(|region| lines.convert_region(region));
 
     match problem {
         Problem::UnusedDef(symbol, region) => {
@@ -459,7 +460,9 @@ pub fn can_problem<'b>(
                     alloc.reflow(" definition:"),
                 ]),
                 alloc.region(lines.convert_region(variable_region), severity),
-                alloc.reflow("Roc does not allow unused type parameters!"),
+                alloc.reflow(
+                    "An unused type parameter has no effect on the type, so it can be removed.",
+                ),
                 // TODO add link to this guide section
                 alloc.tip().append(alloc.reflow(
                     "If you want an unused type parameter (a so-called \"phantom type\"), \
@@ -1472,6 +1475,7 @@ pub fn can_problem<'b>(
         filename,
         doc,
         severity,
+        region,
     }
 }
 
@@ -1505,6 +1509,7 @@ fn to_invalid_optional_value_report<'b>(
         filename,
         doc,
         severity: Severity::RuntimeError,
+        region: None,
     }
 }
 
@@ -2070,6 +2075,7 @@ fn pretty_runtime_error<'b>(
                 EmptySingleQuote => " empty character literal ",
                 MultipleCharsInSingleQuote => " overfull literal ",
                 DuplicateListRestPattern => " second rest pattern ",
+                EmptyIntRange => " range ",
             };
 
             let tip = match problem {
@@ -2085,6 +2091,9 @@ fn pretty_runtime_error<'b>(
                 DuplicateListRestPattern => alloc
                     .tip()
                     .append(alloc.reflow("List patterns can only have one rest pattern")),
+                EmptyIntRange => alloc.tip().append(alloc.reflow(
+                    "The start of a range pattern can't be bigger than its end, so this range can never match",
+                )),
             };
 
             doc = alloc.stack([
@@ -2433,6 +2442,26 @@ fn pretty_runtime_error<'b>(
 
             title = SYNTAX_PROBLEM;
         }
+        RuntimeError::DefaultUsesPatternBinding { symbol, region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This default value uses "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(", which is bound by the same pattern:"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("The default of an optional field is computed before "),
+                    alloc.reflow("any of the names in its pattern are available, so it can't "),
+                    alloc.reflow("refer to them."),
+                ]),
+                alloc.tip().append(alloc.reflow(
+                    "Give the field a default that doesn't depend on the pattern, and compute the value you need in the body instead.",
+                )),
+            ]);
+
+            title = "DEFAULT USES PATTERN NAME";
+        }
         RuntimeError::InvalidRecordUpdate { region } => {
             doc = alloc.stack([
                 alloc.concat([
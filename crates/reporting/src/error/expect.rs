@@ -165,6 +165,7 @@ impl<'a> Renderer<'a> {
             doc,
             filename: self.filename.clone(),
             severity,
+            region: Some(line_col_region),
         };
 
         let mut buf = String::new();
@@ -173,7 +174,7 @@ impl<'a> Renderer<'a> {
             self.render_target,
             &mut buf,
             &self.alloc,
-            &crate::report::DEFAULT_PALETTE,
+            &crate::report::terminal_palette(),
         );
 
         write!(writer, "{buf}")
@@ -191,12 +192,15 @@ impl<'a> Renderer<'a> {
         W: std::io::Write,
     {
         let line_col_region = self.to_line_col_region(expect_region, dbg_expr_region);
+        let palette = crate::report::terminal_palette();
         write!(
             writer,
-            "\u{001b}[36m[{} {}:{}] \u{001b}[0m",
+            "{}[{} {}:{}] {}",
+            palette.header,
             self.filename.display(),
             line_col_region.start.line + 1,
-            line_col_region.start.column + 1
+            line_col_region.start.column + 1,
+            palette.reset,
         )?;
 
         let expr = expressions[0];
@@ -237,6 +241,7 @@ impl<'a> Renderer<'a> {
             doc,
             filename: self.filename.clone(),
             severity,
+            region: Some(line_col_region),
         };
 
         let mut buf = String::new();
@@ -245,7 +250,7 @@ impl<'a> Renderer<'a> {
             self.render_target,
             &mut buf,
             &self.alloc,
-            &crate::report::DEFAULT_PALETTE,
+            &crate::report::terminal_palette(),
         );
 
         write!(writer, "{buf}")
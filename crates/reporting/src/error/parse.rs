@@ -3,7 +3,7 @@ use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Position, Region};
 use std::path::PathBuf;
 
-use crate::report::{Report, RocDocAllocator, RocDocBuilder};
+use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder};
 use ven_pretty::DocAllocator;
 
 pub fn parse_problem<'a>(
@@ -13,16 +13,33 @@ pub fn parse_problem<'a>(
     starting_line: u32,
     parse_problem: FileError<SyntaxError<'a>>,
 ) -> Report<'a> {
-    to_syntax_report(
+    let syntax_error = &parse_problem.problem.problem;
+    let region = syntax_error_region(syntax_error).map(|region| lines.convert_region(region));
+
+    let report = to_syntax_report(
         alloc,
         lines,
         filename,
-        &parse_problem.problem.problem,
+        syntax_error,
         lines.convert_line_column(LineColumn {
             line: starting_line,
             column: 0,
         }),
-    )
+    );
+
+    Report { region, ..report }
+}
+
+/// The region of a syntax error, for the variants that record where they happened.
+fn syntax_error_region(syntax_error: &SyntaxError) -> Option<Region> {
+    match syntax_error {
+        SyntaxError::Unexpected(region)
+        | SyntaxError::Eof(region)
+        | SyntaxError::ReservedKeyword(region)
+        | SyntaxError::ArgumentsBeforeEquals(region) => Some(*region),
+        SyntaxError::Expr(_, pos) | SyntaxError::NotEndOfFile(pos) => Some(Region::from_pos(*pos)),
+        _ => None,
+    }
 }
 
 fn note_for_record_type_indent<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<'a> {
@@ -76,6 +93,7 @@ fn to_syntax_report<'a>(
         doc,
         title: "PARSE PROBLEM".to_string(),
         severity,
+        region: None,
     };
 
     match parse_problem {
@@ -90,6 +108,7 @@ fn to_syntax_report<'a>(
                 doc,
                 title: "PARSE PROBLEM".to_string(),
                 severity,
+                region: None,
             }
         }
         Unexpected(region) => {
@@ -122,6 +141,7 @@ fn to_syntax_report<'a>(
                 doc,
                 title: "NOT END OF FILE".to_string(),
                 severity,
+                region: None,
             }
         }
         SyntaxError::Eof(region) => {
@@ -135,6 +155,7 @@ fn to_syntax_report<'a>(
                 doc,
                 title: "PARSE PROBLEM".to_string(),
                 severity,
+                region: None,
             }
         }
         SyntaxError::OutdentedTooFar => {
@@ -145,6 +166,7 @@ fn to_syntax_report<'a>(
                 doc,
                 title: "PARSE PROBLEM".to_string(),
                 severity,
+                region: None,
             }
         }
         Type(typ) => to_type_report(alloc, lines, filename, typ, Position::default()),
@@ -237,6 +259,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -355,6 +378,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "UNKNOWN OPERATOR".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -382,6 +406,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "WEIRD IDENTIFIER".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -465,6 +490,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: title.to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -493,6 +519,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "MISSING FINAL EXPRESSION".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -524,6 +551,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "SYNTAX PROBLEM".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -547,6 +575,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -565,6 +594,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "BAD BACKPASSING ARROW".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -623,6 +653,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "INDENT ENDS AFTER EXPRESSION".to_string(),
                 severity,
+                region: None,
             }
         }
         EExpr::Expect(e_expect, _position) => {
@@ -650,6 +681,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "TRAILING OPERATOR".to_string(),
                 severity,
+                region: None,
             }
         }
         EExpr::UnexpectedComma(pos) => {
@@ -667,6 +699,7 @@ fn to_expr_report<'a>(
                 doc,
                 title: "UNEXPECTED COMMA".to_string(),
                 severity,
+                region: None,
             }
         }
         EExpr::Return(EReturn::Return(pos) | EReturn::IndentReturnValue(pos), start) => {
@@ -757,6 +790,7 @@ fn to_unhandled_parse_error_report<'a>(
         doc,
         title: "UNHANDLED PARSE ERROR".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -783,6 +817,7 @@ fn to_record_report<'a>(
         doc,
         title: "RECORD PARSE PROBLEM".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -820,6 +855,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "WEIRD ARROW".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -842,6 +878,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "MISSING ARROW".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -867,6 +904,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "WEIRD ARROW".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -889,6 +927,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "MISSING ARROW".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -913,6 +952,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "UNFINISHED ARGUMENT LIST".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -934,6 +974,7 @@ fn to_lambda_report<'a>(
                     doc,
                     title: "MISSING ARROW".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -1016,6 +1057,7 @@ fn to_unfinished_lambda_report<'a>(
         doc,
         title: "UNFINISHED FUNCTION".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -1082,6 +1124,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "WEIRD ESCAPE".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::CodePtOpen(pos) | EString::CodePtEnd(pos) => {
@@ -1108,6 +1151,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "WEIRD CODE POINT".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::FormatEnd(pos) => {
@@ -1129,6 +1173,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "ENDLESS FORMAT".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::EndlessSingleQuote(pos) => {
@@ -1152,6 +1197,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "ENDLESS SCALAR".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::InvalidSingleQuote(e, pos) => {
@@ -1218,6 +1264,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "INVALID SCALAR".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::EndlessSingleLine(pos) => {
@@ -1241,6 +1288,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "ENDLESS STRING".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::ExpectedDoubleQuoteGotSingleQuote(pos) => {
@@ -1265,6 +1313,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "EXPECTED STRING".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::EndlessMultiLine(pos) => {
@@ -1288,6 +1337,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "ENDLESS STRING".to_string(),
                 severity,
+                region: None,
             }
         }
         EString::MultilineInsufficientIndent(pos) => {
@@ -1309,6 +1359,7 @@ fn to_str_report<'a>(
                 doc,
                 title: "INSUFFICIENT INDENT IN MULTI-LINE STRING".to_string(),
                 severity,
+                region: None,
             }
         }
     }
@@ -1351,6 +1402,7 @@ fn to_expr_in_parens_report<'a>(
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
         EInParens::End(pos) => {
@@ -1375,6 +1427,7 @@ fn to_expr_in_parens_report<'a>(
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
         EInParens::Open(pos) => {
@@ -1400,6 +1453,7 @@ fn to_expr_in_parens_report<'a>(
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
     }
@@ -1454,6 +1508,7 @@ fn to_list_report<'a>(
                         doc,
                         title: "UNFINISHED LIST".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -1491,6 +1546,7 @@ fn to_list_report<'a>(
                         doc,
                         title: "UNFINISHED LIST".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -1618,6 +1674,7 @@ fn to_import_report<'a>(
                 doc,
                 title: "IGNORED RECORD FIELD IN MODULE PARAMS".to_string(),
                 severity,
+                region: None,
             }
         }
         Params(EImportParams::RecordUpdateFound(region), _) => {
@@ -1635,6 +1692,7 @@ fn to_import_report<'a>(
                 doc,
                 title: "RECORD UPDATE IN MODULE PARAMS".to_string(),
                 severity,
+                region: None,
             }
         }
         Params(EImportParams::RecordBuilderFound(region), _) => {
@@ -1652,6 +1710,7 @@ fn to_import_report<'a>(
                 doc,
                 title: "RECORD BUILDER IN MODULE PARAMS".to_string(),
                 severity,
+                region: None,
             }
         }
         IndentAlias(pos) | Alias(pos) => to_unfinished_import_report(
@@ -1681,6 +1740,7 @@ fn to_import_report<'a>(
                 doc,
                 title: "LOWERCASE ALIAS".to_string(),
                 severity,
+                region: None,
             }
         }
         ExposingListStart(pos) => to_unfinished_import_report(
@@ -1716,6 +1776,7 @@ fn to_import_report<'a>(
                 doc,
                 title: "WEIRD EXPOSING".to_string(),
                 severity,
+                region: None,
             }
         }
         IndentIngestedName(pos) | IngestedName(pos) => to_unfinished_import_report(
@@ -1778,6 +1839,7 @@ fn to_unfinished_import_report<'a>(
         doc,
         title: "UNFINISHED IMPORT".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -1891,6 +1953,7 @@ fn to_unfinished_if_report<'a>(
         doc,
         title: "UNFINISHED IF".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -1928,6 +1991,7 @@ fn to_when_report<'a>(
                         doc,
                         title: "IF GUARD NO CONDITION".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => to_expr_report(
@@ -1960,6 +2024,7 @@ fn to_when_report<'a>(
                 doc,
                 title: "MISSING ARROW".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2115,6 +2180,7 @@ fn to_unfinished_when_report<'a>(
                 doc,
                 title: "UNFINISHED WHEN".to_string(),
                 severity,
+                region: None,
             }
         }
     }
@@ -2157,6 +2223,7 @@ fn to_unexpected_arrow_report<'a>(
         doc,
         title: "UNEXPECTED ARROW".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -2234,6 +2301,7 @@ fn to_pattern_report<'a>(
                 doc,
                 title: "UNFINISHED PATTERN".to_string(),
                 severity,
+                region: None,
             }
         }
         EPattern::Record(record, pos) => to_precord_report(alloc, lines, filename, record, *pos),
@@ -2244,10 +2312,35 @@ fn to_pattern_report<'a>(
         &EPattern::NumLiteral(ENumber::End, pos) => {
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
+        EPattern::AsIdentifier(pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"I am partway through parsing an "),
+                    alloc.keyword("as"),
+                    alloc.reflow(" pattern, but I got stuck here:"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("I was expecting a lowercase name for the whole value, like "),
+                    alloc.parser_suggestion("A x as whole"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNFINISHED AS PATTERN".to_string(),
+                severity,
+                region: None,
+            }
+        }
         // If you're adding or changing syntax, please handle the case with a
         // good error message above instead of adding more unhandled cases below.
         EPattern::AsKeyword(pos)
-        | EPattern::AsIdentifier(pos)
         | EPattern::Underscore(pos)
         | EPattern::NotAPattern(pos)
         | EPattern::End(pos)
@@ -2299,6 +2392,7 @@ fn to_precord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -2320,6 +2414,7 @@ fn to_precord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -2345,6 +2440,7 @@ fn to_precord_report<'a>(
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -2365,6 +2461,7 @@ fn to_precord_report<'a>(
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -2390,6 +2487,7 @@ fn to_precord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
                     severity,
+                    region: None,
                 }
             }
             Next::Other(Some(',')) => todo!(),
@@ -2415,6 +2513,7 @@ fn to_precord_report<'a>(
                     doc,
                     title: "PROBLEM IN RECORD PATTERN".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -2465,6 +2564,7 @@ fn to_plist_report<'a>(
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2487,6 +2587,7 @@ fn to_plist_report<'a>(
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2509,6 +2610,47 @@ fn to_plist_report<'a>(
                 doc,
                 title: "INCORRECT REST PATTERN".to_string(),
                 severity,
+                region: None,
+            }
+        }
+
+        PList::RestWithoutAs(pos) => {
+            let surroundings = Region::new(start, pos);
+            let name_pos = lines.convert_pos(pos);
+            let region = LineColumnRegion::from_pos(name_pos);
+
+            // Suggest the user's own line, with the `as` they left out.
+            let line = alloc
+                .src_lines
+                .get(name_pos.line as usize)
+                .copied()
+                .unwrap_or_default();
+            let (before_name, name_onwards) =
+                line.split_at((name_pos.column as usize).min(line.len()));
+            let suggestion = format!("{}as {}", before_name.trim_start(), name_onwards.trim_end());
+
+            let doc = alloc.stack([
+                alloc.reflow("It looks like you are trying to name the rest of this list, but there is no `as` before the name:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("To bind the rest of a list to a name, put "),
+                    alloc.keyword("as"),
+                    alloc.reflow(" between the "),
+                    alloc.parser_suggestion(".."),
+                    alloc.reflow(" and the name, like so:"),
+                ]),
+                alloc
+                    .string(suggestion)
+                    .annotate(Annotation::ParserSuggestion)
+                    .indent(4),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "INCORRECT REST PATTERN".to_string(),
+                severity,
+                region: None,
             }
         }
 
@@ -2554,6 +2696,7 @@ fn to_pattern_in_parens_report<'a>(
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2576,6 +2719,7 @@ fn to_pattern_in_parens_report<'a>(
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2600,6 +2744,7 @@ fn to_pattern_in_parens_report<'a>(
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2629,6 +2774,7 @@ fn to_malformed_number_literal_report<'a>(
         doc,
         title: "INVALID NUMBER LITERAL".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -2670,6 +2816,7 @@ fn to_type_report<'a>(
                         doc,
                         title: "DOUBLE COMMA".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 Next::Other(_) | Next::Keyword(_) | Next::Close(_, _) | Next::Token(_) => todo!(),
@@ -2697,6 +2844,7 @@ fn to_type_report<'a>(
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2715,6 +2863,7 @@ fn to_type_report<'a>(
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2733,6 +2882,7 @@ fn to_type_report<'a>(
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2751,6 +2901,7 @@ fn to_type_report<'a>(
                 doc,
                 title: "UNFINISHED INLINE ALIAS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2768,6 +2919,60 @@ fn to_type_report<'a>(
                 doc,
                 title: "BAD TYPE VARIABLE".to_string(),
                 severity,
+                region: None,
+            }
+        }
+
+        EType::THasInsteadOfImplements(pos) => {
+            let has_region = Region::new(*pos, pos.bump_column(3));
+            let surroundings = Region::new(start, has_region.end());
+            let region = lines.convert_region(has_region);
+
+            let doc = alloc.stack([
+                alloc.reflow(r"This opaque type lists its abilities after the `has` keyword:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Abilities are now listed after the "),
+                    alloc.keyword("implements"),
+                    alloc.reflow(" keyword instead:"),
+                ]),
+                with_implements_syntax(alloc, lines.convert_pos(*pos)),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "OLD ABILITY SYNTAX".to_string(),
+                severity,
+                region: None,
+            }
+        }
+
+        EType::THasClauseInsteadOfWhere(pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"This type annotation constrains its type variables with the old `|` syntax:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Ability constraints are now written with "),
+                    alloc.keyword("where"),
+                    alloc.reflow(" and "),
+                    alloc.keyword("implements"),
+                    alloc.reflow(" instead:"),
+                ]),
+                with_implements_syntax(alloc, lines.convert_pos(*pos)),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "OLD ABILITY SYNTAX".to_string(),
+                severity,
+                region: None,
             }
         }
         // If you're adding or changing syntax, please handle the case with a
@@ -2822,6 +3027,7 @@ fn to_trecord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -2847,6 +3053,7 @@ fn to_trecord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -2872,6 +3079,7 @@ fn to_trecord_report<'a>(
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -2892,6 +3100,7 @@ fn to_trecord_report<'a>(
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -2917,6 +3126,7 @@ fn to_trecord_report<'a>(
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
             Next::Other(Some(',')) => todo!(),
@@ -2942,6 +3152,7 @@ fn to_trecord_report<'a>(
                     doc,
                     title: "PROBLEM IN RECORD TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -2975,6 +3186,7 @@ fn to_trecord_report<'a>(
                 doc,
                 title: "UNFINISHED RECORD TYPE".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -2999,6 +3211,7 @@ fn to_trecord_report<'a>(
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 None => {
@@ -3028,6 +3241,7 @@ fn to_trecord_report<'a>(
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -3077,6 +3291,7 @@ fn to_ttag_union_report<'a>(
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
             Next::Other(Some(c)) if c.is_alphabetic() => {
@@ -3103,6 +3318,7 @@ fn to_ttag_union_report<'a>(
                     doc,
                     title: "WEIRD TAG NAME".to_string(),
                     severity,
+                    region: None,
                 }
             }
             _ => {
@@ -3128,6 +3344,7 @@ fn to_ttag_union_report<'a>(
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -3158,6 +3375,7 @@ fn to_ttag_union_report<'a>(
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -3178,6 +3396,7 @@ fn to_ttag_union_report<'a>(
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -3223,6 +3442,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 Next::Other(Some(c)) if c.is_alphabetic() => {
@@ -3245,6 +3465,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -3272,6 +3493,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -3295,6 +3517,7 @@ fn to_tinparens_report<'a>(
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3321,6 +3544,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 _ => {
@@ -3341,6 +3565,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -3369,6 +3594,7 @@ fn to_tinparens_report<'a>(
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3393,6 +3619,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
                         severity,
+                        region: None,
                     }
                 }
                 None => {
@@ -3418,6 +3645,7 @@ fn to_tinparens_report<'a>(
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
                         severity,
+                        region: None,
                     }
                 }
             }
@@ -3452,6 +3680,7 @@ fn to_tapply_report<'a>(
                 doc,
                 title: "DOUBLE DOT".to_string(),
                 severity,
+                region: None,
             }
         }
         ETypeApply::TrailingDot(pos) => {
@@ -3474,6 +3703,7 @@ fn to_tapply_report<'a>(
                 doc,
                 title: "TRAILING DOT".to_string(),
                 severity,
+                region: None,
             }
         }
         ETypeApply::StartIsNumber(pos) => {
@@ -3496,6 +3726,7 @@ fn to_tapply_report<'a>(
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
                 severity,
+                region: None,
             }
         }
         ETypeApply::StartNotUppercase(pos) => {
@@ -3518,6 +3749,7 @@ fn to_tapply_report<'a>(
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3536,6 +3768,7 @@ fn to_tapply_report<'a>(
                 doc,
                 title: "END OF FILE".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3577,6 +3810,7 @@ fn to_talias_report<'a>(
                 doc,
                 title: "NOT AN INLINE ALIAS".to_string(),
                 severity,
+                region: None,
             }
         }
         ETypeInlineAlias::Qualified(pos) => {
@@ -3593,6 +3827,7 @@ fn to_talias_report<'a>(
                 doc,
                 title: "QUALIFIED ALIAS NAME".to_string(),
                 severity,
+                region: None,
             }
         }
         ETypeInlineAlias::ArgumentNotLowercase(pos) => {
@@ -3609,6 +3844,7 @@ fn to_talias_report<'a>(
                 doc,
                 title: "TYPE ARGUMENT NOT LOWERCASE".to_string(),
                 severity,
+                region: None,
             }
         }
     }
@@ -3659,6 +3895,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "INCOMPLETE HEADER".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3701,6 +3938,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "MISSING HEADER".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3725,6 +3963,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3752,6 +3991,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3776,6 +4016,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "WEIRD APP NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3798,6 +4039,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "INVALID PACKAGE NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3821,6 +4063,7 @@ fn to_header_report<'a>(
                 doc,
                 title: "INVALID PLATFORM NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3862,6 +4105,7 @@ fn to_provides_report<'a>(
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3887,6 +4131,7 @@ fn to_provides_report<'a>(
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3914,6 +4159,7 @@ fn to_provides_report<'a>(
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -3935,6 +4181,7 @@ fn to_provides_report<'a>(
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
                 severity,
+                region: None,
             }
         }
         // If you're adding or changing syntax, please handle the case with a
@@ -3985,6 +4232,7 @@ fn to_params_report<'a>(
                 doc,
                 title: "WEIRD MODULE PARAMS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4025,6 +4273,7 @@ fn to_exposes_report<'a>(
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4050,6 +4299,7 @@ fn to_exposes_report<'a>(
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4097,6 +4347,7 @@ fn to_imports_report<'a>(
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4122,6 +4373,7 @@ fn to_imports_report<'a>(
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4148,6 +4400,7 @@ fn to_imports_report<'a>(
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4167,6 +4420,7 @@ fn to_imports_report<'a>(
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
                 severity,
+                region: None,
             }
         }
         // If you're adding or changing syntax, please handle the case with a
@@ -4228,6 +4482,7 @@ fn to_requires_report<'a>(
                 doc,
                 title: "MISSING REQUIRES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4255,6 +4510,7 @@ fn to_requires_report<'a>(
                 doc,
                 title: "MISSING REQUIRES".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4284,6 +4540,7 @@ fn to_requires_report<'a>(
                 doc,
                 title: "BAD REQUIRES RIGIDS".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4313,6 +4570,7 @@ fn to_requires_report<'a>(
                 doc,
                 title: "BAD REQUIRES".to_string(),
                 severity,
+                region: None,
             }
         }
         // If you're adding or changing syntax, please handle the case with a
@@ -4362,6 +4620,7 @@ fn to_packages_report<'a>(
                 doc,
                 title: "MISSING PACKAGES".to_string(),
                 severity,
+                region: None,
             }
         }
         EPackages::ListEnd(pos) => {
@@ -4384,6 +4643,7 @@ fn to_packages_report<'a>(
                 doc,
                 title: "WEIRD PACKAGES LIST".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4435,6 +4695,7 @@ fn to_space_report<'a>(
                 doc,
                 title: "TAB CHARACTER".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4452,6 +4713,7 @@ fn to_space_report<'a>(
                 doc,
                 title: "ASCII CONTROL CHARACTER".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4469,6 +4731,7 @@ fn to_space_report<'a>(
                 doc,
                 title: "MISPLACED CARRIAGE RETURN".to_string(),
                 severity,
+                region: None,
             }
         }
 
@@ -4534,6 +4797,37 @@ fn to_ability_def_report<'a>(
                 alloc.reflow(" annotating the signature of this value next."),
             ]),
         ),
+        EAbility::HasInsteadOfImplements(pos) => {
+            let has_region = Region::new(*pos, pos.bump_column(3));
+            let surroundings = Region::new(start, has_region.end());
+            let region = lines.convert_region(has_region);
+            let severity = Severity::RuntimeError;
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"This looks like an ability definition, but it uses the `has` keyword:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow("Abilities are now defined with the "),
+                    alloc.keyword("implements"),
+                    alloc.reflow(" keyword instead, and ability constraints are written with "),
+                    alloc.keyword("where"),
+                    alloc.reflow(" and "),
+                    alloc.keyword("implements"),
+                    alloc.reflow(" as well:"),
+                ]),
+                with_implements_syntax(alloc, lines.convert_pos(*pos)),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "OLD ABILITY SYNTAX".to_string(),
+                severity,
+                region: None,
+            }
+        }
     }
 }
 
@@ -4560,6 +4854,7 @@ fn to_unfinished_ability_report<'a>(
         doc,
         title: "UNFINISHED ABILITY".to_string(),
         severity,
+        region: None,
     }
 }
 
@@ -4647,6 +4942,42 @@ fn next_line_starts_with_char(
     }
 }
 
+/// The user's code on the line at `pos`, rewritten from the old `has` and `|` ability
+/// syntax to `implements` and `where`. Lines below it that are indented further,
+/// like the demands of an ability, are rewritten along with it.
+fn with_implements_syntax<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    pos: LineColumn,
+) -> RocDocBuilder<'a> {
+    fn indentation(line: &str) -> usize {
+        line.len() - line.trim_start().len()
+    }
+
+    let mut src_lines = alloc.src_lines.iter().skip(pos.line as usize);
+    let first_line = src_lines.next().copied().unwrap_or_default();
+    let base_indent = indentation(first_line);
+
+    let following_lines =
+        src_lines.take_while(|line| !line.trim().is_empty() && indentation(line) > base_indent);
+
+    let rewritten = std::iter::once(first_line)
+        .chain(following_lines.copied())
+        .map(|line| {
+            let words = line[base_indent..]
+                .trim_end()
+                .split(' ')
+                .map(|word| match word {
+                    "has" => "implements",
+                    "|" => "where",
+                    _ => word,
+                });
+
+            alloc.text(words.collect::<Vec<_>>().join(" "))
+        });
+
+    alloc.vcat(rewritten).indent(4)
+}
+
 fn to_keyword_region(pos: LineColumn, keyword: &str) -> LineColumnRegion {
     LineColumnRegion {
         start: pos,
@@ -16,9 +16,11 @@ use roc_module::symbol::Symbol;
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::{
-    NotDerivableContext, NotDerivableEq, TypeError, UnderivableReason, Unfulfilled,
+    NotDerivableContext, NotDerivableEq, NotDerivableSort, TypeError, UnderivableReason,
+    Unfulfilled,
 };
 use roc_std::RocDec;
+use roc_types::num::IntLitWidth;
 use roc_types::pretty_print::{Parens, WILDCARD};
 use roc_types::types::{
     AbilitySet, AliasKind, Category, ErrorType, IndexOrField, PatternCategory, Polarity, Reason,
@@ -44,6 +46,7 @@ pub fn type_problem<'b>(
     use TypeError::*;
 
     let severity = problem.severity();
+    let region = problem.region().map(|region| lines.convert_region(region));
 
     let report =
         move |title: String, doc: RocDocBuilder<'b>, filename: PathBuf| -> Option<Report<'b>> {
@@ -52,10 +55,11 @@ pub fn type_problem<'b>(
                 filename,
                 doc,
                 severity,
+                region: None,
             })
         };
 
-    match problem {
+    let opt_report = match problem {
         BadExpr(region, category, found, expected) => Some(to_expr_report(
             alloc, lines, filename, severity, region, category, found, expected,
         )),
@@ -113,6 +117,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             };
             Some(report)
         }
@@ -135,6 +140,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             };
             Some(report)
         }
@@ -148,6 +154,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc,
                 severity,
+                region: None,
             })
         }
         StructuralSpecialization {
@@ -178,6 +185,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         WrongSpecialization {
@@ -207,6 +215,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         IngestedFileBadUtf8(file_path, utf8_err) => {
@@ -223,6 +232,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         IngestedFileUnsupportedType(file_path, typ) => {
@@ -246,6 +256,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         UnexpectedModuleParams(region, module_id) => {
@@ -266,6 +277,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         MissingModuleParams(region, module_id, expected) => {
@@ -288,6 +300,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         ModuleParamsMismatch(region, module_id, actual_type, expected_type) => {
@@ -313,6 +326,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         FxInPureFunction(fx_call_region, fx_call_kind, ann_region) => {
@@ -346,6 +360,7 @@ pub fn type_problem<'b>(
                 title: "EFFECT IN PURE FUNCTION".to_string(),
                 doc: alloc.stack(lines),
                 severity,
+                region: None,
             })
         }
         FxInTopLevel(call_region, fx_call_kind) => {
@@ -366,6 +381,7 @@ pub fn type_problem<'b>(
                 title: "EFFECT IN TOP-LEVEL".to_string(),
                 doc: alloc.stack(lines),
                 severity,
+                region: None,
             })
         }
         ExpectedEffectful(region, ExpectEffectfulReason::Stmt) => {
@@ -382,6 +398,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         ExpectedEffectful(region, ExpectEffectfulReason::Ignored) => {
@@ -396,6 +413,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         UnsuffixedEffectfulFunction(
@@ -425,6 +443,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         UnsuffixedEffectfulFunction(region, FxSuffixKind::UnsuffixedRecordField) => {
@@ -444,6 +463,7 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
         SuffixedPureFunction(region, kind) => {
@@ -470,9 +490,31 @@ pub fn type_problem<'b>(
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                region: None,
             })
         }
-    }
+        TypeHole(region, typ) => {
+            let stack = [
+                alloc.reflow("I inferred the type of this hole in an annotation:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.reflow("It is:"),
+                alloc.type_block(error_type_to_doc(alloc, typ)),
+                alloc.reflow(
+                    "You can replace the hole with this type, or keep the hole if you'd like it to stay inferred.",
+                ),
+            ];
+
+            Some(Report {
+                title: "INFERRED TYPE HOLE".to_string(),
+                filename,
+                doc: alloc.stack(stack),
+                severity,
+                region: None,
+            })
+        }
+    };
+
+    opt_report.map(|report| Report { region, ..report })
 }
 
 fn report_unfulfilled_ability<'a>(
@@ -662,6 +704,28 @@ fn underivable_hint<'b>(
                 ])))
             }
         },
+        NotDerivableContext::Sort(reason) => match reason {
+            NotDerivableSort::FloatingPoint => {
+                Some(alloc.note("").append(alloc.concat([
+                    alloc.reflow("I can't derive "),
+                    alloc.symbol_qualified(Symbol::SORT_COMPARE),
+                    alloc.reflow(" for floating-point types. That's because Roc's floating-point numbers do not have a total order - in Roc, `NaN` is neither less than, greater than, nor equal to any other number."),
+                    alloc.reflow(" If a type doesn't have a total order, it cannot support the "),
+                    alloc.symbol_unqualified(Symbol::SORT_SORT_ABILITY),
+                    alloc.reflow(" ability!"),
+                ])))
+            }
+            NotDerivableSort::OptionalRecordField(field) => {
+                Some(alloc.note("").append(alloc.concat([
+                    alloc.reflow("I can't derive "),
+                    alloc.symbol_qualified(Symbol::SORT_COMPARE),
+                    alloc.reflow(" for a record with an optional field, which in this case is "),
+                    alloc.record_field(field),
+                    alloc.reflow(". Default value record fields are polymorphic over records that may or may not contain them at compile time, "),
+                    alloc.reflow("but are not a concept that extends to runtime!"),
+                ])))
+            }
+        },
     }
 }
 
@@ -766,6 +830,7 @@ fn report_mismatch<'b>(
         filename,
         doc: alloc.stack(lines),
         severity,
+        region: None,
     }
 }
 
@@ -810,6 +875,7 @@ fn report_bad_type<'b>(
         filename,
         doc: alloc.stack(lines),
         severity,
+        region: None,
     }
 }
 
@@ -820,6 +886,8 @@ fn pattern_to_doc<'b>(
     use roc_can::pattern::Pattern::*;
 
     match pattern {
+        // inline type annotations desugar to a def with a generated name
+        Identifier(symbol) if symbol.is_generated(alloc.interns) => None,
         Identifier(symbol) => Some(alloc.symbol_unqualified(*symbol)),
         _ => None,
     }
@@ -897,6 +965,7 @@ fn to_expr_report<'b>(
                     comparison,
                 ]),
                 severity,
+                region: None,
             }
         }
         Expected::FromAnnotation(name, _arity, annotation_source, expected_type) => {
@@ -1048,6 +1117,7 @@ fn to_expr_report<'b>(
                     comparison,
                 ]),
                 severity,
+                region: None,
             }
         }
         Expected::ForReason(reason, expected_type, region) => match reason {
@@ -1432,6 +1502,7 @@ fn to_expr_report<'b>(
                         title: "TOO MANY ARGS".to_string(),
                         doc,
                         severity,
+                        region: None,
                     }
                 }
                 DescribedFunction::Arguments(n) => {
@@ -1467,6 +1538,7 @@ fn to_expr_report<'b>(
                             title: "TOO MANY ARGS".to_string(),
                             doc: alloc.stack(lines),
                             severity,
+                            region: None,
                         }
                     } else {
                         let lines = vec![
@@ -1494,6 +1566,7 @@ fn to_expr_report<'b>(
                             title: "TOO FEW ARGS".to_string(),
                             doc: alloc.stack(lines),
                             severity,
+                            region: None,
                         }
                     }
                 }
@@ -1696,6 +1769,7 @@ fn to_expr_report<'b>(
                     filename,
                     doc: alloc.stack(lines),
                     severity,
+                    region: None,
                 }
             }
 
@@ -1733,6 +1807,7 @@ fn to_expr_report<'b>(
                     title: "TYPE MISMATCH".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
 
@@ -1769,6 +1844,7 @@ fn to_expr_report<'b>(
                     title: "TYPE MISMATCH".to_string(),
                     doc: alloc.stack(lines),
                     severity,
+                    region: None,
                 }
             }
 
@@ -1836,6 +1912,7 @@ fn to_expr_report<'b>(
                         comparison,
                     ]),
                     severity,
+                    region: None,
                 }
             }
 
@@ -1876,6 +1953,7 @@ fn to_expr_report<'b>(
                     title: "IGNORED RESULT".to_string(),
                     doc: alloc.stack(lines),
                     severity,
+                    region: None,
                 }
             }
         },
@@ -2264,6 +2342,7 @@ fn to_pattern_report<'b>(
                 title: "TYPE MISMATCH".to_string(),
                 doc,
                 severity,
+                region: None,
             }
         }
 
@@ -2307,6 +2386,7 @@ fn to_pattern_report<'b>(
                     title: "TYPE MISMATCH".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
             PReason::WhenMatch { index, sub_pattern } => {
@@ -2341,13 +2421,13 @@ fn to_pattern_report<'b>(
                         ),
                     ]),
                     (index, sub_pattern) => {
-                        let (first, index) = match sub_pattern {
+                        let (first, index, previous) = match sub_pattern {
                             HumanIndex::FIRST => {
                                 let doc = alloc
                                     .string(format!("The {} pattern in this ", index.ordinal()))
                                     .append(alloc.keyword("when"))
                                     .append(alloc.text(" does not match the previous ones:"));
-                                (doc, index)
+                                (doc, index, "But all the previous branches match:")
                             }
 
                             _ => {
@@ -2355,7 +2435,11 @@ fn to_pattern_report<'b>(
                                     "The {} pattern in this branch does not match the previous ones:",
                                     sub_pattern.ordinal()
                                 ));
-                                (doc, sub_pattern)
+                                (
+                                    doc,
+                                    sub_pattern,
+                                    "But all the previous patterns in this branch match:",
+                                )
                             }
                         };
 
@@ -2378,7 +2462,7 @@ fn to_pattern_report<'b>(
                                     )),
                                     &category,
                                 ),
-                                alloc.text("But all the previous branches match:"),
+                                alloc.text(previous),
                                 vec![],
                             ),
                         ])
@@ -2389,6 +2473,7 @@ fn to_pattern_report<'b>(
                     title: "TYPE MISMATCH".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
             PReason::ListElem => {
@@ -2416,6 +2501,7 @@ fn to_pattern_report<'b>(
                     title: "TYPE MISMATCH".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
             PReason::TagArg { .. } | PReason::PatternGuard => {
@@ -2517,23 +2603,42 @@ fn to_circular_report<'b>(
             ])
         },
         severity,
+        region: None,
     }
 }
 
 #[derive(Debug, Clone)]
 pub enum Problem {
     IntFloat,
+    NumberDoesNotFit(IntLitWidth),
     ArityMismatch(usize, usize),
     FieldTypo(Lowercase, Vec<Lowercase>),
     FieldsMissing(Vec<Lowercase>),
     TagTypo(TagName, Vec<TagName>),
     TagsMissing(Vec<TagName>),
+    /// Tags that were used, but that a closed tag union does not have, along with the tags it
+    /// does have that are not already accounted for.
+    TagsNotInClosedUnion(Vec<TagName>, Vec<TagName>),
     BadRigidVar(Lowercase, ErrorType, Option<AbilitySet>),
     OptionalRequiredMismatch(Lowercase),
     OpaqueComparedToNonOpaque,
     BoolVsBoolTag(TagName),
 }
 
+/// The integer width a number literal would need to fit in, if this is a concrete integer type
+fn int_lit_width(typ: &ErrorType) -> Option<IntLitWidth> {
+    use IntLitWidth::*;
+
+    let symbol = match typ {
+        ErrorType::Type(symbol, _) | ErrorType::Alias(symbol, _, _, _) => *symbol,
+        _ => return None,
+    };
+
+    [U8, U16, U32, U64, U128, I8, I16, I32, I64, I128]
+        .into_iter()
+        .find(|width| width.symbol() == symbol)
+}
+
 fn problems_to_tip<'b>(
     alloc: &'b RocDocAllocator<'b>,
     mut problems: Vec<Problem>,
@@ -2600,6 +2705,17 @@ pub mod suggest {
 
         options
     }
+
+    /// The option closest to the typo, as long as it's within `max_distance` edits of it.
+    pub fn closest<T>(typo: &str, options: Vec<T>, max_distance: usize) -> Option<T>
+    where
+        T: ToStr,
+    {
+        sort(typo, options)
+            .into_iter()
+            .next()
+            .filter(|option| distance::damerau_levenshtein(typo, option.to_str()) <= max_distance)
+    }
 }
 
 pub struct Comparison<'b> {
@@ -3378,6 +3494,12 @@ fn to_diff<'b>(
                 (a, b) if (is_int(&a) && is_float(&b)) || (is_float(&a) && is_int(&b)) => {
                     vec![Problem::IntFloat]
                 }
+                (ErrorType::Range(_), other) | (other, ErrorType::Range(_)) => {
+                    match int_lit_width(&other) {
+                        Some(width) => vec![Problem::NumberDoesNotFit(width)],
+                        None => vec![],
+                    }
+                }
                 _ => vec![],
             };
 
@@ -4047,10 +4169,20 @@ fn diff_tag_union<'b>(
             // At least one tag appeared only on the left, and also
             // at least one tag appeared only on the right. There's a chance this is
             // because of a typo, so we'll suggest that as a hint.
-            (Some((f, _, _, _)), Some(_)) => Status::Different(vec![Problem::TagTypo(
-                f.clone(),
-                tags_in_right_only.keys().cloned().collect(),
-            )]),
+            (Some((f, _, _, _)), Some(_)) => {
+                let possibilities = tags_in_right_only.keys().cloned().collect();
+
+                if matches!(ext2, TypeExt::Closed) {
+                    // The tags on the left can never be added to the closed union on the right,
+                    // so name them explicitly rather than only guessing at a typo.
+                    Status::Different(vec![Problem::TagsNotInClosedUnion(
+                        left.clone().map(|v| v.0).collect(),
+                        possibilities,
+                    )])
+                } else {
+                    Status::Different(vec![Problem::TagTypo(f.clone(), possibilities)])
+                }
+            }
             // At least one tag appeared only on the left, but all of the tags
             // on the right also appeared on the left. So at least one tag is missing.
             (Some(_), None) => Status::Different(vec![Problem::TagsMissing(
@@ -4695,6 +4827,27 @@ fn list_abilities<'a>(alloc: &'a RocDocAllocator<'a>, abilities: &AbilitySet) ->
     }
 }
 
+/// Tips for a tag that a closed tag union doesn't have. If an annotation closed the union,
+/// it was closed on purpose, so we don't suggest opening it.
+fn closed_union_tips<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    tip: RocDocBuilder<'b>,
+    expectation_context: &ExpectationContext<'b>,
+) -> RocDocBuilder<'b> {
+    match expectation_context {
+        ExpectationContext::Annotation { .. } => tip,
+        ExpectationContext::WhenCondition | ExpectationContext::Arbitrary => {
+            let cannot_grow = alloc.tip().append(alloc.reflow(
+                "Closed tag unions can't grow, \
+                because that might change the size in memory. \
+                Can you use an open tag union?",
+            ));
+
+            alloc.stack([tip, cannot_grow])
+        }
+    }
+}
+
 fn type_problem_to_pretty<'b>(
     alloc: &'b RocDocAllocator<'b>,
     problem: crate::error::r#type::Problem,
@@ -4780,6 +4933,47 @@ fn type_problem_to_pretty<'b>(
                 }
             }
         }
+        (TagsNotInClosedUnion(extra, possibilities), context) => match extra.split_last() {
+            None => alloc.nil(),
+            Some(split) => {
+                let extra_tags = match split {
+                    (f1, []) => alloc.tag_name(f1.clone()).append(alloc.reflow(" tag.")),
+                    (last, init) => alloc
+                        .intersperse(init.iter().map(|v| alloc.tag_name(v.clone())), ", ")
+                        .append(alloc.reflow(" and "))
+                        .append(alloc.tag_name(last.clone()))
+                        .append(alloc.reflow(" tags.")),
+                };
+
+                let mut tip1 = alloc
+                    .tip()
+                    .append(alloc.reflow("Looks like a closed tag union does not have the "))
+                    .append(extra_tags);
+
+                let typo = &extra[0];
+                let typo_str = format!("{}", typo.as_ident_str());
+                let possibilities: Vec<IdentStr> = possibilities
+                    .into_iter()
+                    .map(|tag_name| tag_name.as_ident_str())
+                    .collect();
+
+                if let Some(nearest) = suggest::sort(&typo_str, possibilities).first() {
+                    let found = alloc.text(typo_str).annotate(Annotation::Typo);
+                    let suggestion = alloc
+                        .text(format!("{nearest}"))
+                        .annotate(Annotation::TypoSuggestion);
+
+                    tip1 = tip1
+                        .append(alloc.reflow(" Maybe "))
+                        .append(found)
+                        .append(alloc.reflow(" should be "))
+                        .append(suggestion)
+                        .append(alloc.text("?"));
+                }
+
+                closed_union_tips(alloc, tip1, &context)
+            }
+        },
         (ArityMismatch(found, expected), _) => {
             let line = if found < expected {
                 format!(
@@ -5001,6 +5195,15 @@ fn type_problem_to_pretty<'b>(
             }
         }
 
+        (NumberDoesNotFit(width), _) => alloc.tip().append(alloc.concat([
+            alloc.reflow("A "),
+            alloc.symbol_unqualified(width.symbol()),
+            alloc.reflow(" can only hold whole numbers from "),
+            alloc.text(width.min_value().to_string()),
+            alloc.reflow(" to "),
+            alloc.text(width.max_value().to_string()),
+            alloc.reflow(", so this number doesn't fit in one."),
+        ])),
         (IntFloat, _) => {
             alloc.tip().append(alloc.concat(
                 [
@@ -5042,7 +5245,7 @@ fn type_problem_to_pretty<'b>(
             }
         },
 
-        (TagsMissing(missing), _) => match missing.split_last() {
+        (TagsMissing(missing), context) => match missing.split_last() {
             None => alloc.nil(),
             Some((f1, [])) => {
                 let tip1 = alloc
@@ -5051,13 +5254,7 @@ fn type_problem_to_pretty<'b>(
                     .append(alloc.tag_name(f1.clone()))
                     .append(alloc.reflow(" tag."));
 
-                let tip2 = alloc.tip().append(alloc.reflow(
-                    "Closed tag unions can't grow, \
-                    because that might change the size in memory. \
-                    Can you use an open tag union?",
-                ));
-
-                alloc.stack([tip1, tip2])
+                closed_union_tips(alloc, tip1, &context)
             }
 
             Some((last, init)) => {
@@ -5074,13 +5271,7 @@ fn type_problem_to_pretty<'b>(
                     .append(alloc.tag_name(last.clone()))
                     .append(alloc.reflow(" tags."));
 
-                let tip2 = alloc.tip().append(alloc.reflow(
-                    "Closed tag unions can't grow, \
-                    because that might change the size in memory. \
-                    Can you use an open tag union?",
-                ));
-
-                alloc.stack([tip1, tip2])
+                closed_union_tips(alloc, tip1, &context)
             }
         },
         (OptionalRequiredMismatch(field), _) => alloc.tip().append(alloc.concat([
@@ -5200,6 +5391,7 @@ fn report_record_field_typo<'b>(
         title: "TYPE MISMATCH".to_string(),
         doc,
         severity,
+        region: None,
     }
 }
 
@@ -5237,6 +5429,7 @@ fn exhaustive_problem<'a>(
                     title: "UNSAFE PATTERN".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
             BadDestruct => {
@@ -5261,6 +5454,7 @@ fn exhaustive_problem<'a>(
                     title: "UNSAFE PATTERN".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
             BadCase => {
@@ -5285,6 +5479,7 @@ fn exhaustive_problem<'a>(
                     title: "UNSAFE PATTERN".to_string(),
                     doc,
                     severity,
+                    region: None,
                 }
             }
         },
@@ -5315,6 +5510,7 @@ fn exhaustive_problem<'a>(
                 title: "REDUNDANT PATTERN".to_string(),
                 doc,
                 severity,
+                region: None,
             }
         }
         Unmatchable {
@@ -5344,6 +5540,7 @@ fn exhaustive_problem<'a>(
                 title: "UNMATCHABLE PATTERN".to_string(),
                 doc,
                 severity,
+                region: None,
             }
         }
     }
@@ -5388,6 +5585,11 @@ fn pattern_to_doc_help<'b>(
         Anything => alloc.text("_"),
         Literal(l) => match l {
             Int(i) => alloc.text(i128::from_ne_bytes(i).to_string()),
+            IntRange { start, end, .. } => alloc.text(format!(
+                "{}..{}",
+                i128::from_ne_bytes(start),
+                i128::from_ne_bytes(end)
+            )),
             U128(i) => alloc.text(u128::from_ne_bytes(i).to_string()),
             Bit(true) => alloc.text("Bool.true"),
             Bit(false) => alloc.text("Bool.false"),
@@ -5407,7 +5609,7 @@ fn pattern_to_doc_help<'b>(
                 ListArity::Slice(num_before, num_after) => {
                     let mut all_patterns = patterns
                         .into_iter()
-                        .map(|p| pattern_to_doc_help(alloc, p, in_type_param));
+                        .map(|p| pattern_to_doc_help(alloc, p, false));
 
                     let spread = alloc.text("..");
                     let comma_space = alloc.text(",").append(alloc.space());
@@ -2,8 +2,10 @@ use roc_module::ident::Ident;
 use roc_module::ident::{Lowercase, ModuleName, TagName, Uppercase};
 use roc_module::symbol::{Interns, ModuleId, ModuleIds, PQModuleName, PackageQualified, Symbol};
 use roc_problem::Severity;
-use roc_region::all::LineColumnRegion;
+use roc_region::all::{LineColumn, LineColumnRegion};
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::{fmt, io};
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
 
@@ -116,6 +118,7 @@ pub enum RenderTarget {
     ColorTerminal,
     Generic,
     LanguageServer,
+    Json,
 }
 
 /// A textual report.
@@ -124,6 +127,8 @@ pub struct Report<'b> {
     pub filename: PathBuf,
     pub doc: RocDocBuilder<'b>,
     pub severity: Severity,
+    /// The main location this report is about, if it has one.
+    pub region: Option<LineColumnRegion>,
 }
 
 impl<'b> Report<'b> {
@@ -138,6 +143,7 @@ impl<'b> Report<'b> {
             RenderTarget::Generic => self.render_ci(buf, alloc),
             RenderTarget::ColorTerminal => self.render_color_terminal(buf, alloc, palette),
             RenderTarget::LanguageServer => self.render_language_server(buf, alloc),
+            RenderTarget::Json => self.render_json(buf, alloc),
         }
     }
 
@@ -193,17 +199,93 @@ impl<'b> Report<'b> {
             .expect(err_msg)
     }
 
+    /// Render as one line of JSON, for editors and CI tools that annotate problems
+    /// themselves instead of parsing terminal output.
+    ///
+    /// Every source snippet the report shows becomes a label, with the paragraph
+    /// leading up to the snippet as its message. Reports that don't set a `region`
+    /// use the first label's region as their primary one.
+    pub fn render_json(self, buf: &mut String, alloc: &'b RocDocAllocator<'b>) {
+        let err_msg = "<buffer is not a utf-8 encoded string>";
+
+        let severity = match self.severity {
+            Severity::Fatal => "fatal",
+            Severity::RuntimeError => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+
+        let mut message = String::new();
+        let mut writer = JsonWrite::new(&mut message);
+
+        self.doc.1.render_raw(70, &mut writer).expect(err_msg);
+
+        let labels = writer.labels;
+        let region = self
+            .region
+            .or_else(|| labels.first().map(|(_, region)| *region));
+        let labels_json: Vec<serde_json::Value> = labels
+            .into_iter()
+            .map(|(label, region)| {
+                serde_json::json!({
+                    "message": label,
+                    "region": region_to_json(alloc, region),
+                })
+            })
+            .collect();
+
+        let json = serde_json::json!({
+            "severity": severity,
+            "code": report_code(&self.title),
+            "title": self.title,
+            "filename": self.filename.to_string_lossy(),
+            "region": region.map(|region| region_to_json(alloc, region)),
+            "labels": labels_json,
+            "message": message.trim_end(),
+        });
+
+        buf.push_str(&json.to_string());
+    }
+
     pub fn horizontal_rule(palette: &'b Palette) -> String {
         format!("{}{}", palette.header, "─".repeat(80))
     }
 }
 
+/// A stable identifier for a kind of report, derived from its title,
+/// e.g. `TYPE_MISMATCH` for a "TYPE MISMATCH" report.
+fn report_code(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_ascii_uppercase()
+}
+
+/// Lines and columns are 1-based, like the line numbers in terminal reports.
+/// Offsets are 0-based byte offsets into the source file.
+fn region_to_json(alloc: &RocDocAllocator<'_>, region: LineColumnRegion) -> serde_json::Value {
+    let position = |line_column: LineColumn| {
+        serde_json::json!({
+            "line": line_column.line + 1,
+            "column": line_column.column + 1,
+            "offset": alloc.byte_offset(line_column),
+        })
+    };
+
+    serde_json::json!({
+        "start": position(region.start),
+        "end": position(region.end),
+    })
+}
+
 /// This struct is a combination of several things
 /// 1. A set of StyleCodes suitable for the environment we're running in (web or terminal)
 /// 2. A set of colors we decided to use
 /// 3. A mapping from UI elements to the styles we use for them
 /// Note: This should really be called Theme! Usually a "palette" is just (2).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Palette {
     pub primary: &'static str,
     pub code_block: &'static str,
@@ -227,6 +309,7 @@ pub struct Palette {
     pub underline: &'static str,
     pub reset: &'static str,
     pub warning: &'static str,
+    pub success: &'static str,
 }
 
 /// Set the default styles for various semantic elements,
@@ -255,6 +338,22 @@ const fn default_palette_from_style_codes(codes: StyleCodes) -> Palette {
         underline: codes.underline,
         reset: codes.reset,
         warning: codes.yellow,
+        success: codes.green,
+    }
+}
+
+/// Like the default palette, but without relying on telling red and green apart:
+/// green elements become cyan, and errors are magenta instead of red.
+const fn colorblind_palette_from_style_codes(codes: StyleCodes) -> Palette {
+    Palette {
+        keyword: codes.cyan,
+        ellipsis: codes.cyan,
+        structure: codes.cyan,
+        error: codes.magenta,
+        module_name: codes.cyan,
+        binop: codes.cyan,
+        success: codes.cyan,
+        ..default_palette_from_style_codes(codes)
     }
 }
 
@@ -284,6 +383,7 @@ const fn no_color_palette_from_style_codes(codes: StyleCodes) -> Palette {
         underline: codes.no_color,
         reset: codes.no_color,
         warning: codes.no_color,
+        success: codes.no_color,
     }
 }
 
@@ -295,12 +395,100 @@ pub const NO_COLOR_PALETTE: Palette = no_color_palette_from_style_codes(ANSI_STY
 
 pub const NO_COLOR_PALETTE_HTML: Palette = no_color_palette_from_style_codes(HTML_STYLE_CODES);
 
+pub const COLORBLIND_PALETTE: Palette = colorblind_palette_from_style_codes(ANSI_STYLE_CODES);
+
+/// Whether to color terminal output, as chosen by `--color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    Always,
+    Never,
+    /// Color only when stdout is a terminal, respecting the `NO_COLOR`
+    /// and `ROC_PALETTE` environment variables.
+    Auto,
+}
+
+static COLOR_CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+/// Override the environment when picking a [terminal_palette].
+/// Only the first call has any effect.
+pub fn set_color_choice(choice: ColorChoice) {
+    let _ = COLOR_CHOICE.set(choice);
+}
+
+/// How the CLI prints reports, as chosen by `--error-format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Human,
+    /// One JSON object per report, on its own line.
+    Json,
+}
+
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Pick what [terminal_render_target] returns.
+/// Only the first call has any effect.
+pub fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+/// How to render reports printed by the CLI.
+pub fn terminal_render_target() -> RenderTarget {
+    match ERROR_FORMAT.get() {
+        Some(ErrorFormat::Json) => RenderTarget::Json,
+        Some(ErrorFormat::Human) | None => RenderTarget::ColorTerminal,
+    }
+}
+
+/// The palette for terminal output, chosen by `--color` or else from the environment:
+/// output that isn't going to a terminal is not colored,
+/// `NO_COLOR` (see <https://no-color.org>) turns colors off entirely,
+/// and `ROC_PALETTE=colorblind` picks a palette that doesn't depend on red vs. green.
+pub fn terminal_palette() -> Palette {
+    use std::io::IsTerminal;
+
+    let choice = COLOR_CHOICE.get().copied().unwrap_or(ColorChoice::Auto);
+    let no_color = std::env::var_os("NO_COLOR");
+    let roc_palette = std::env::var("ROC_PALETTE").ok();
+
+    palette_for(
+        choice,
+        std::io::stdout().is_terminal(),
+        no_color.as_deref(),
+        roc_palette.as_deref(),
+    )
+}
+
+fn palette_for(
+    choice: ColorChoice,
+    stdout_is_terminal: bool,
+    no_color: Option<&OsStr>,
+    roc_palette: Option<&str>,
+) -> Palette {
+    let colorblind = roc_palette == Some("colorblind");
+
+    match choice {
+        ColorChoice::Never => NO_COLOR_PALETTE,
+        ColorChoice::Always if colorblind => COLORBLIND_PALETTE,
+        ColorChoice::Always => DEFAULT_PALETTE,
+        ColorChoice::Auto => {
+            if !stdout_is_terminal || no_color.map_or(false, |val| !val.is_empty()) {
+                NO_COLOR_PALETTE
+            } else if colorblind {
+                COLORBLIND_PALETTE
+            } else {
+                DEFAULT_PALETTE
+            }
+        }
+    }
+}
+
 /// A machine-readable format for text styles (colors and other styles)
 #[derive(Debug, PartialEq)]
 pub struct StyleCodes {
     pub red: &'static str,
     pub green: &'static str,
     pub yellow: &'static str,
+    pub magenta: &'static str,
     pub cyan: &'static str,
     pub white: &'static str,
     pub bold: &'static str,
@@ -313,6 +501,7 @@ pub const ANSI_STYLE_CODES: StyleCodes = StyleCodes {
     red: "\u{001b}[1;31m",
     green: "\u{001b}[1;32m",
     yellow: "\u{001b}[1;33m",
+    magenta: "\u{001b}[1;35m",
     cyan: "\u{001b}[1;36m",
     white: "\u{001b}[37m",
     bold: "\u{001b}[1m",
@@ -331,6 +520,7 @@ pub const HTML_STYLE_CODES: StyleCodes = StyleCodes {
     red: html_color!("red"),
     green: html_color!("green"),
     yellow: html_color!("yellow"),
+    magenta: html_color!("magenta"),
     cyan: html_color!("cyan"),
     white: html_color!("white"),
     bold: "<span class='bold'>",
@@ -344,6 +534,7 @@ pub fn strip_colors(str: &str) -> String {
     str.replace(ANSI_STYLE_CODES.red, "")
         .replace(ANSI_STYLE_CODES.green, "")
         .replace(ANSI_STYLE_CODES.yellow, "")
+        .replace(ANSI_STYLE_CODES.magenta, "")
         .replace(ANSI_STYLE_CODES.cyan, "")
         .replace(ANSI_STYLE_CODES.white, "")
         .replace(ANSI_STYLE_CODES.bold, "")
@@ -408,6 +599,20 @@ impl<'a> RocDocAllocator<'a> {
 
     /// like vcat, but adds a double line break between elements. Visually this means an empty line
     /// between elements.
+    /// The byte offset of a line and column in the source these reports are about.
+    /// This relies on `src_lines` having been split on `\n`, so that a `\r` before
+    /// a newline is still counted.
+    pub fn byte_offset(&self, line_column: LineColumn) -> usize {
+        let line_starts: usize = self
+            .src_lines
+            .iter()
+            .take(line_column.line as usize)
+            .map(|line| line.len() + 1)
+            .sum();
+
+        line_starts + line_column.column as usize
+    }
+
     pub fn stack<A, I>(&'a self, docs: I) -> DocBuilder<'a, Self, A>
     where
         A: 'a + Clone,
@@ -724,7 +929,14 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result.annotate(Annotation::CodeBlock)
+        let mut result = result.annotate(Annotation::CodeBlock);
+
+        // Snippets are collected outside-in, so annotate the second region first.
+        if sub_region2 != sub_region1 {
+            result = result.annotate(Annotation::Snippet(sub_region2));
+        }
+
+        result.annotate(Annotation::Snippet(sub_region1))
     }
 
     pub fn region_with_subregion(
@@ -748,6 +960,7 @@ impl<'a> RocDocAllocator<'a> {
         let annotation = match severity {
             Severity::RuntimeError | Severity::Fatal => Annotation::Error,
             Severity::Warning => Annotation::Warning,
+            Severity::Info => Annotation::Tip,
         };
 
         // if true, the final line of the snippet will be some ^^^ that point to the region where
@@ -837,7 +1050,7 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result
+        result.annotate(Annotation::Snippet(sub_region))
     }
 
     pub fn region(
@@ -960,6 +1173,8 @@ pub enum Annotation {
     Header,
     ParserSuggestion,
     Warning,
+    /// A source snippet, highlighting the given region.
+    Snippet(LineColumnRegion),
 }
 
 /// Render with minimal formatting
@@ -1085,6 +1300,59 @@ where
     }
 }
 
+/// Render like [CiWrite], additionally collecting every source snippet
+/// along with the paragraph that introduces it.
+struct JsonWrite<'a> {
+    ci: CiWrite<&'a mut String>,
+    labels: Vec<(String, LineColumnRegion)>,
+}
+
+impl<'a> JsonWrite<'a> {
+    fn new(upstream: &'a mut String) -> Self {
+        JsonWrite {
+            ci: CiWrite::new(upstream),
+            labels: Vec::new(),
+        }
+    }
+
+    /// The paragraph written most recently, joined into one line.
+    fn last_paragraph(&self) -> String {
+        let written = self.ci.upstream.trim_end();
+        let paragraph = match written.rfind("\n\n") {
+            Some(index) => &written[index..],
+            None => written,
+        };
+
+        paragraph.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl<'a> Render for JsonWrite<'a> {
+    type Error = fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<usize, fmt::Error> {
+        self.ci.write_str(s)
+    }
+
+    fn write_str_all(&mut self, s: &str) -> fmt::Result {
+        self.ci.write_str_all(s)
+    }
+}
+
+impl<'a> RenderAnnotated<Annotation> for JsonWrite<'a> {
+    fn push_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        if let Annotation::Snippet(region) = annotation {
+            self.labels.push((self.last_paragraph(), *region));
+        }
+
+        self.ci.push_annotation(annotation)
+    }
+
+    fn pop_annotation(&mut self) -> Result<(), Self::Error> {
+        self.ci.pop_annotation()
+    }
+}
+
 impl<'a, W> Render for ColorWrite<'a, W>
 where
     W: fmt::Write,
@@ -1176,8 +1444,8 @@ where
             Warning => {
                 self.write_str(self.palette.warning)?;
             }
-            TypeBlock | InlineTypeBlock | Tag | RecordField | RecordUpdater | TupleElem => { /* nothing yet */
-            }
+            TypeBlock | InlineTypeBlock | Tag | RecordField | RecordUpdater | TupleElem
+            | Snippet(_) => { /* nothing yet */ }
         }
         self.style_stack.push(*annotation);
         Ok(())
@@ -1197,7 +1465,7 @@ where
                 }
 
                 TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField | RecordUpdater
-                | TupleElem => { /* nothing yet */ }
+                | TupleElem | Snippet(_) => { /* nothing yet */ }
             },
         }
         Ok(())
@@ -1222,9 +1490,9 @@ pub fn to_https_problem_report_string(
     let alloc = RocDocAllocator::new(&src_lines, module_id, &interns);
 
     let mut buf = String::new();
-    let palette = DEFAULT_PALETTE;
+    let palette = terminal_palette();
     let report = to_https_problem_report(&alloc, url, https_problem, filename);
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(terminal_render_target(), &mut buf, &alloc, &palette);
 
     buf
 }
@@ -1267,6 +1535,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "UNSUPPORTED ENCODING".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::MultipleEncodings(multiple_encodings) => {
@@ -1302,6 +1571,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "MULTIPLE ENCODINGS".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidContentHash { expected, actual } => {
@@ -1337,6 +1607,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "INVALID CONTENT HASH".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::NotFound => {
@@ -1354,6 +1625,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "NOTFOUND".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         // TODO: The reporting text for IoErr and FsExtraErr could probably be unified
@@ -1381,6 +1653,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "IO ERROR".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         // TODO: The reporting text for IoErr and FsExtraErr could probably be unified
@@ -1408,6 +1681,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "IO ERROR".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::HttpErr(reqwest_error) => {
@@ -1437,6 +1711,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "HTTP ERROR".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::InvalidExtensionSuffix(
@@ -1478,6 +1753,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "INVALID EXTENSION SUFFIX".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingTarExt) => {
@@ -1511,6 +1787,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "INVALID EXTENSION".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::InvalidFragment(
@@ -1549,6 +1826,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "INVALID FRAGMENT".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingHash) => {
@@ -1587,6 +1865,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "MISSING PACKAGE HASH".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingHttps) => {
@@ -1613,6 +1892,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "HTTPS MANDATORY".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MisleadingCharacter) => {
@@ -1656,6 +1936,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "MISLEADING CHARACTERS".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         Problem::DownloadTooBig(content_len) => {
@@ -1686,6 +1967,7 @@ pub fn to_https_problem_report<'b>(
                 doc,
                 title: "FILE TOO LARGE".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
     }
@@ -1694,7 +1976,8 @@ pub fn to_https_problem_report<'b>(
 pub fn to_file_problem_report_string(
     filename: PathBuf,
     error: io::ErrorKind,
-    has_color: bool,
+    render: RenderTarget,
+    palette: Palette,
 ) -> String {
     let src_lines: Vec<&str> = Vec::new();
     let mut module_ids = ModuleIds::default();
@@ -1705,13 +1988,8 @@ pub fn to_file_problem_report_string(
     let alloc = RocDocAllocator::new(&src_lines, module_id, &interns);
 
     let mut buf = String::new();
-    let palette = if has_color {
-        DEFAULT_PALETTE
-    } else {
-        NO_COLOR_PALETTE
-    };
     let report = to_file_problem_report(&alloc, filename, error);
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(render, &mut buf, &alloc, &palette);
 
     buf
 }
@@ -1741,6 +2019,7 @@ pub fn to_file_problem_report<'b>(
                 doc,
                 title: "FILE NOT FOUND".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         io::ErrorKind::PermissionDenied => {
@@ -1759,6 +2038,7 @@ pub fn to_file_problem_report<'b>(
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         io::ErrorKind::Unsupported => {
@@ -1795,6 +2075,7 @@ pub fn to_file_problem_report<'b>(
                 doc,
                 title: "NOT A ROC FILE".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
         _ => {
@@ -1815,7 +2096,73 @@ pub fn to_file_problem_report<'b>(
                 doc,
                 title: "FILE PROBLEM".to_string(),
                 severity: Severity::Fatal,
+                region: None,
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test_palette {
+    use super::*;
+
+    #[test]
+    fn auto_respects_environment() {
+        let no_color = Some(OsStr::new("1"));
+        let empty = Some(OsStr::new(""));
+        let colorblind = Some("colorblind");
+
+        assert_eq!(
+            palette_for(ColorChoice::Auto, true, None, None),
+            DEFAULT_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Auto, true, empty, None),
+            DEFAULT_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Auto, true, no_color, colorblind),
+            NO_COLOR_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Auto, true, None, colorblind),
+            COLORBLIND_PALETTE
+        );
+    }
+
+    #[test]
+    fn auto_skips_colors_when_not_a_terminal() {
+        assert_eq!(
+            palette_for(ColorChoice::Auto, false, None, None),
+            NO_COLOR_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Auto, false, None, Some("colorblind")),
+            NO_COLOR_PALETTE
+        );
+    }
+
+    #[test]
+    fn color_flag_overrides_environment() {
+        let no_color = Some(OsStr::new("1"));
+        let colorblind = Some("colorblind");
+
+        assert_eq!(
+            palette_for(ColorChoice::Always, false, no_color, None),
+            DEFAULT_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Always, false, no_color, colorblind),
+            COLORBLIND_PALETTE
+        );
+        assert_eq!(
+            palette_for(ColorChoice::Never, true, None, colorblind),
+            NO_COLOR_PALETTE
+        );
+    }
+
+    #[test]
+    fn colorblind_errors_stand_out_from_warnings() {
+        assert_ne!(COLORBLIND_PALETTE.error, COLORBLIND_PALETTE.warning);
+    }
+}